@@ -0,0 +1,629 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The Cholesky decomposition of a symmetric positive definite matrix.
+///
+/// The decomposition of a symmetric positive definite matrix `a` with `N` rows
+/// and `N` columns is a lower triangular matrix `l` with positive diagonal
+/// elements such that
+/// ```text
+/// a == l * transpose(l)
+/// ```
+/// A symmetric matrix has a Cholesky decomposition exactly when it is positive
+/// definite, so computing the decomposition doubles as a test for positive
+/// definiteness. The decomposition needs no pivoting to be numerically stable,
+/// and it is the standard way to solve linear systems with, and invert, matrices
+/// such as covariance matrices, normal equations, and inertia tensors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CholeskyDecomposition<S, const N: usize> {
+    l: Matrix<S, N, N>,
+}
+
+impl<S, const N: usize> CholeskyDecomposition<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the Cholesky decomposition of a matrix, if the matrix is
+    /// symmetric and positive definite.
+    ///
+    /// Returns `None` if the matrix is not symmetric (see [`Matrix::is_symmetric`]),
+    /// or if it is not positive definite. Only the lower triangle of the matrix
+    /// is used in the computation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     CholeskyDecomposition,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      4_f64,   12_f64, -16_f64,
+    ///      12_f64,  37_f64, -43_f64,
+    ///     -16_f64, -43_f64,  98_f64,
+    /// );
+    /// let expected = Matrix3x3::new(
+    ///     2_f64, 6_f64, -8_f64,
+    ///     0_f64, 1_f64,  5_f64,
+    ///     0_f64, 0_f64,  3_f64,
+    /// );
+    /// let cholesky = CholeskyDecomposition::try_new(&matrix).unwrap();
+    ///
+    /// assert_eq!(cholesky.l(), &expected);
+    ///
+    /// // A symmetric matrix that is not positive definite.
+    /// let indefinite = Matrix3x3::new(
+    ///     1_f64, 2_f64, 0_f64,
+    ///     2_f64, 1_f64, 0_f64,
+    ///     0_f64, 0_f64, 1_f64,
+    /// );
+    ///
+    /// assert!(CholeskyDecomposition::try_new(&indefinite).is_none());
+    /// ```
+    pub fn try_new(matrix: &Matrix<S, N, N>) -> Option<Self> {
+        if !matrix.is_symmetric() {
+            return None;
+        }
+
+        let mut l = Matrix::zero();
+        for j in 0..N {
+            let mut pivot = matrix[j][j];
+            for k in 0..j {
+                pivot -= l[k][j] * l[k][j];
+            }
+            if !pivot.is_finite() || pivot <= S::zero() {
+                return None;
+            }
+
+            let l_jj = pivot.sqrt();
+            l[j][j] = l_jj;
+            for i in (j + 1)..N {
+                let mut sum = matrix[j][i];
+                for k in 0..j {
+                    sum -= l[k][i] * l[k][j];
+                }
+                l[j][i] = sum / l_jj;
+            }
+        }
+
+        Some(Self { l })
+    }
+
+    /// Get the lower triangular factor `l` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     CholeskyDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 3_f64,
+    /// );
+    /// let cholesky = CholeskyDecomposition::try_new(&matrix).unwrap();
+    /// let l = cholesky.l();
+    /// let expected = Matrix2x2::new(
+    ///     2_f64, 1_f64,
+    ///     0_f64, f64::sqrt(2_f64),
+    /// );
+    ///
+    /// assert_relative_eq!(l, &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(l * l.transpose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn l(&self) -> &Matrix<S, N, N> {
+        &self.l
+    }
+
+    /// Solve the linear system `a * x == b` for `x`.
+    ///
+    /// The system is solved by forward substitution with `l` followed by back
+    /// substitution with `transpose(l)`, without forming the inverse of `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     CholeskyDecomposition,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      4_f64,   12_f64, -16_f64,
+    ///      12_f64,  37_f64, -43_f64,
+    ///     -16_f64, -43_f64,  98_f64,
+    /// );
+    /// let b = Vector3::new(-20_f64, -43_f64, 192_f64);
+    /// let cholesky = CholeskyDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let result = cholesky.solve(&b);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn solve(&self, b: &Vector<S, N>) -> Vector<S, N> {
+        let mut result = *b;
+        // Solve `l * y == b` by forward substitution.
+        for i in 0..N {
+            let mut sum = result[i];
+            for j in 0..i {
+                sum -= self.l[j][i] * result[j];
+            }
+            result[i] = sum / self.l[i][i];
+        }
+        // Solve `transpose(l) * x == y` by back substitution.
+        for i in (0..N).rev() {
+            let mut sum = result[i];
+            for j in (i + 1)..N {
+                sum -= self.l[i][j] * result[j];
+            }
+            result[i] = sum / self.l[i][i];
+        }
+
+        result
+    }
+
+    /// Compute the inverse of the decomposed matrix.
+    ///
+    /// Each column of the inverse is the solution of a linear system with the
+    /// corresponding column of the identity matrix. Prefer [`CholeskyDecomposition::solve`]
+    /// for solving linear systems, since it is both cheaper and more accurate
+    /// than multiplying by the inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     CholeskyDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 3_f64,
+    /// );
+    /// let cholesky = CholeskyDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Matrix2x2::new(
+    ///      3_f64 / 8_f64, -1_f64 / 4_f64,
+    ///     -1_f64 / 4_f64,  1_f64 / 2_f64,
+    /// );
+    /// let result = cholesky.inverse();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn inverse(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::zero();
+        for c in 0..N {
+            let mut unit = Vector::zero();
+            unit[c] = S::one();
+            result[c] = self.solve(&unit);
+        }
+
+        result
+    }
+
+    /// Compute the determinant of the decomposed matrix.
+    ///
+    /// The determinant is the square of the product of the diagonal of `l`. For
+    /// large or badly scaled matrices, prefer [`CholeskyDecomposition::log_determinant`],
+    /// which does not overflow or underflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     CholeskyDecomposition,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      4_f64,   12_f64, -16_f64,
+    ///      12_f64,  37_f64, -43_f64,
+    ///     -16_f64, -43_f64,  98_f64,
+    /// );
+    /// let cholesky = CholeskyDecomposition::try_new(&matrix).unwrap();
+    ///
+    /// assert_relative_eq!(cholesky.determinant(), 36_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// ```
+    pub fn determinant(&self) -> S {
+        let product = (0..N).fold(S::one(), |acc, i| acc * self.l[i][i]);
+
+        product * product
+    }
+
+    /// Compute the natural logarithm of the determinant of the decomposed matrix.
+    ///
+    /// The log-determinant is computed as twice the sum of the logarithms of the
+    /// diagonal of `l`. It appears in the log-likelihood of a multivariate normal
+    /// distribution with the decomposed matrix as covariance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     CholeskyDecomposition,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::from_diagonal(&Vector3::new(1e-200_f64, 1e-200_f64, 1e-200_f64));
+    /// let cholesky = CholeskyDecomposition::try_new(&matrix).unwrap();
+    /// let expected = -600_f64 * f64::ln(10_f64);
+    ///
+    /// assert_eq!(cholesky.determinant(), 0_f64);
+    /// assert_relative_eq!(cholesky.log_determinant(), expected, abs_diff <= 1e-10, relative <= f64::EPSILON);
+    /// ```
+    pub fn log_determinant(&self) -> S {
+        let two = S::one() + S::one();
+
+        two * (0..N).fold(S::zero(), |acc, i| acc + self.l[i][i].ln())
+    }
+}
+
+/// The `LDL^T` decomposition of a symmetric positive definite matrix.
+///
+/// The decomposition of a symmetric positive definite matrix `a` with `N` rows
+/// and `N` columns is a unit lower triangular matrix `l` and a diagonal matrix
+/// `d` with positive diagonal elements such that
+/// ```text
+/// a == l * d * transpose(l)
+/// ```
+/// This is a variant of the Cholesky decomposition that avoids taking square
+/// roots. The two are related by `cholesky.l() == l * sqrt(d)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LdltDecomposition<S, const N: usize> {
+    l: Matrix<S, N, N>,
+    d: Vector<S, N>,
+}
+
+impl<S, const N: usize> LdltDecomposition<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the `LDL^T` decomposition of a matrix, if the matrix is symmetric
+    /// and positive definite.
+    ///
+    /// Returns `None` if the matrix is not symmetric (see [`Matrix::is_symmetric`]),
+    /// or if it is not positive definite. Only the lower triangle of the matrix
+    /// is used in the computation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      4_f64,   12_f64, -16_f64,
+    ///      12_f64,  37_f64, -43_f64,
+    ///     -16_f64, -43_f64,  98_f64,
+    /// );
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    /// let expected_l = Matrix3x3::new(
+    ///     1_f64, 3_f64, -4_f64,
+    ///     0_f64, 1_f64,  5_f64,
+    ///     0_f64, 0_f64,  1_f64,
+    /// );
+    /// let expected_d = Vector3::new(4_f64, 1_f64, 9_f64);
+    ///
+    /// assert_eq!(ldlt.l(), &expected_l);
+    /// assert_eq!(ldlt.d(), &expected_d);
+    ///
+    /// // A positive semidefinite matrix.
+    /// let singular = Matrix3x3::new(
+    ///     1_f64, 1_f64, 0_f64,
+    ///     1_f64, 1_f64, 0_f64,
+    ///     0_f64, 0_f64, 1_f64,
+    /// );
+    ///
+    /// assert!(LdltDecomposition::try_new(&singular).is_none());
+    /// ```
+    pub fn try_new(matrix: &Matrix<S, N, N>) -> Option<Self> {
+        if !matrix.is_symmetric() {
+            return None;
+        }
+
+        let mut l = Matrix::identity();
+        let mut d = Vector::zero();
+        for j in 0..N {
+            let mut pivot = matrix[j][j];
+            for k in 0..j {
+                pivot -= l[k][j] * l[k][j] * d[k];
+            }
+            if !pivot.is_finite() || pivot <= S::zero() {
+                return None;
+            }
+
+            d[j] = pivot;
+            for i in (j + 1)..N {
+                let mut sum = matrix[j][i];
+                for k in 0..j {
+                    sum -= l[k][i] * l[k][j] * d[k];
+                }
+                l[j][i] = sum / pivot;
+            }
+        }
+
+        Some(Self { l, d })
+    }
+
+    /// Get the unit lower triangular factor `l` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 3_f64,
+    /// );
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Matrix2x2::new(
+    ///     1_f64, 0.5_f64,
+    ///     0_f64, 1_f64,
+    /// );
+    ///
+    /// assert_eq!(ldlt.l(), &expected);
+    /// ```
+    #[inline]
+    pub const fn l(&self) -> &Matrix<S, N, N> {
+        &self.l
+    }
+
+    /// Get the diagonal of the diagonal factor `d` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix2x2,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 3_f64,
+    /// );
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Vector2::new(4_f64, 2_f64);
+    ///
+    /// assert_eq!(ldlt.d(), &expected);
+    /// ```
+    #[inline]
+    pub const fn d(&self) -> &Vector<S, N> {
+        &self.d
+    }
+
+    /// Solve the linear system `a * x == b` for `x`.
+    ///
+    /// The system is solved by forward substitution with `l`, scaling by the
+    /// inverse of `d`, and back substitution with `transpose(l)`, without
+    /// forming the inverse of `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      4_f64,   12_f64, -16_f64,
+    ///      12_f64,  37_f64, -43_f64,
+    ///     -16_f64, -43_f64,  98_f64,
+    /// );
+    /// let b = Vector3::new(-20_f64, -43_f64, 192_f64);
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let result = ldlt.solve(&b);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn solve(&self, b: &Vector<S, N>) -> Vector<S, N> {
+        let mut result = *b;
+        // Solve `l * y == b` by forward substitution.
+        for i in 0..N {
+            let mut sum = result[i];
+            for j in 0..i {
+                sum -= self.l[j][i] * result[j];
+            }
+            result[i] = sum;
+        }
+        // Solve `d * z == y`.
+        for i in 0..N {
+            result[i] /= self.d[i];
+        }
+        // Solve `transpose(l) * x == z` by back substitution.
+        for i in (0..N).rev() {
+            let mut sum = result[i];
+            for j in (i + 1)..N {
+                sum -= self.l[i][j] * result[j];
+            }
+            result[i] = sum;
+        }
+
+        result
+    }
+
+    /// Compute the inverse of the decomposed matrix.
+    ///
+    /// Each column of the inverse is the solution of a linear system with the
+    /// corresponding column of the identity matrix. Prefer [`LdltDecomposition::solve`]
+    /// for solving linear systems, since it is both cheaper and more accurate
+    /// than multiplying by the inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 3_f64,
+    /// );
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    /// let result = ldlt.inverse();
+    ///
+    /// assert_relative_eq!(result * matrix, Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn inverse(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::zero();
+        for c in 0..N {
+            let mut unit = Vector::zero();
+            unit[c] = S::one();
+            result[c] = self.solve(&unit);
+        }
+
+        result
+    }
+
+    /// Compute the determinant of the decomposed matrix.
+    ///
+    /// The determinant is the product of the diagonal of `d`. For large or badly
+    /// scaled matrices, prefer [`LdltDecomposition::log_determinant`], which does
+    /// not overflow or underflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      4_f64,   12_f64, -16_f64,
+    ///      12_f64,  37_f64, -43_f64,
+    ///     -16_f64, -43_f64,  98_f64,
+    /// );
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    ///
+    /// assert_relative_eq!(ldlt.determinant(), 36_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// ```
+    pub fn determinant(&self) -> S {
+        (0..N).fold(S::one(), |acc, i| acc * self.d[i])
+    }
+
+    /// Compute the natural logarithm of the determinant of the decomposed matrix.
+    ///
+    /// The log-determinant is computed as the sum of the logarithms of the
+    /// diagonal of `d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LdltDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 3_f64,
+    /// );
+    /// let ldlt = LdltDecomposition::try_new(&matrix).unwrap();
+    ///
+    /// assert_relative_eq!(ldlt.log_determinant(), f64::ln(8_f64), abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// ```
+    pub fn log_determinant(&self) -> S {
+        (0..N).fold(S::zero(), |acc, i| acc + self.d[i].ln())
+    }
+}
+
+impl<S, const N: usize> Matrix<S, N, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the Cholesky decomposition of a matrix, if the matrix is
+    /// symmetric and positive definite.
+    ///
+    /// See [`CholeskyDecomposition`] for details.
+    ///
+    /// # Example
+    ///
+    /// Evaluate the squared Mahalanobis distance of a point from the mean of
+    /// a normal distribution with a given covariance.
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let covariance = Matrix2x2::new(
+    ///     2_f64, 1_f64,
+    ///     1_f64, 2_f64,
+    /// );
+    /// let residual = Vector2::new(1_f64, -1_f64);
+    /// let cholesky = covariance.try_cholesky().unwrap();
+    /// let result = residual.dot(&cholesky.solve(&residual));
+    ///
+    /// assert_relative_eq!(result, 2_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// assert!(Matrix2x2::new(1_f64, 2_f64, 2_f64, 1_f64).try_cholesky().is_none());
+    /// ```
+    #[inline]
+    pub fn try_cholesky(&self) -> Option<CholeskyDecomposition<S, N>> {
+        CholeskyDecomposition::try_new(self)
+    }
+
+    /// Compute the `LDL^T` decomposition of a matrix, if the matrix is symmetric
+    /// and positive definite.
+    ///
+    /// See [`LdltDecomposition`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix,
+    /// #     Vector,
+    /// # };
+    /// #
+    /// let matrix: Matrix<f64, 5, 5> = Matrix::from([
+    ///     [4_f64, 1_f64, 0_f64, 0_f64, 0_f64],
+    ///     [1_f64, 4_f64, 1_f64, 0_f64, 0_f64],
+    ///     [0_f64, 1_f64, 4_f64, 1_f64, 0_f64],
+    ///     [0_f64, 0_f64, 1_f64, 4_f64, 1_f64],
+    ///     [0_f64, 0_f64, 0_f64, 1_f64, 4_f64],
+    /// ]);
+    /// let b = Vector::from([5_f64, 6_f64, 6_f64, 6_f64, 5_f64]);
+    /// let expected = Vector::from([1_f64, 1_f64, 1_f64, 1_f64, 1_f64]);
+    /// let result = matrix.try_ldlt().unwrap().solve(&b);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn try_ldlt(&self) -> Option<LdltDecomposition<S, N>> {
+        LdltDecomposition::try_new(self)
+    }
+}
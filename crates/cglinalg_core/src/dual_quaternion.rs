@@ -0,0 +1,1361 @@
+use crate::matrix::{
+    Matrix3x3,
+    Matrix4x4,
+};
+use crate::point::Point3;
+use crate::quaternion::Quaternion;
+use crate::vector::{
+    Vector3,
+    Vector4,
+};
+use cglinalg_numeric::{
+    SimdScalar,
+    SimdScalarFloat,
+    SimdScalarSigned,
+};
+
+use core::fmt;
+use core::ops;
+
+
+/// A stack-allocated dual quaternion.
+///
+/// A dual quaternion is a pair of quaternions
+/// ```text
+/// q == q_real + epsilon * q_dual
+/// ```
+/// where `epsilon` is the dual unit satisfying `epsilon * epsilon == 0`. The unit
+/// dual quaternions, the dual quaternions whose real part `q_real` is a unit
+/// quaternion and whose dual part satisfies `dot(q_real, q_dual) == 0`, represent
+/// the rigid transformations in three dimensions. The rigid transformation that
+/// rotates by the unit quaternion `r` and then translates by the vector `t` is
+/// represented by the unit dual quaternion
+/// ```text
+/// q == r + epsilon * (1 / 2) * t * r
+/// ```
+/// where `t` is treated as a pure quaternion. Composing rigid transformations
+/// corresponds to multiplying dual quaternions.
+///
+/// Unlike blending affine matrices, interpolating and blending unit dual
+/// quaternions always produces a rigid transformation. In particular, screw
+/// linear interpolation ([`DualQuaternion::sclerp`]) moves at constant speed
+/// along the screw motion connecting two rigid transformations, which avoids the
+/// volume loss (candy wrapper) artifacts of linear blend skinning.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::{
+/// #     DualQuaternion,
+/// #     Point3,
+/// #     Quaternion,
+/// #     Unit,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_trigonometry::Degrees;
+/// #
+/// let axis = Unit::from_value(Vector3::unit_z());
+/// let rotation = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+/// let translation = Vector3::new(1_f64, 2_f64, 3_f64);
+/// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+/// let point = Point3::new(1_f64, 0_f64, 0_f64);
+/// let expected = Point3::new(1_f64, 3_f64, 3_f64);
+/// let result = dual_quaternion.apply_point(&point);
+///
+/// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DualQuaternion<S> {
+    /// The real part of the dual quaternion.
+    pub real: Quaternion<S>,
+    /// The dual part of the dual quaternion.
+    pub dual: Quaternion<S>,
+}
+
+impl<S> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real part and its dual part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let real = Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64);
+    /// let dual = Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64);
+    /// let dual_quaternion = DualQuaternion::new(real, dual);
+    ///
+    /// assert_eq!(dual_quaternion.real, real);
+    /// assert_eq!(dual_quaternion.dual, dual);
+    /// ```
+    #[inline]
+    pub const fn new(real: Quaternion<S>, dual: Quaternion<S>) -> Self {
+        Self { real, dual }
+    }
+}
+
+impl<S> DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    /// Construct the zero dual quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let zero: DualQuaternion<f64> = DualQuaternion::zero();
+    ///
+    /// assert!(zero.is_zero());
+    /// assert_eq!(zero.real, Quaternion::zero());
+    /// assert_eq!(zero.dual, Quaternion::zero());
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(Quaternion::zero(), Quaternion::zero())
+    }
+
+    /// Determine whether a dual quaternion is the zero dual quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let zero: DualQuaternion<f64> = DualQuaternion::zero();
+    /// let identity: DualQuaternion<f64> = DualQuaternion::identity();
+    ///
+    /// assert!(zero.is_zero());
+    /// assert!(!identity.is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.is_zero()
+    }
+
+    /// Construct the identity dual quaternion.
+    ///
+    /// The identity dual quaternion represents the identity rigid
+    /// transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let identity: DualQuaternion<f64> = DualQuaternion::identity();
+    ///
+    /// assert!(identity.is_identity());
+    /// assert_eq!(identity.real, Quaternion::identity());
+    /// assert_eq!(identity.dual, Quaternion::zero());
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(Quaternion::identity(), Quaternion::zero())
+    }
+
+    /// Determine whether a dual quaternion is the identity dual quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let identity: DualQuaternion<f64> = DualQuaternion::identity();
+    ///
+    /// assert!(identity.is_identity());
+    ///
+    /// let translation = DualQuaternion::from_translation(&Vector3::new(1_f64, 2_f64, 3_f64));
+    ///
+    /// assert!(!translation.is_identity());
+    /// ```
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.real.is_identity() && self.dual.is_zero()
+    }
+}
+
+impl<S> DualQuaternion<S>
+where
+    S: SimdScalarSigned,
+{
+    /// Compute the quaternion conjugate of a dual quaternion.
+    ///
+    /// The quaternion conjugate conjugates both parts of the dual quaternion
+    /// ```text
+    /// conjugate(q_real + epsilon * q_dual) == q_real* + epsilon * q_dual*
+    /// ```
+    /// It reverses the order of products, and it is the inverse of a unit dual
+    /// quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let expected = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, -2_f64, -3_f64, -4_f64),
+    ///     Quaternion::new(5_f64, -6_f64, -7_f64, -8_f64),
+    /// );
+    /// let result = dual_quaternion.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Compute the dual number conjugate of a dual quaternion.
+    ///
+    /// The dual number conjugate negates the dual part of the dual quaternion
+    /// ```text
+    /// dual_conjugate(q_real + epsilon * q_dual) == q_real - epsilon * q_dual
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let expected = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(-5_f64, -6_f64, -7_f64, -8_f64),
+    /// );
+    /// let result = dual_quaternion.dual_conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn dual_conjugate(&self) -> Self {
+        Self::new(self.real, -self.dual)
+    }
+
+    /// Compute the combined conjugate of a dual quaternion.
+    ///
+    /// The combined conjugate applies both the quaternion conjugate and the
+    /// dual number conjugate
+    /// ```text
+    /// combined_conjugate(q_real + epsilon * q_dual) == q_real* - epsilon * q_dual*
+    /// ```
+    /// A unit dual quaternion `q` transforms a point `p`, written as the dual
+    /// quaternion `1 + epsilon * p`, by the sandwich product
+    /// `q * (1 + epsilon * p) * combined_conjugate(q)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let expected = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, -2_f64, -3_f64, -4_f64),
+    ///     Quaternion::new(-5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let result = dual_quaternion.combined_conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn combined_conjugate(&self) -> Self {
+        Self::new(self.real.conjugate(), -self.dual.conjugate())
+    }
+}
+
+impl<S> DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a unit dual quaternion from a rotation followed by a
+    /// translation.
+    ///
+    /// The rotation is given by the unit quaternion `rotation`, and the
+    /// translation by the displacement vector `translation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(72_f64));
+    /// let translation = Vector3::new(-1_f64, 4_f64, 2_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    ///
+    /// assert_relative_eq!(dual_quaternion.rotation(), rotation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(dual_quaternion.translation(), translation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_rotation_translation(rotation: &Quaternion<S>, translation: &Vector3<S>) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let dual = Quaternion::from_pure(*translation) * rotation * one_half;
+
+        Self::new(*rotation, dual)
+    }
+
+    /// Construct a unit dual quaternion from a rotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_y());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(30_f64));
+    /// let dual_quaternion = DualQuaternion::from_rotation(&rotation);
+    ///
+    /// assert_eq!(dual_quaternion.real, rotation);
+    /// assert_eq!(dual_quaternion.dual, Quaternion::zero());
+    /// ```
+    #[inline]
+    pub fn from_rotation(rotation: &Quaternion<S>) -> Self {
+        Self::new(*rotation, Quaternion::zero())
+    }
+
+    /// Construct a unit dual quaternion from a translation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let translation = Vector3::new(1_f64, -2_f64, 3_f64);
+    /// let dual_quaternion = DualQuaternion::from_translation(&translation);
+    /// let point = Point3::new(1_f64, 1_f64, 1_f64);
+    /// let expected = Point3::new(2_f64, -1_f64, 4_f64);
+    /// let result = dual_quaternion.apply_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_translation(translation: &Vector3<S>) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+
+        Self::new(Quaternion::identity(), Quaternion::from_pure(*translation * one_half))
+    }
+
+    /// Construct a unit dual quaternion from an affine matrix of a rigid
+    /// transformation.
+    ///
+    /// The upper left 3x3 block of the matrix must be a rotation matrix, and the
+    /// last row of the matrix must be `[0, 0, 0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Matrix4x4,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64))
+    ///     * Matrix4x4::from_affine_angle_x(Degrees(60_f64));
+    /// let dual_quaternion = DualQuaternion::from_affine_matrix(&matrix);
+    /// let result = dual_quaternion.to_affine_matrix();
+    ///
+    /// assert_relative_eq!(result, matrix, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_affine_matrix(matrix: &Matrix4x4<S>) -> Self {
+        let rotation_matrix = Matrix3x3::new(
+            matrix.c0r0, matrix.c0r1, matrix.c0r2,
+            matrix.c1r0, matrix.c1r1, matrix.c1r2,
+            matrix.c2r0, matrix.c2r1, matrix.c2r2,
+        );
+        let rotation = Quaternion::from_matrix(&rotation_matrix);
+        let translation = Vector3::new(matrix.c3r0, matrix.c3r1, matrix.c3r2);
+
+        Self::from_rotation_translation(&rotation, &translation)
+    }
+
+    /// Construct the affine matrix of the rigid transformation that a unit dual
+    /// quaternion represents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Matrix4x4,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_y());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(45_f64));
+    /// let translation = Vector3::new(3_f64, 0_f64, -1_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let expected = Matrix4x4::from_affine_translation(&translation)
+    ///     * Matrix4x4::from_affine_angle_y(Degrees(45_f64));
+    /// let result = dual_quaternion.to_affine_matrix();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_affine_matrix(&self) -> Matrix4x4<S> {
+        let translation = self.translation();
+        let mut matrix = self.real.to_affine_matrix();
+        matrix[3][0] = translation.x;
+        matrix[3][1] = translation.y;
+        matrix[3][2] = translation.z;
+
+        matrix
+    }
+
+    /// Get the rotation part of a unit dual quaternion.
+    ///
+    /// This is the real part of the dual quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_x());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(10_f64));
+    /// let translation = Vector3::new(3_f64, 0_f64, -1_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    ///
+    /// assert_eq!(dual_quaternion.rotation(), rotation);
+    /// ```
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<S> {
+        self.real
+    }
+
+    /// Get the translation part of a unit dual quaternion.
+    ///
+    /// The translation is the vector part of `2 * q_dual * q_real*`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_x());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(10_f64));
+    /// let translation = Vector3::new(3_f64, 0_f64, -1_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    ///
+    /// assert_relative_eq!(dual_quaternion.translation(), translation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        let two = S::one() + S::one();
+
+        (self.dual * self.real.conjugate()).vector() * two
+    }
+
+    /// Get the rotation and the translation of a unit dual quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(150_f64));
+    /// let translation = Vector3::new(3_f64, 5_f64, -1_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let (result_rotation, result_translation) = dual_quaternion.to_rotation_translation();
+    ///
+    /// assert_eq!(result_rotation, rotation);
+    /// assert_relative_eq!(result_translation, translation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_rotation_translation(&self) -> (Quaternion<S>, Vector3<S>) {
+        (self.rotation(), self.translation())
+    }
+
+    /// Apply the rigid transformation of a unit dual quaternion to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Point3,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_x());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+    /// let translation = Vector3::new(0_f64, 0_f64, 5_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let point = Point3::new(1_f64, 1_f64, 0_f64);
+    /// let expected = Point3::new(1_f64, 0_f64, 6_f64);
+    /// let result = dual_quaternion.apply_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn apply_point(&self, point: &Point3<S>) -> Point3<S> {
+        let rotated = self.apply_vector(&point.to_vector());
+
+        Point3::from_vector(&(rotated + self.translation()))
+    }
+
+    /// Apply the rigid transformation of a unit dual quaternion to a vector.
+    ///
+    /// Vectors are displacements, so only the rotation applies to them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_x());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+    /// let translation = Vector3::new(0_f64, 0_f64, 5_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let vector = Vector3::new(1_f64, 1_f64, 0_f64);
+    /// let expected = Vector3::new(1_f64, 0_f64, 1_f64);
+    /// let result = dual_quaternion.apply_vector(&vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn apply_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        (self.real * Quaternion::from_pure(*vector) * self.real.conjugate()).vector()
+    }
+
+    /// Normalize a dual quaternion to a unit dual quaternion.
+    ///
+    /// Normalization divides the dual quaternion by the norm of its real part,
+    /// and then removes the component of the dual part along the real part,
+    /// so that the result satisfies `|q_real| == 1` and `dot(q_real, q_dual) == 0`.
+    /// This is the projection used to turn a weighted sum of unit dual
+    /// quaternions into a rigid transformation in dual quaternion skinning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let result = dual_quaternion.normalize();
+    ///
+    /// assert_relative_eq!(result.real.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.real.dot(&result.dual), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let norm_real = self.real.norm();
+        let real = self.real / norm_real;
+        let dual = self.dual / norm_real;
+
+        Self::new(real, dual - real * real.dot(&dual))
+    }
+
+    /// Attempt to normalize a dual quaternion to a unit dual quaternion.
+    ///
+    /// The function returns `None` when the norm of the real part of the dual
+    /// quaternion is at most `threshold`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::new(
+    ///     Quaternion::zero(),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    ///
+    /// assert!(dual_quaternion.try_normalize(1e-10).is_none());
+    /// ```
+    #[inline]
+    pub fn try_normalize(&self, threshold: S) -> Option<Self> {
+        if self.real.norm() <= threshold {
+            None
+        } else {
+            Some(self.normalize())
+        }
+    }
+
+    /// Compute the inverse of a dual quaternion.
+    ///
+    /// The inverse exists if and only if the real part of the dual quaternion is
+    /// invertible, in which case
+    /// ```text
+    /// inverse(q_real + epsilon * q_dual)
+    ///     == q_real^-1 - epsilon * q_real^-1 * q_dual * q_real^-1
+    /// ```
+    /// The function returns `None` otherwise. The inverse of a unit dual
+    /// quaternion is its quaternion conjugate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::new(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let expected = DualQuaternion::identity();
+    /// let result = dual_quaternion * dual_quaternion.try_inverse().unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        self.real.try_inverse().map(|real_inverse| {
+            let dual_inverse = -(real_inverse * self.dual * real_inverse);
+
+            Self::new(real_inverse, dual_inverse)
+        })
+    }
+
+    /// Compute the inverse of a dual quaternion.
+    ///
+    /// # Safety
+    ///
+    /// Panics if the dual quaternion is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(30_f64));
+    /// let translation = Vector3::new(3_f64, 5_f64, -1_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let expected = dual_quaternion.conjugate();
+    /// let result = dual_quaternion.inverse();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().unwrap()
+    }
+
+    /// Raise a unit dual quaternion to a real power.
+    ///
+    /// Every rigid transformation is a screw motion: a rotation by an angle
+    /// `theta` about an axis line, combined with a translation by a distance `d`
+    /// along the same line. Raising the dual quaternion to the power `exponent`
+    /// scales both the angle and the distance by `exponent`, keeping the axis
+    /// line fixed.
+    ///
+    /// The dual quaternions `q` and `-q` represent the same rigid transformation.
+    /// The power is taken along the shorter screw motion, i.e. the one whose
+    /// rotation angle is at most `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+    /// let translation = Vector3::new(0_f64, 0_f64, 4_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let expected = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(&axis, Degrees(45_f64)),
+    ///     &Vector3::new(0_f64, 0_f64, 2_f64),
+    /// );
+    /// let result = dual_quaternion.powf(0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn powf(&self, exponent: S) -> Self {
+        let dual_quaternion = if self.real.scalar() < S::zero() { -self } else { *self };
+        let real = dual_quaternion.real;
+        let dual = dual_quaternion.dual;
+        let sin_half_angle = real.vector().norm();
+        if sin_half_angle <= S::default_epsilon() {
+            // The transformation is a pure translation.
+            return Self::new(Quaternion::identity(), Quaternion::from_pure(dual.vector() * exponent));
+        }
+
+        // Decompose the dual quaternion into its screw parameters: the direction
+        // and moment of the axis line, the rotation angle, and the distance.
+        let cos_half_angle = real.scalar();
+        let half_angle = S::atan2(sin_half_angle, cos_half_angle);
+        let direction = real.vector() / sin_half_angle;
+        let half_distance = -dual.scalar() / sin_half_angle;
+        let moment = (dual.vector() - direction * (half_distance * cos_half_angle)) / sin_half_angle;
+
+        let new_half_angle = half_angle * exponent;
+        let new_half_distance = half_distance * exponent;
+        let (new_sin_half_angle, new_cos_half_angle) = new_half_angle.sin_cos();
+        let new_real = Quaternion::from_parts(new_cos_half_angle, direction * new_sin_half_angle);
+        let new_dual = Quaternion::from_parts(
+            -new_half_distance * new_sin_half_angle,
+            moment * new_sin_half_angle + direction * (new_half_distance * new_cos_half_angle),
+        );
+
+        Self::new(new_real, new_dual)
+    }
+
+    /// Compute the screw linear interpolation (ScLERP) between two unit dual
+    /// quaternions.
+    ///
+    /// Screw linear interpolation is the rigid body analogue of spherical linear
+    /// interpolation. It computes
+    /// ```text
+    /// sclerp(q1, q2, t) == q1 * (q1^-1 * q2)^t
+    /// ```
+    /// which moves along the screw motion from `q1` to `q2` with constant
+    /// angular and linear velocity. The interpolation follows the shorter screw
+    /// motion between the two rigid transformations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Point3,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let dual_quaternion1 = DualQuaternion::from_translation(&Vector3::new(1_f64, 0_f64, 0_f64));
+    /// let dual_quaternion2 = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(&axis, Degrees(180_f64)),
+    ///     &Vector3::new(-1_f64, 0_f64, 0_f64),
+    /// );
+    /// let interpolated = dual_quaternion1.sclerp(&dual_quaternion2, 0.5_f64);
+    ///
+    /// // The motion is a half turn about the z-axis. Halfway through it, the
+    /// // origin, which starts at (1, 0, 0), has made a quarter turn.
+    /// let point = Point3::origin();
+    /// let expected = Point3::new(0_f64, 1_f64, 0_f64);
+    /// let result = interpolated.apply_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn sclerp(&self, other: &Self, amount: S) -> Self {
+        let difference = self.conjugate() * other;
+
+        self * difference.powf(amount)
+    }
+}
+
+impl<S> Default for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<S> From<DualQuaternion<S>> for Matrix4x4<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(dual_quaternion: DualQuaternion<S>) -> Matrix4x4<S> {
+        dual_quaternion.to_affine_matrix()
+    }
+}
+
+impl<S> From<&DualQuaternion<S>> for Matrix4x4<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(dual_quaternion: &DualQuaternion<S>) -> Matrix4x4<S> {
+        dual_quaternion.to_affine_matrix()
+    }
+}
+
+impl<S> fmt::Display for DualQuaternion<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "DualQuaternion [real={}, dual={}]", self.real, self.dual)
+    }
+}
+
+impl<S> ops::Neg for DualQuaternion<S>
+where
+    S: SimdScalarSigned,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualQuaternion::new(-self.real, -self.dual)
+    }
+}
+
+impl<S> ops::Neg for &DualQuaternion<S>
+where
+    S: SimdScalarSigned,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualQuaternion::new(-self.real, -self.dual)
+    }
+}
+
+impl<S> ops::Add<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Add<&DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Add<DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Add<&DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Sub<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Sub<&DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Sub<DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Sub<&DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Mul<S> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        DualQuaternion::new(self.real * other, self.dual * other)
+    }
+}
+
+impl<S> ops::Mul<S> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        DualQuaternion::new(self.real * other, self.dual * other)
+    }
+}
+
+impl<S> ops::Mul<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real * other.real, self.real * other.dual + self.dual * other.real)
+    }
+}
+
+impl<S> ops::Mul<&DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real * other.real, self.real * other.dual + self.dual * other.real)
+    }
+}
+
+impl<S> ops::Mul<DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real * other.real, self.real * other.dual + self.dual * other.real)
+    }
+}
+
+impl<S> ops::Mul<&DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: SimdScalar,
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::new(self.real * other.real, self.real * other.dual + self.dual * other.real)
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualQuaternionTol<S> {
+    real: Vector4<S>,
+    dual: Vector4<S>,
+}
+
+impl<S> DualQuaternionTol<S> {
+    #[inline]
+    pub const fn from_parts(real: Vector4<S>, dual: Vector4<S>) -> Self {
+        Self { real, dual }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualQuaternionDiff<S> {
+    real: Vector4<S>,
+    dual: Vector4<S>,
+}
+
+impl<S> DualQuaternionDiff<S> {
+    #[inline]
+    const fn from_parts(real: Vector4<S>, dual: Vector4<S>) -> Self {
+        Self { real, dual }
+    }
+}
+
+impl<S> approx_cmp::AbsDiffEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = DualQuaternionTol<<S as approx_cmp::AbsDiffEq>::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.real, &other.real, &max_abs_diff.real)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.dual, &other.dual, &max_abs_diff.dual)
+    }
+}
+
+impl<S> approx_cmp::AbsDiffAllEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.real, &other.real, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.dual, &other.dual, max_abs_diff)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = DualQuaternionDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff>;
+    type DebugTolerance = DualQuaternionTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let real = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.real, &other.real);
+        let dual = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.dual, &other.dual);
+
+        DualQuaternionDiff::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let real = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.real, &other.real, &max_abs_diff.real);
+        let dual = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.dual, &other.dual, &max_abs_diff.dual);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffAllEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = DualQuaternionTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let real = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.real, &other.real, max_abs_diff);
+        let dual = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.dual, &other.dual, max_abs_diff);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+}
+
+impl<S> approx_cmp::RelativeEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = DualQuaternionTol<<S as approx_cmp::RelativeEq>::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.real, &other.real, &max_abs_diff.real, &max_relative.real)
+            && approx_cmp::RelativeEq::relative_eq(&self.dual, &other.dual, &max_abs_diff.dual, &max_relative.dual)
+    }
+}
+
+impl<S> approx_cmp::RelativeAllEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.real, &other.real, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.dual, &other.dual, max_abs_diff, max_relative)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = DualQuaternionDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff>;
+    type DebugTolerance = DualQuaternionTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let real = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.real, &other.real);
+        let dual = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.dual, &other.dual);
+
+        DualQuaternionDiff::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let real = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.real, &other.real, &max_abs_diff.real);
+        let dual = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.dual, &other.dual, &max_abs_diff.dual);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let real = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.real, &other.real, &max_relative.real);
+        let dual = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.dual, &other.dual, &max_relative.dual);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeAllEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = DualQuaternionTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let real = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.real, &other.real, max_abs_diff);
+        let dual = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.dual, &other.dual, max_abs_diff);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let real = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.real, &other.real, max_relative);
+        let dual = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.dual, &other.dual, max_relative);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+}
+
+impl<S> approx_cmp::UlpsEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = DualQuaternionTol<<S as approx_cmp::UlpsEq>::Tolerance>;
+    type UlpsTolerance = DualQuaternionTol<<S as approx_cmp::UlpsEq>::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.real, &other.real, &max_abs_diff.real, &max_ulps.real)
+            && approx_cmp::UlpsEq::ulps_eq(&self.dual, &other.dual, &max_abs_diff.dual, &max_ulps.dual)
+    }
+}
+
+impl<S> approx_cmp::UlpsAllEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.real, &other.real, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.dual, &other.dual, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = DualQuaternionDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff>;
+    type DebugUlpsDiff = DualQuaternionDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff>;
+    type DebugTolerance = DualQuaternionTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance>;
+    type DebugUlpsTolerance = DualQuaternionTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let real = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.real, &other.real);
+        let dual = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.dual, &other.dual);
+
+        DualQuaternionDiff::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let real = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.real, &other.real);
+        let dual = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.dual, &other.dual);
+
+        DualQuaternionDiff::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let real = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.real, &other.real, &max_abs_diff.real);
+        let dual = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.dual, &other.dual, &max_abs_diff.dual);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let real = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.real, &other.real, &max_ulps.real);
+        let dual = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.dual, &other.dual, &max_ulps.dual);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsAllEq for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = DualQuaternionTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance>;
+    type AllDebugUlpsTolerance = DualQuaternionTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let real = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.real, &other.real, max_abs_diff);
+        let dual = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.dual, &other.dual, max_abs_diff);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let real = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.real, &other.real, max_ulps);
+        let dual = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.dual, &other.dual, max_ulps);
+
+        DualQuaternionTol::from_parts(real, dual)
+    }
+}
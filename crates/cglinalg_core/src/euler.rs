@@ -3,6 +3,7 @@ use crate::matrix::{
     Matrix4x4,
 };
 use crate::quaternion::Quaternion;
+use crate::vector::Vector3;
 use cglinalg_numeric::SimdScalarFloat;
 use cglinalg_trigonometry::{
     Angle,
@@ -12,6 +13,264 @@ use cglinalg_trigonometry::{
 use core::fmt;
 
 
+/// The sequence of coordinate axes that a set of Euler angles rotates about.
+///
+/// There are twelve sequences of three axial rotations in which no two consecutive
+/// axes are the same. The six **Tait-Bryan** sequences rotate about each of the
+/// three coordinate axes once. The six **proper Euler** sequences rotate about the
+/// same axis first and last.
+///
+/// The sequences are named by the order in which their axes appear in the
+/// rotation sequence. Whether the later rotations act on the rotated axes or on
+/// the original axes is determined by the [`EulerFrame`] of an [`EulerOrder`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerAxes {
+    /// The Tait-Bryan sequence **x-axis**, **y-axis**, **z-axis**.
+    XYZ,
+    /// The Tait-Bryan sequence **x-axis**, **z-axis**, **y-axis**.
+    XZY,
+    /// The Tait-Bryan sequence **y-axis**, **x-axis**, **z-axis**.
+    YXZ,
+    /// The Tait-Bryan sequence **y-axis**, **z-axis**, **x-axis**.
+    YZX,
+    /// The Tait-Bryan sequence **z-axis**, **x-axis**, **y-axis**.
+    ZXY,
+    /// The Tait-Bryan sequence **z-axis**, **y-axis**, **x-axis**.
+    ZYX,
+    /// The proper Euler sequence **x-axis**, **y-axis**, **x-axis**.
+    XYX,
+    /// The proper Euler sequence **x-axis**, **z-axis**, **x-axis**.
+    XZX,
+    /// The proper Euler sequence **y-axis**, **x-axis**, **y-axis**.
+    YXY,
+    /// The proper Euler sequence **y-axis**, **z-axis**, **y-axis**.
+    YZY,
+    /// The proper Euler sequence **z-axis**, **x-axis**, **z-axis**.
+    ZXZ,
+    /// The proper Euler sequence **z-axis**, **y-axis**, **z-axis**.
+    ZYZ,
+}
+
+impl EulerAxes {
+    /// Determine whether the sequence is a proper Euler sequence, i.e. whether
+    /// its first and last axes are the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::EulerAxes;
+    /// #
+    /// assert!(EulerAxes::ZXZ.is_proper());
+    /// assert!(!EulerAxes::ZXY.is_proper());
+    /// ```
+    #[inline]
+    pub const fn is_proper(self) -> bool {
+        let [first, _, third] = self.sequence();
+
+        first == third
+    }
+
+    /// Determine whether the sequence is a Tait-Bryan sequence, i.e. whether it
+    /// rotates about each coordinate axis exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::EulerAxes;
+    /// #
+    /// assert!(EulerAxes::YXZ.is_tait_bryan());
+    /// assert!(!EulerAxes::YXY.is_tait_bryan());
+    /// ```
+    #[inline]
+    pub const fn is_tait_bryan(self) -> bool {
+        !self.is_proper()
+    }
+
+    /// Get the indices of the coordinate axes of the sequence in rotation order.
+    #[inline]
+    const fn sequence(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+/// The frame of reference that the axial rotations of a set of Euler angles
+/// rotate about.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerFrame {
+    /// Each rotation is about an axis of the frame rotated by the preceding
+    /// rotations. The intrinsic sequence `(a, b, c)` produces the rotation matrix
+    /// `R_a * R_b * R_c`.
+    Intrinsic,
+    /// Each rotation is about an axis of the fixed original frame. The extrinsic
+    /// sequence `(a, b, c)` produces the rotation matrix `R_c * R_b * R_a`.
+    Extrinsic,
+}
+
+/// A rotation order for a set of Euler angles.
+///
+/// A rotation order consists of the sequence of `axes` that the Euler angles
+/// rotate about, and the `frame` that determines whether each rotation is about
+/// the rotated axes (intrinsic) or about the original axes (extrinsic). An
+/// intrinsic rotation order is the same rotation as the extrinsic rotation order
+/// with the reversed axis sequence. For example, intrinsic **XYZ** and extrinsic
+/// **ZYX** both produce the rotation matrix `R_x(x) * R_y(y) * R_z(z)`.
+///
+/// # Angle Layout
+///
+/// For a Tait-Bryan sequence, each angle of an [`Euler`] is the angle of the
+/// rotation about the coordinate axis of the same name, regardless of its
+/// position in the sequence. This is the layout that Blender and Unity use. For a
+/// proper Euler sequence, the angles are stored in the order of the sequence: `x`
+/// holds the angle of the first rotation, `y` holds the angle of the second
+/// rotation, and `z` holds the angle of the third rotation.
+///
+/// # Common Rotation Orders
+///
+/// | Application          | Rotation order                        |
+/// |----------------------|---------------------------------------|
+/// | [`Euler`] default    | extrinsic **ZYX** (intrinsic **XYZ**) |
+/// | Blender `XYZ` mode   | extrinsic **XYZ** (intrinsic **ZYX**) |
+/// | Unity                | extrinsic **ZXY** (intrinsic **YXZ**) |
+/// | Classical mechanics  | intrinsic **ZXZ**                     |
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     EulerAxes,
+/// #     EulerFrame,
+/// #     EulerOrder,
+/// # };
+/// #
+/// let order = EulerOrder::new(EulerAxes::ZYX, EulerFrame::Extrinsic);
+///
+/// assert_eq!(order, EulerOrder::extrinsic(EulerAxes::ZYX));
+/// assert_eq!(EulerOrder::default(), order);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EulerOrder {
+    /// The sequence of coordinate axes of the rotations.
+    pub axes: EulerAxes,
+    /// The frame of reference of the rotations.
+    pub frame: EulerFrame,
+}
+
+impl EulerOrder {
+    /// Construct a new rotation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     EulerAxes,
+    /// #     EulerFrame,
+    /// #     EulerOrder,
+    /// # };
+    /// #
+    /// let order = EulerOrder::new(EulerAxes::ZXZ, EulerFrame::Intrinsic);
+    ///
+    /// assert_eq!(order.axes, EulerAxes::ZXZ);
+    /// assert_eq!(order.frame, EulerFrame::Intrinsic);
+    /// ```
+    #[inline]
+    pub const fn new(axes: EulerAxes, frame: EulerFrame) -> Self {
+        Self { axes, frame }
+    }
+
+    /// Construct an intrinsic rotation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     EulerAxes,
+    /// #     EulerFrame,
+    /// #     EulerOrder,
+    /// # };
+    /// #
+    /// let order = EulerOrder::intrinsic(EulerAxes::YXZ);
+    ///
+    /// assert_eq!(order, EulerOrder::new(EulerAxes::YXZ, EulerFrame::Intrinsic));
+    /// ```
+    #[inline]
+    pub const fn intrinsic(axes: EulerAxes) -> Self {
+        Self::new(axes, EulerFrame::Intrinsic)
+    }
+
+    /// Construct an extrinsic rotation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     EulerAxes,
+    /// #     EulerFrame,
+    /// #     EulerOrder,
+    /// # };
+    /// #
+    /// let order = EulerOrder::extrinsic(EulerAxes::XYZ);
+    ///
+    /// assert_eq!(order, EulerOrder::new(EulerAxes::XYZ, EulerFrame::Extrinsic));
+    /// ```
+    #[inline]
+    pub const fn extrinsic(axes: EulerAxes) -> Self {
+        Self::new(axes, EulerFrame::Extrinsic)
+    }
+
+    /// Get the indices of the coordinate axes of the equivalent intrinsic
+    /// sequence, whose rotation matrix is the product of the axial rotations
+    /// from left to right.
+    #[inline]
+    pub(crate) const fn intrinsic_sequence(self) -> [usize; 3] {
+        let [first, second, third] = self.axes.sequence();
+        match self.frame {
+            EulerFrame::Intrinsic => [first, second, third],
+            EulerFrame::Extrinsic => [third, second, first],
+        }
+    }
+
+    /// Get the indices of the fields of an [`Euler`] that hold the angles of the
+    /// axial rotations of the equivalent intrinsic sequence.
+    #[inline]
+    pub(crate) const fn intrinsic_fields(self) -> [usize; 3] {
+        if self.axes.is_proper() {
+            match self.frame {
+                EulerFrame::Intrinsic => [0, 1, 2],
+                EulerFrame::Extrinsic => [2, 1, 0],
+            }
+        } else {
+            self.intrinsic_sequence()
+        }
+    }
+}
+
+impl Default for EulerOrder {
+    #[inline]
+    fn default() -> Self {
+        Self::extrinsic(EulerAxes::ZYX)
+    }
+}
+
+impl fmt::Display for EulerOrder {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "EulerOrder [axes={:?}, frame={:?}]", self.axes, self.frame)
+    }
+}
+
+
 /// A data type storing a set of Euler angles for representing a rotation about
 /// an arbitrary axis in three dimensions.
 ///
@@ -27,6 +286,10 @@ use core::fmt;
 /// apply Euler angles, but the ZYX rotation order is one of the most commonly
 /// use rotation orders used in computer graphics.
 ///
+/// The other Tait-Bryan and proper Euler rotation orders, both intrinsic and
+/// extrinsic, are available through an [`EulerOrder`]. See
+/// [`Euler::to_matrix_with_order`] and [`Euler::from_matrix_with_order`].
+///
 /// ## Discussion
 ///
 /// Euler angles are prone to gimbal lock. Gimbal lock is the loss of one
@@ -492,8 +755,165 @@ where
         )
     }
 
+    /// Construct a rotation matrix from a set of Euler angles in a given
+    /// rotation order.
+    ///
+    /// The rotation matrix is the product of the three axial rotations of the
+    /// order. For an intrinsic order `(a, b, c)` the rotation matrix is
+    /// ```text
+    /// R == R_a(angle_a) * R_b(angle_b) * R_c(angle_c)
+    /// ```
+    /// and for an extrinsic order `(a, b, c)` the rotation matrix is
+    /// ```text
+    /// R == R_c(angle_c) * R_b(angle_b) * R_a(angle_a)
+    /// ```
+    /// See [`EulerOrder`] for which angle of the set of Euler angles belongs to
+    /// which axial rotation. In the default rotation order, extrinsic **ZYX**, this
+    /// is the same rotation matrix as [`Euler::to_matrix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let euler_angles = Euler::new(Degrees(30_f64), Degrees(45_f64), Degrees(60_f64));
+    /// let order = EulerOrder::extrinsic(EulerAxes::XYZ);
+    /// let expected = {
+    ///     let rotation_x = Matrix3x3::from_angle_x(Degrees(30_f64));
+    ///     let rotation_y = Matrix3x3::from_angle_y(Degrees(45_f64));
+    ///     let rotation_z = Matrix3x3::from_angle_z(Degrees(60_f64));
+    ///
+    ///     rotation_z * rotation_y * rotation_x
+    /// };
+    /// let result = euler_angles.to_matrix_with_order(order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_matrix_with_order(&self, order: EulerOrder) -> Matrix3x3<S> {
+        let axes = order.intrinsic_sequence();
+        let angles = self.intrinsic_angles(order);
+        let rotations: [Matrix3x3<S>; 3] = core::array::from_fn(|i| {
+            let (sin_angle, cos_angle) = angles[i].sin_cos();
+
+            axis_rotation_matrix(axes[i], sin_angle, cos_angle)
+        });
+
+        rotations[0] * rotations[1] * rotations[2]
+    }
+
+    /// Construct an affine rotation matrix from a set of Euler angles in a given
+    /// rotation order.
+    ///
+    /// The upper left 3x3 block of the affine matrix is the rotation matrix
+    /// [`Euler::to_matrix_with_order`] and the matrix has no translation terms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix4x4,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let euler_angles = Euler::new(Degrees(30_f64), Degrees(45_f64), Degrees(60_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::YXZ);
+    /// let expected = {
+    ///     let rotation_x = Matrix4x4::from_affine_angle_x(Degrees(30_f64));
+    ///     let rotation_y = Matrix4x4::from_affine_angle_y(Degrees(45_f64));
+    ///     let rotation_z = Matrix4x4::from_affine_angle_z(Degrees(60_f64));
+    ///
+    ///     rotation_y * rotation_x * rotation_z
+    /// };
+    /// let result = euler_angles.to_affine_matrix_with_order(order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn to_affine_matrix_with_order(&self, order: EulerOrder) -> Matrix4x4<S> {
+        let matrix = self.to_matrix_with_order(order);
+        let zero = S::zero();
+        let one = S::one();
+
+        Matrix4x4::new(
+            matrix.c0r0, matrix.c0r1, matrix.c0r2, zero,
+            matrix.c1r0, matrix.c1r1, matrix.c1r2, zero,
+            matrix.c2r0, matrix.c2r1, matrix.c2r2, zero,
+            zero,        zero,        zero,        one,
+        )
+    }
+
+    /// Construct a unit quaternion from a set of Euler angles in a given
+    /// rotation order.
+    ///
+    /// The quaternion is the product of the unit quaternions of the three axial
+    /// rotations of the order, in the same order as the rotation matrices in
+    /// [`Euler::to_matrix_with_order`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let euler_angles = Euler::new(Degrees(30_f64), Degrees(45_f64), Degrees(60_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZXZ);
+    /// let expected = euler_angles.to_matrix_with_order(order);
+    /// let result = euler_angles.to_quaternion_with_order(order).to_matrix();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_quaternion_with_order(&self, order: EulerOrder) -> Quaternion<S> {
+        let one_half = S::one() / (S::one() + S::one());
+        let axes = order.intrinsic_sequence();
+        let angles = self.intrinsic_angles(order);
+        let rotations: [Quaternion<S>; 3] = core::array::from_fn(|i| {
+            let (sin_half_angle, cos_half_angle) = (angles[i] * one_half).sin_cos();
+            let mut vector = Vector3::zero();
+            vector[axes[i]] = sin_half_angle;
+
+            Quaternion::from_parts(cos_half_angle, vector)
+        });
+
+        rotations[0] * rotations[1] * rotations[2]
+    }
+
+    /// Get the angles of the axial rotations of the equivalent intrinsic sequence
+    /// of a rotation order, in order from left to right.
     #[inline]
-    fn from_matrix(matrix: &Matrix3x3<S>) -> Euler<Radians<S>> {
+    pub(crate) fn intrinsic_angles(&self, order: EulerOrder) -> [A; 3] {
+        order.intrinsic_fields().map(|field| self.angle(field))
+    }
+
+    #[inline]
+    fn angle(&self, axis: usize) -> A {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            _ => self.z,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_matrix(matrix: &Matrix3x3<S>) -> Euler<Radians<S>> {
         let yaw = Radians::asin(matrix[2][0]);
         let cos_yaw = Radians::cos(yaw);
         let (pitch, roll) = if cos_yaw.abs().is_zero() {
@@ -512,6 +932,141 @@ where
     }
 }
 
+impl<S> Euler<Radians<S>>
+where
+    S: SimdScalarFloat,
+{
+    /// Extract a set of Euler angles in a given rotation order from a rotation
+    /// matrix.
+    ///
+    /// The matrix is converted to a unit quaternion, and the angles are extracted
+    /// from the quaternion with [`Euler::from_quaternion_with_order`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let expected = Euler::new(Radians(0.1_f64), Radians(-0.7_f64), Radians(2.3_f64));
+    /// let order = EulerOrder::extrinsic(EulerAxes::ZXY);
+    /// let matrix = expected.to_matrix_with_order(order);
+    /// let result = Euler::from_matrix_with_order(&matrix, order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_matrix_with_order(matrix: &Matrix3x3<S>, order: EulerOrder) -> Self {
+        let quaternion = Quaternion::from_matrix(matrix);
+
+        Self::from_quaternion_with_order(&quaternion, order)
+    }
+
+    /// Extract a set of Euler angles in a given rotation order from a quaternion.
+    ///
+    /// The quaternion does not need to have unit norm, since every nonzero
+    /// multiple of a quaternion describes the same rotation.
+    ///
+    /// The angles are in the ranges
+    /// ```text
+    /// first  in [-pi, pi]
+    /// second in [-pi/2, pi/2]  (Tait-Bryan sequences)
+    /// second in [0, pi]        (proper Euler sequences)
+    /// third  in [-pi, pi]
+    /// ```
+    /// where the first, second, and third angles are the angles of the rotations
+    /// in the order of the sequence.
+    ///
+    /// # Gimbal Lock
+    ///
+    /// When the second angle is at either end of its range, the first and third
+    /// rotations are about the same axis, and only their combined angle is
+    /// determined by the rotation. In this case the angle of the axial rotation
+    /// that acts on a vector first is set to zero: the third angle of an intrinsic
+    /// order, or the first angle of an extrinsic order.
+    ///
+    /// # Discussion
+    ///
+    /// The angles are computed directly from the quaternion components with the
+    /// method of Bernardes and Viollet [1], which handles every rotation order
+    /// and frame uniformly and remains accurate near gimbal lock.
+    ///
+    /// # References
+    ///
+    /// [1] Evandro Bernardes, Stéphane Viollet. Quaternion to Euler angles
+    ///     conversion: A direct, general and computationally efficient method.
+    ///     PLoS ONE 17(11): e0276302 (2022).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let expected = Euler::new(Radians(0.4_f64), Radians(1.2_f64), Radians(-2.5_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZXZ);
+    /// let quaternion = expected.to_quaternion_with_order(order);
+    /// let result = Euler::from_quaternion_with_order(&quaternion, order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_quaternion_with_order(quaternion: &Quaternion<S>, order: EulerOrder) -> Self {
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let [i, j, k] = order.intrinsic_sequence();
+        let is_proper = i == k;
+        // The remaining axis completes the proper Euler sequence to a right-handed
+        // or left-handed triple of axes.
+        let k = if is_proper { 3 - i - j } else { k };
+        let sign = if j == (i + 1) % 3 { S::one() } else { -S::one() };
+        let qs = quaternion.scalar();
+        let qv = quaternion.vector();
+
+        // A Tait-Bryan sequence is a proper Euler sequence after rotating the
+        // third axis onto the first axis by a quarter turn about the second axis.
+        let (a, b, c, d) = if is_proper {
+            (qs, qv[i], qv[j], sign * qv[k])
+        } else {
+            (qs - qv[j], qv[i] - sign * qv[k], qv[j] + qs, qv[i] + sign * qv[k])
+        };
+
+        let second = two * S::atan2(S::hypot(c, d), S::hypot(a, b));
+        let half_sum = S::atan2(b, a);
+        let half_difference = S::atan2(d, c);
+        let tolerance = S::default_epsilon() * two;
+        let (first, third) = if second.abs() <= tolerance {
+            (two * half_sum, zero)
+        } else if (second - S::pi()).abs() <= tolerance {
+            (two * half_difference, zero)
+        } else {
+            (half_sum + half_difference, half_sum - half_difference)
+        };
+        let (second, third) = if is_proper {
+            (second, third)
+        } else {
+            (second - S::frac_pi_2(), -sign * third)
+        };
+
+        let intrinsic_angles = [wrap_angle(first), second, wrap_angle(third)];
+        let mut angles = [zero; 3];
+        for (field, angle) in order.intrinsic_fields().into_iter().zip(intrinsic_angles) {
+            angles[field] = angle;
+        }
+
+        Euler::new(Radians(angles[0]), Radians(angles[1]), Radians(angles[2]))
+    }
+}
+
 impl<A> fmt::Display for Euler<A>
 where
     A: fmt::Display,
@@ -849,3 +1404,45 @@ where
         )
     }
 }
+
+
+#[rustfmt::skip]
+#[inline]
+pub(crate) fn axis_rotation_matrix<S>(axis: usize, sin_angle: S, cos_angle: S) -> Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    let zero = S::zero();
+    let one = S::one();
+    match axis {
+        0 => Matrix3x3::new(
+            one,   zero,       zero,
+            zero,  cos_angle,  sin_angle,
+            zero, -sin_angle,  cos_angle,
+        ),
+        1 => Matrix3x3::new(
+            cos_angle, zero, -sin_angle,
+            zero,      one,   zero,
+            sin_angle, zero,  cos_angle,
+        ),
+        _ => Matrix3x3::new(
+             cos_angle, sin_angle, zero,
+            -sin_angle, cos_angle, zero,
+             zero,      zero,      one,
+        ),
+    }
+}
+
+#[inline]
+fn wrap_angle<S>(angle: S) -> S
+where
+    S: SimdScalarFloat,
+{
+    if angle > S::pi() {
+        angle - S::two_pi()
+    } else if angle < -S::pi() {
+        angle + S::two_pi()
+    } else {
+        angle
+    }
+}
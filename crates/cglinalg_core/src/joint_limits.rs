@@ -0,0 +1,435 @@
+use crate::quaternion::Quaternion;
+use crate::unit::Unit;
+use crate::vector::Vector3;
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_trigonometry::Radians;
+
+use core::fmt;
+
+
+/// Swing and twist limits on the rotation of a joint.
+///
+/// The limits constrain a rotation relative to a **twist axis**, as in the
+/// ball-and-socket joints of inverse kinematics rigs and ragdolls. The rotation
+/// is decomposed into a **twist** about the twist axis followed by a **swing**
+/// about an axis perpendicular to it (see [`Quaternion::swing_twist`]).
+///
+/// * The twist angle is limited to the range `[twist_min, twist_max]`, where
+///   `-pi <= twist_min <= twist_max <= pi`.
+/// * The swing is limited to an elliptical cone about the twist axis. Let `phi`
+///   be the rotation vector of the swing, and let `phi_1` and `phi_2` be its
+///   components along the two **swing axes**, a pair of orthogonal unit vectors
+///   perpendicular to the twist axis. The swing satisfies the limits when
+///   ```text
+///   (phi_1 / swing_limit_1)^2 + (phi_2 / swing_limit_2)^2 <= 1
+///   ```
+///   so `swing_limit_1` is the largest swing angle about the first swing axis,
+///   and `swing_limit_2` is the largest swing angle about the second swing axis.
+///   The swing limits lie in the range `[0, pi]`, and the cone is circular
+///   when they are equal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SwingTwistLimits<S> {
+    twist_axis: Unit<Vector3<S>>,
+    swing_axis_1: Unit<Vector3<S>>,
+    swing_axis_2: Unit<Vector3<S>>,
+    swing_limit_1: Radians<S>,
+    swing_limit_2: Radians<S>,
+    twist_min: Radians<S>,
+    twist_max: Radians<S>,
+}
+
+impl<S> SwingTwistLimits<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct joint limits with a circular swing cone.
+    ///
+    /// The swing angle is limited to at most `swing_limit` in every direction,
+    /// and the twist angle about `twist_axis` is limited to the range
+    /// `[twist_min, twist_max]`.
+    ///
+    /// Returns `None` if `swing_limit` is negative, or if `twist_min` is greater
+    /// than `twist_max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::unit_z());
+    /// let limits = SwingTwistLimits::new_cone(&twist_axis, Degrees(45_f64), Degrees(-30_f64), Degrees(60_f64)).unwrap();
+    ///
+    /// assert_eq!(limits.twist_axis(), &twist_axis);
+    /// assert_eq!(limits.swing_limits(), (Degrees(45_f64).into(), Degrees(45_f64).into()));
+    /// assert_eq!(limits.twist_limits(), (Degrees(-30_f64).into(), Degrees(60_f64).into()));
+    ///
+    /// assert!(SwingTwistLimits::new_cone(&twist_axis, Degrees(-45_f64), Degrees(-30_f64), Degrees(60_f64)).is_none());
+    /// assert!(SwingTwistLimits::new_cone(&twist_axis, Degrees(45_f64), Degrees(60_f64), Degrees(-30_f64)).is_none());
+    /// ```
+    #[inline]
+    pub fn new_cone<A>(twist_axis: &Unit<Vector3<S>>, swing_limit: A, twist_min: A, twist_max: A) -> Option<Self>
+    where
+        A: Into<Radians<S>>,
+    {
+        let axis = twist_axis.as_ref();
+        // Cross with the coordinate axis least aligned with the twist axis, so the
+        // result is far from zero.
+        let (x, y, z) = (axis[0].abs(), axis[1].abs(), axis[2].abs());
+        let other = if x <= y && x <= z {
+            Vector3::unit_x()
+        } else if y <= z {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+        let swing_axis_1 = Unit::from_value(axis.cross(&other));
+        let swing_axis_2 = Unit::from_value(axis.cross(&swing_axis_1));
+        let swing_limit = swing_limit.into();
+
+        Self::try_from_parts(
+            twist_axis,
+            swing_axis_1,
+            swing_axis_2,
+            swing_limit,
+            swing_limit,
+            twist_min.into(),
+            twist_max.into(),
+        )
+    }
+
+    /// Construct joint limits with an elliptical swing cone.
+    ///
+    /// The first swing axis is the component of `swing_axis` perpendicular to
+    /// `twist_axis`, and the second swing axis is the cross product of the twist
+    /// axis and the first swing axis. The swing angle about the first swing axis
+    /// is limited to at most `swing_limit_1`, the swing angle about the second
+    /// swing axis is limited to at most `swing_limit_2`, and the twist angle about
+    /// `twist_axis` is limited to the range `[twist_min, twist_max]`.
+    ///
+    /// Returns `None` if `swing_axis` is parallel to `twist_axis`, if either swing
+    /// limit is negative, or if `twist_min` is greater than `twist_max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::unit_x());
+    /// let swing_axis = Vector3::new(1_f64, 1_f64, 0_f64);
+    /// let limits = SwingTwistLimits::new_elliptical_cone(
+    ///     &twist_axis,
+    ///     &swing_axis,
+    ///     Degrees(70_f64),
+    ///     Degrees(20_f64),
+    ///     Degrees(-10_f64),
+    ///     Degrees(10_f64),
+    /// )
+    /// .unwrap();
+    /// let (swing_axis_1, swing_axis_2) = limits.swing_axes();
+    ///
+    /// assert_eq!(swing_axis_1.as_ref(), &Vector3::unit_y());
+    /// assert_eq!(swing_axis_2.as_ref(), &Vector3::unit_z());
+    ///
+    /// let result = SwingTwistLimits::new_elliptical_cone(
+    ///     &twist_axis,
+    ///     &Vector3::new(-2_f64, 0_f64, 0_f64),
+    ///     Degrees(70_f64),
+    ///     Degrees(20_f64),
+    ///     Degrees(-10_f64),
+    ///     Degrees(10_f64),
+    /// );
+    ///
+    /// assert!(result.is_none());
+    ///
+    /// let result = SwingTwistLimits::new_elliptical_cone(
+    ///     &twist_axis,
+    ///     &swing_axis,
+    ///     Degrees(70_f64),
+    ///     Degrees(-20_f64),
+    ///     Degrees(-10_f64),
+    ///     Degrees(10_f64),
+    /// );
+    ///
+    /// assert!(result.is_none());
+    /// ```
+    #[inline]
+    pub fn new_elliptical_cone<A>(
+        twist_axis: &Unit<Vector3<S>>,
+        swing_axis: &Vector3<S>,
+        swing_limit_1: A,
+        swing_limit_2: A,
+        twist_min: A,
+        twist_max: A,
+    ) -> Option<Self>
+    where
+        A: Into<Radians<S>>,
+    {
+        let axis = twist_axis.as_ref();
+        let perpendicular = swing_axis - axis * swing_axis.dot(axis);
+        let threshold = S::default_epsilon() * swing_axis.norm();
+
+        let swing_axis_1 = Unit::try_from_value(perpendicular, threshold)?;
+        let swing_axis_2 = Unit::from_value(axis.cross(&swing_axis_1));
+
+        Self::try_from_parts(
+            twist_axis,
+            swing_axis_1,
+            swing_axis_2,
+            swing_limit_1.into(),
+            swing_limit_2.into(),
+            twist_min.into(),
+            twist_max.into(),
+        )
+    }
+
+    /// Construct joint limits from a twist axis and a pair of swing axes
+    /// perpendicular to it, if the limits are well-ordered.
+    ///
+    /// Returns `None` if either swing limit is negative, or if the lower twist
+    /// limit is greater than the upper twist limit.
+    #[inline]
+    fn try_from_parts(
+        twist_axis: &Unit<Vector3<S>>,
+        swing_axis_1: Unit<Vector3<S>>,
+        swing_axis_2: Unit<Vector3<S>>,
+        swing_limit_1: Radians<S>,
+        swing_limit_2: Radians<S>,
+        twist_min: Radians<S>,
+        twist_max: Radians<S>,
+    ) -> Option<Self> {
+        if swing_limit_1.0 < S::zero() || swing_limit_2.0 < S::zero() || twist_min.0 > twist_max.0 {
+            return None;
+        }
+
+        Some(Self {
+            twist_axis: *twist_axis,
+            swing_axis_1,
+            swing_axis_2,
+            swing_limit_1,
+            swing_limit_2,
+            twist_min,
+            twist_max,
+        })
+    }
+
+    /// Get the twist axis of the joint limits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64));
+    /// let limits = SwingTwistLimits::new_cone(&twist_axis, Degrees(30_f64), Degrees(0_f64), Degrees(0_f64)).unwrap();
+    ///
+    /// assert_eq!(limits.twist_axis(), &twist_axis);
+    /// ```
+    #[inline]
+    pub const fn twist_axis(&self) -> &Unit<Vector3<S>> {
+        &self.twist_axis
+    }
+
+    /// Get the two swing axes of the joint limits.
+    ///
+    /// The swing axes are orthogonal unit vectors perpendicular to the twist
+    /// axis, and form a right-handed frame with it in the order
+    /// `(swing_axis_1, swing_axis_2, twist_axis)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 3_f64));
+    /// let limits = SwingTwistLimits::new_cone(&twist_axis, Degrees(30_f64), Degrees(-5_f64), Degrees(5_f64)).unwrap();
+    /// let (swing_axis_1, swing_axis_2) = limits.swing_axes();
+    ///
+    /// assert_relative_eq!(swing_axis_1.dot(&twist_axis), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// assert_relative_eq!(swing_axis_2.dot(&twist_axis), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// assert_relative_eq!(
+    ///     swing_axis_1.cross(&swing_axis_2),
+    ///     twist_axis.into_inner(),
+    ///     abs_diff_all <= 1e-12,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    #[inline]
+    pub const fn swing_axes(&self) -> (&Unit<Vector3<S>>, &Unit<Vector3<S>>) {
+        (&self.swing_axis_1, &self.swing_axis_2)
+    }
+
+    /// Get the largest swing angles about the two swing axes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::unit_y());
+    /// let limits = SwingTwistLimits::new_elliptical_cone(
+    ///     &twist_axis,
+    ///     &Vector3::unit_z(),
+    ///     Radians(0.5_f64),
+    ///     Radians(0.25_f64),
+    ///     Radians(-1_f64),
+    ///     Radians(1_f64),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(limits.swing_limits(), (Radians(0.5_f64), Radians(0.25_f64)));
+    /// ```
+    #[inline]
+    pub const fn swing_limits(&self) -> (Radians<S>, Radians<S>) {
+        (self.swing_limit_1, self.swing_limit_2)
+    }
+
+    /// Get the smallest and largest twist angles about the twist axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::unit_y());
+    /// let limits = SwingTwistLimits::new_cone(&twist_axis, Radians(0.5_f64), Radians(-0.2_f64), Radians(0.7_f64)).unwrap();
+    ///
+    /// assert_eq!(limits.twist_limits(), (Radians(-0.2_f64), Radians(0.7_f64)));
+    /// ```
+    #[inline]
+    pub const fn twist_limits(&self) -> (Radians<S>, Radians<S>) {
+        (self.twist_min, self.twist_max)
+    }
+}
+
+impl<S> fmt::Display for SwingTwistLimits<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "SwingTwistLimits [twist_axis={}, swing_axes=({}, {}), swing_limits=({}, {}), twist_limits=({}, {})]",
+            self.twist_axis, self.swing_axis_1, self.swing_axis_2, self.swing_limit_1, self.swing_limit_2, self.twist_min, self.twist_max,
+        )
+    }
+}
+
+impl<S> Quaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Clamp a unit quaternion to a set of swing and twist joint limits.
+    ///
+    /// The function decomposes the rotation into a swing and a twist about the
+    /// twist axis of the limits, clamps the twist angle to the twist range, and
+    /// pulls the swing back onto the boundary of the swing cone by scaling its
+    /// angle while keeping its axis. The function returns the clamped rotation,
+    /// and whether any limit was hit. When no limit is hit, the rotation is
+    /// returned unchanged. The clamped rotation lies in the same hemisphere as
+    /// the original quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::unit_z());
+    /// let limits = SwingTwistLimits::new_cone(&twist_axis, Degrees(30_f64), Degrees(-45_f64), Degrees(45_f64)).unwrap();
+    ///
+    /// // A rotation inside the limits is unchanged.
+    /// let rotation = Quaternion::from_axis_angle(&twist_axis, Degrees(40_f64));
+    /// let (result, clamped) = rotation.clamp_swing_twist(&limits);
+    ///
+    /// assert_eq!(result, rotation);
+    /// assert!(!clamped);
+    ///
+    /// // A swing past the edge of the cone is pulled back to the cone.
+    /// let unit_x = Unit::from_value(Vector3::unit_x());
+    /// let rotation = Quaternion::from_axis_angle(&unit_x, Degrees(50_f64));
+    /// let expected = Quaternion::from_axis_angle(&unit_x, Degrees(30_f64));
+    /// let (result, clamped) = rotation.clamp_swing_twist(&limits);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(clamped);
+    /// ```
+    #[inline]
+    pub fn clamp_swing_twist(&self, limits: &SwingTwistLimits<S>) -> (Self, bool) {
+        let (swing, twist) = self.swing_twist(&limits.twist_axis);
+        let two = S::one() + S::one();
+        // The twist has a nonnegative scalar part, so its angle lies in `[-pi, pi]`.
+        let twist_angle = two * twist.vector().dot(&limits.twist_axis).atan2(twist.scalar());
+        let clamped_twist_angle = twist_angle.clamp(limits.twist_min.0, limits.twist_max.0);
+        let twist_clamped = clamped_twist_angle != twist_angle;
+
+        let swing_vector = swing.scaled_axis();
+        let ratio_squared = |component: S, limit: Radians<S>| {
+            if component == S::zero() {
+                S::zero()
+            } else {
+                let ratio = component / limit.0;
+
+                ratio * ratio
+            }
+        };
+        let swing_norm_squared = ratio_squared(swing_vector.dot(&limits.swing_axis_1), limits.swing_limit_1)
+            + ratio_squared(swing_vector.dot(&limits.swing_axis_2), limits.swing_limit_2);
+        let swing_clamped = swing_norm_squared > S::one();
+
+        if !twist_clamped && !swing_clamped {
+            return (*self, false);
+        }
+
+        let twist = if twist_clamped {
+            Self::from_axis_angle(&limits.twist_axis, Radians(clamped_twist_angle))
+        } else {
+            twist
+        };
+        let swing = if swing_clamped {
+            Self::from_scaled_axis(&(swing_vector / swing_norm_squared.sqrt()))
+        } else {
+            swing
+        };
+        let result = swing * twist;
+        let result = if result.dot(self) < S::zero() { -result } else { result };
+
+        (result, true)
+    }
+}
@@ -1,10 +1,18 @@
+mod cholesky;
 mod complex;
+mod dual_quaternion;
 mod euler;
+mod joint_limits;
+mod lu;
 mod matrix;
 mod matrix_euler;
+mod ndc;
 mod normed;
 mod point;
+mod qr;
 mod quaternion;
+mod svd;
+mod symmetric_eigen;
 mod unit;
 mod vector;
 
@@ -12,13 +20,20 @@ mod constraint;
 mod coordinates;
 
 
+pub use cholesky::*;
 pub use complex::*;
 pub use constraint::*;
+pub use dual_quaternion::*;
 pub use euler::*;
+pub use joint_limits::*;
+pub use lu::*;
 pub use matrix::*;
-// pub use matrix_euler::*;
+pub use ndc::*;
 pub use normed::*;
 pub use point::*;
+pub use qr::*;
 pub use quaternion::*;
+pub use svd::*;
+pub use symmetric_eigen::*;
 pub use unit::*;
 pub use vector::*;
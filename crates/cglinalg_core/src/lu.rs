@@ -0,0 +1,456 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The LU decomposition of a square matrix with partial pivoting.
+///
+/// The decomposition of a square matrix `a` is a permutation matrix `p`, a unit
+/// lower triangular matrix `l`, and an upper triangular matrix `u` such that
+/// ```text
+/// p * a == l * u
+/// ```
+/// At each step of the elimination, the row with the largest pivot in absolute
+/// value is swapped into place, which keeps every entry of `l` at most one in
+/// absolute value. The factors `l` and `u` share a single matrix: `l` occupies
+/// the strict lower triangle, and `u` occupies the upper triangle including the
+/// diagonal.
+///
+/// Every square matrix has an LU decomposition with partial pivoting. The
+/// matrix is singular exactly when a diagonal element of `u` is zero, in which
+/// case the decomposition can still compute the determinant, but it cannot
+/// solve linear systems or invert the matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LuDecomposition<S, const N: usize> {
+    lu: Matrix<S, N, N>,
+    permutation: [usize; N],
+    sign: S,
+}
+
+impl<S, const N: usize> LuDecomposition<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the LU decomposition of a square matrix with partial pivoting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 2_f64, 4_f64,
+    ///     3_f64, 8_f64, 14_f64,
+    ///     2_f64, 6_f64, 13_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    ///
+    /// assert_relative_eq!(
+    ///     lu.p() * matrix,
+    ///     lu.l() * lu.u(),
+    ///     abs_diff_all <= 1e-12,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    pub fn new(matrix: &Matrix<S, N, N>) -> Self {
+        let mut lu = *matrix;
+        let mut permutation = [0; N];
+        for (i, index) in permutation.iter_mut().enumerate() {
+            *index = i;
+        }
+        let mut sign = S::one();
+
+        for k in 0..N {
+            let mut pivot_row = k;
+            let mut pivot_abs = lu[k][k].abs();
+            for r in (k + 1)..N {
+                let candidate_abs = lu[k][r].abs();
+                if candidate_abs > pivot_abs {
+                    pivot_row = r;
+                    pivot_abs = candidate_abs;
+                }
+            }
+
+            if pivot_row != k {
+                lu.swap_rows(k, pivot_row);
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = lu[k][k];
+            if pivot.is_zero() {
+                // The column below the diagonal is already zero, so there is
+                // nothing left to eliminate.
+                continue;
+            }
+
+            for r in (k + 1)..N {
+                lu[k][r] /= pivot;
+            }
+            let multipliers = lu[k];
+            for c in (k + 1)..N {
+                let factor = lu[c][k];
+                for r in (k + 1)..N {
+                    lu[c][r] -= multipliers[r] * factor;
+                }
+            }
+        }
+
+        Self { lu, permutation, sign }
+    }
+
+    /// Get the unit lower triangular factor `l` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     2_f64, 4_f64,
+    ///     1_f64, 1_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    /// let expected = Matrix2x2::new(
+    ///     1_f64, 0.5_f64,
+    ///     0_f64, 1_f64,
+    /// );
+    ///
+    /// assert_eq!(lu.l(), expected);
+    /// ```
+    pub fn l(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::identity();
+        for c in 0..N {
+            for r in (c + 1)..N {
+                result[c][r] = self.lu[c][r];
+            }
+        }
+
+        result
+    }
+
+    /// Get the upper triangular factor `u` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     2_f64, 4_f64,
+    ///     1_f64, 1_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    /// let expected = Matrix2x2::new(
+    ///     4_f64, 0_f64,
+    ///     1_f64, 0.5_f64,
+    /// );
+    ///
+    /// assert_eq!(lu.u(), expected);
+    /// ```
+    pub fn u(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::zero();
+        for c in 0..N {
+            for r in 0..=c {
+                result[c][r] = self.lu[c][r];
+            }
+        }
+
+        result
+    }
+
+    /// Get the permutation matrix `p` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1_f64, 4_f64,
+    ///     2_f64, 1_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    /// let expected = Matrix2x2::new(
+    ///     0_f64, 1_f64,
+    ///     1_f64, 0_f64,
+    /// );
+    ///
+    /// assert_eq!(lu.p(), expected);
+    /// ```
+    pub fn p(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::zero();
+        for (r, &index) in self.permutation.iter().enumerate() {
+            result[index][r] = S::one();
+        }
+
+        result
+    }
+
+    /// Get the row permutation of the decomposition.
+    ///
+    /// Row `i` of `p * a` is row `permutation[i]` of the original matrix `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     0_f64, 1_f64, 3_f64,
+    ///     1_f64, 0_f64, 0_f64,
+    ///     0_f64, 2_f64, 1_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    ///
+    /// assert_eq!(lu.permutation(), &[2, 0, 1]);
+    /// ```
+    #[inline]
+    pub const fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    /// Compute the determinant of the decomposed matrix.
+    ///
+    /// The determinant is the product of the diagonal of `u`, with its sign
+    /// flipped for each row swap in the permutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_f64, 4_f64, 7_f64,  8_f64,
+    ///     2_f64, 5_f64, 8_f64,  4_f64,
+    ///     5_f64, 6_f64, 11_f64, 4_f64,
+    ///     9_f64, 3_f64, 13_f64, 5_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    ///
+    /// assert_relative_eq!(lu.determinant(), matrix.determinant(), abs_diff <= 1e-10, relative <= f64::EPSILON);
+    /// ```
+    pub fn determinant(&self) -> S {
+        let mut result = self.sign;
+        for i in 0..N {
+            result *= self.lu[i][i];
+        }
+
+        result
+    }
+
+    /// Determine whether the decomposed matrix is invertible.
+    ///
+    /// The matrix is invertible when every diagonal element of `u` is nonzero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     5_f64, 7_f64, 9_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    ///
+    /// assert!(!lu.is_invertible());
+    /// ```
+    pub fn is_invertible(&self) -> bool {
+        (0..N).all(|i| !self.lu[i][i].is_zero())
+    }
+
+    /// Solve the linear system `a * x == b` for `x`, if the matrix `a` is
+    /// invertible.
+    ///
+    /// The system is solved by forward and back substitution with the triangular
+    /// factors, without forming the inverse of `a`. Returns `None` if `a` is
+    /// singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     2_f64, -3_f64, -2_f64,
+    ///     1_f64, -1_f64,  1_f64,
+    ///    -1_f64,  2_f64,  2_f64,
+    /// );
+    /// let b = Vector3::new(8_f64, -11_f64, -3_f64);
+    /// let lu = LuDecomposition::new(&matrix);
+    /// let expected = Vector3::new(2_f64, 3_f64, -1_f64);
+    /// let result = lu.solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(matrix * result, b, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn solve(&self, b: &Vector<S, N>) -> Option<Vector<S, N>> {
+        if !self.is_invertible() {
+            return None;
+        }
+
+        let mut result = Vector::zero();
+        // Solve `l * y == p * b` by forward substitution.
+        for (i, &index) in self.permutation.iter().enumerate() {
+            let mut sum = b[index];
+            for j in 0..i {
+                sum -= self.lu[j][i] * result[j];
+            }
+            result[i] = sum;
+        }
+        // Solve `u * x == y` by back substitution.
+        for i in (0..N).rev() {
+            let mut sum = result[i];
+            for j in (i + 1)..N {
+                sum -= self.lu[j][i] * result[j];
+            }
+            result[i] = sum / self.lu[i][i];
+        }
+
+        Some(result)
+    }
+
+    /// Compute the inverse of the decomposed matrix, if it exists.
+    ///
+    /// Each column of the inverse is the solution of a linear system with the
+    /// corresponding column of the identity matrix. Prefer [`LuDecomposition::solve`]
+    /// for solving linear systems, since it is both cheaper and more accurate
+    /// than multiplying by the inverse. Returns `None` if the matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix4x4,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     1_f64, 4_f64, 7_f64,  8_f64,
+    ///     2_f64, 5_f64, 8_f64,  4_f64,
+    ///     5_f64, 6_f64, 11_f64, 4_f64,
+    ///     9_f64, 3_f64, 13_f64, 5_f64,
+    /// );
+    /// let expected = Matrix4x4::new(
+    ///      17_f64 / 60_f64, -41_f64 / 30_f64,  21_f64 / 20_f64, -1_f64 / 5_f64,
+    ///      7_f64 / 30_f64,  -16_f64 / 15_f64,  11_f64 / 10_f64, -2_f64 / 5_f64,
+    ///     -13_f64 / 36_f64,  25_f64 / 18_f64, -13_f64 / 12_f64,  1_f64 / 3_f64,
+    ///      13_f64 / 45_f64, -23_f64 / 45_f64,  4_f64 / 15_f64,  -1_f64 / 15_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    /// let result = lu.try_inverse().unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn try_inverse(&self) -> Option<Matrix<S, N, N>> {
+        if !self.is_invertible() {
+            return None;
+        }
+
+        let mut result = Matrix::zero();
+        for c in 0..N {
+            let mut unit = Vector::zero();
+            unit[c] = S::one();
+            result[c] = self.solve(&unit)?;
+        }
+
+        Some(result)
+    }
+
+    /// Compute the inverse of the decomposed matrix.
+    ///
+    /// # Safety
+    ///
+    /// Panics if the matrix is not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     LuDecomposition,
+    /// #     Matrix2x2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     7_f64, 6_f64,
+    /// );
+    /// let lu = LuDecomposition::new(&matrix);
+    /// let result = lu.inverse();
+    ///
+    /// assert_relative_eq!(result * matrix, Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Matrix<S, N, N> {
+        self.try_inverse().unwrap()
+    }
+}
+
+impl<S, const N: usize> Matrix<S, N, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the LU decomposition of a square matrix with partial pivoting.
+    ///
+    /// This works for square matrices of any size. See [`LuDecomposition`] for
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix,
+    /// #     Vector,
+    /// # };
+    /// #
+    /// let matrix: Matrix<f64, 5, 5> = Matrix::from([
+    ///     [4_f64, 1_f64, 0_f64, 0_f64, 0_f64],
+    ///     [1_f64, 4_f64, 1_f64, 0_f64, 0_f64],
+    ///     [0_f64, 1_f64, 4_f64, 1_f64, 0_f64],
+    ///     [0_f64, 0_f64, 1_f64, 4_f64, 1_f64],
+    ///     [0_f64, 0_f64, 0_f64, 1_f64, 4_f64],
+    /// ]);
+    /// let b = Vector::from([5_f64, 6_f64, 6_f64, 6_f64, 5_f64]);
+    /// let expected = Vector::from([1_f64, 1_f64, 1_f64, 1_f64, 1_f64]);
+    /// let result = matrix.lu().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn lu(&self) -> LuDecomposition<S, N> {
+        LuDecomposition::new(self)
+    }
+}
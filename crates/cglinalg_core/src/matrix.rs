@@ -9,6 +9,7 @@ use crate::constraint::{
     DimSub,
     ShapeConstraint,
 };
+use crate::ndc::NdcConvention;
 use crate::normed::{
     Norm,
     Normed,
@@ -7265,6 +7266,582 @@ where
         )
     }
 
+    /// Construct a new possibly asymmetric orthographic projection matrix based
+    /// on the location of the **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, **near plane**, and **far plane**, for an arbitrary
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in
+    /// [`Matrix4x4::from_orthographic`], except that the **near plane** and the
+    /// **far plane** are placed along the viewing direction of the view space
+    /// that `ndc` specifies. That is, the planes lie along the **negative z-axis**
+    /// for a right-handed view space, and along the **positive z-axis** for a
+    /// left-handed view space. The matrix maps the view volume onto the canonical
+    /// view volume `[-1, 1] x [-1, 1] x [z_near, z_far]`, where
+    /// `(z_near, z_far) == ndc.depth_bounds()`, and flips the **y-axis** when the
+    /// normalized device coordinates **y-axis** points down.
+    ///
+    /// # Matrix Representation Of The Orthographic Projection Transformation
+    ///
+    /// Let `g := ndc.gaze_sign()`, `s := ndc.y_axis_sign()`, and
+    /// `(z_near, z_far) := ndc.depth_bounds()`. The underlying matrix is a
+    /// homogeneous affine matrix with the following form
+    ///
+    /// ```text
+    /// [ m[0, 0]  0        0        m[3, 0] ]
+    /// [ 0        m[1, 1]  0        m[3, 1] ]
+    /// [ 0        0        m[2, 2]  m[3, 2] ]
+    /// [ 0        0        0        1       ]
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    /// m[0, 0] ==  2 / (right + left)
+    /// m[3, 0] == -(right - left) / (right + left)
+    /// m[1, 1] ==  s * (2 / (top + bottom))
+    /// m[3, 1] == -s * ((top - bottom) / (top + bottom))
+    /// m[2, 2] ==  g * ((z_far - z_near) / (far - near))
+    /// m[3, 2] ==  (z_near * far - z_far * near) / (far - near)
+    /// ```
+    ///
+    /// where the matrix entries are indexed in column-major order. With
+    /// `ndc == NdcConvention::OPENGL`, this is the same matrix as the one that
+    /// [`Matrix4x4::from_orthographic`] constructs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// #
+    /// let left = 4_f64;
+    /// let right = 4_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let expected = Matrix4x4::new(
+    ///     1_f64 / 4_f64,  0_f64,          0_f64,           0_f64,
+    ///     0_f64,         -1_f64 / 2_f64,  0_f64,           0_f64,
+    ///     0_f64,          0_f64,         -1_f64 / 99_f64,  0_f64,
+    ///     0_f64,          0_f64,         -1_f64 / 99_f64,  1_f64,
+    /// );
+    /// let result = Matrix4x4::from_orthographic_with_ndc(left, right, bottom, top, near, far, NdcConvention::VULKAN);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_orthographic_with_ndc(left: S, right: S, bottom: S, top: S, near: S, far: S, ndc: NdcConvention) -> Self {
+        let zero = S::zero();
+        let one  = S::one();
+        let two = one + one;
+        let gaze_sign = ndc.gaze_sign::<S>();
+        let y_axis_sign = ndc.y_axis_sign::<S>();
+        let (z_near, z_far) = ndc.depth_bounds::<S>();
+
+        let c0r0 = two / (right + left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = y_axis_sign * (two / (top + bottom));
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = zero;
+        let c2r1 = zero;
+        let c2r2 = gaze_sign * ((z_far - z_near) / (far - near));
+        let c2r3 = zero;
+
+        let c3r0 = -(right - left) / (right + left);
+        let c3r1 = -y_axis_sign * ((top - bottom) / (top + bottom));
+        let c3r2 = (z_near * far - z_far * near) / (far - near);
+        let c3r3 = one;
+
+        Self::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
+    /// Construct a new possibly asymmetric perspective projection matrix based
+    /// on the location of the **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, **near plane**, and **far plane**, for an arbitrary
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in
+    /// [`Matrix4x4::from_perspective`], except that the **near plane** and the
+    /// **far plane** are placed along the viewing direction of the view space
+    /// that `ndc` specifies. That is, the planes lie along the **negative z-axis**
+    /// for a right-handed view space, and along the **positive z-axis** for a
+    /// left-handed view space. After perspective division, the matrix maps the view
+    /// volume onto the canonical view volume `[-1, 1] x [-1, 1] x [z_near, z_far]`,
+    /// where `(z_near, z_far) == ndc.depth_bounds()`, and flips the **y-axis** when the
+    /// normalized device coordinates **y-axis** points down.
+    ///
+    /// # Matrix Representation Of The Perspective Projection Transformation
+    ///
+    /// Let `g := ndc.gaze_sign()`, `s := ndc.y_axis_sign()`, and
+    /// `(z_near, z_far) := ndc.depth_bounds()`. The underlying matrix is a
+    /// homogeneous projective matrix with the following form
+    ///
+    /// ```text
+    /// [ m[0, 0]  0        m[2, 0]  0       ]
+    /// [ 0        m[1, 1]  m[2, 1]  0       ]
+    /// [ 0        0        m[2, 2]  m[3, 2] ]
+    /// [ 0        0        g        0       ]
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    /// m[0, 0] ==  (2 * near) / (right + left)
+    /// m[2, 0] == -g * ((right - left) / (right + left))
+    /// m[1, 1] ==  s * ((2 * near) / (top + bottom))
+    /// m[2, 1] == -g * s * ((top - bottom) / (top + bottom))
+    /// m[2, 2] ==  g * ((z_far * far - z_near * near) / (far - near))
+    /// m[3, 2] ==  ((z_near - z_far) * far * near) / (far - near)
+    /// ```
+    ///
+    /// where the matrix entries are indexed in column-major order. The depth
+    /// entries follow from the requirement that a point at distance `near` in front
+    /// of the viewer lands on `z_near` after perspective division, and a point at
+    /// distance `far` lands on `z_far`. With `ndc == NdcConvention::OPENGL`, this
+    /// is the same matrix as the one that [`Matrix4x4::from_perspective`] constructs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// #
+    /// let left = 4_f64;
+    /// let right = 4_f64;
+    /// let bottom = 2_f64;
+    /// let top = 3_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let expected = Matrix4x4::new(
+    ///     1_f64 / 4_f64,  0_f64,          0_f64,             0_f64,
+    ///     0_f64,          2_f64 / 5_f64,  0_f64,             0_f64,
+    ///     0_f64,         -1_f64 / 5_f64,  100_f64 / 99_f64,  1_f64,
+    ///     0_f64,          0_f64,         -100_f64 / 99_f64,  0_f64,
+    /// );
+    /// let result = Matrix4x4::from_perspective_with_ndc(left, right, bottom, top, near, far, NdcConvention::DIRECT3D);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_with_ndc(left: S, right: S, bottom: S, top: S, near: S, far: S, ndc: NdcConvention) -> Self {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let gaze_sign = ndc.gaze_sign::<S>();
+        let y_axis_sign = ndc.y_axis_sign::<S>();
+        let (z_near, z_far) = ndc.depth_bounds::<S>();
+
+        let c0r0 = (two * near) / (right + left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = y_axis_sign * ((two * near) / (top + bottom));
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = -gaze_sign * ((right - left) / (right + left));
+        let c2r1 = -gaze_sign * y_axis_sign * ((top - bottom) / (top + bottom));
+        let c2r2 =  gaze_sign * ((z_far * far - z_near * near) / (far - near));
+        let c2r3 =  gaze_sign;
+
+        let c3r0 = zero;
+        let c3r1 = zero;
+        let c3r2 = ((z_near - z_far) * far * near) / (far - near);
+        let c3r3 = zero;
+
+        Self::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
+    /// Construct a symmetric perspective projection matrix based on the `near`
+    /// plane, the `far` plane and the vertical field of view angle `vfov` and
+    /// the horizontal/vertical aspect ratio `aspect_ratio`, for an arbitrary
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The field of view parameters have the same meaning as in
+    /// [`Matrix4x4::from_perspective_vfov`], except that the **near plane** and
+    /// the **far plane** are placed along the viewing direction of the view space
+    /// that `ndc` specifies. The resulting matrix is the matrix that
+    /// [`Matrix4x4::from_perspective_with_ndc`] constructs from the frustum
+    /// parameters
+    ///
+    /// ```text
+    /// right  == aspect_ratio * near * tan(vfov / 2)
+    /// left   == aspect_ratio * near * tan(vfov / 2)
+    /// top    == near * tan(vfov / 2)
+    /// bottom == near * tan(vfov / 2)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let expected = Matrix4x4::new(
+    ///     1_f64 / 2_f64,  0_f64,           0_f64,             0_f64,
+    ///     0_f64,         -1_f64,           0_f64,             0_f64,
+    ///     0_f64,          0_f64,          -101_f64 / 100_f64, -1_f64,
+    ///     0_f64,          0_f64,          -101_f64 / 100_f64,  0_f64,
+    /// );
+    /// let result = Matrix4x4::from_perspective_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::VULKAN);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_perspective_vfov_with_ndc<A>(vfov: A, aspect_ratio: S, near: S, far: S, ndc: NdcConvention) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let one = S::one();
+        let two = one + one;
+        let top = Angle::tan(vfov.into() / two) * near;
+        let right = aspect_ratio * top;
+
+        Self::from_perspective_with_ndc(right, right, top, top, near, far, ndc)
+    }
+
+    /// Construct a new possibly asymmetric reversed-z perspective projection matrix
+    /// based on the location of the **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, **near plane**, and **far plane**, for an arbitrary normalized
+    /// device coordinates convention `ndc`.
+    ///
+    /// A reversed-z perspective projection maps the **near plane** to the far end of the
+    /// depth range of the canonical view volume, and the **far plane** to the near end.
+    /// With a floating point depth buffer, this distributes the depth precision much more
+    /// evenly over the viewing frustum than the standard depth mapping does. The
+    /// resulting matrix is the matrix that [`Matrix4x4::from_perspective_with_ndc`]
+    /// constructs with the two ends of the depth range of `ndc` swapped.
+    ///
+    /// # Matrix Representation Of The Perspective Projection Transformation
+    ///
+    /// Let `g := ndc.gaze_sign()`, `s := ndc.y_axis_sign()`, and
+    /// `(z_near, z_far) := ndc.depth_bounds()`. The underlying matrix is a
+    /// homogeneous projective matrix with the following form
+    ///
+    /// ```text
+    /// [ m[0, 0]  0        m[2, 0]  0       ]
+    /// [ 0        m[1, 1]  m[2, 1]  0       ]
+    /// [ 0        0        m[2, 2]  m[3, 2] ]
+    /// [ 0        0        g        0       ]
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    /// m[0, 0] ==  (2 * near) / (right + left)
+    /// m[2, 0] == -g * ((right - left) / (right + left))
+    /// m[1, 1] ==  s * ((2 * near) / (top + bottom))
+    /// m[2, 1] == -g * s * ((top - bottom) / (top + bottom))
+    /// m[2, 2] ==  g * ((z_near * far - z_far * near) / (far - near))
+    /// m[3, 2] ==  ((z_far - z_near) * far * near) / (far - near)
+    /// ```
+    ///
+    /// where the matrix entries are indexed in column-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let left = 1_f64;
+    /// let right = 1_f64;
+    /// let bottom = 1_f64;
+    /// let top = 1_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let expected = Matrix4x4::new(
+    ///     1_f64, 0_f64, 0_f64,             0_f64,
+    ///     0_f64, 1_f64, 0_f64,             0_f64,
+    ///     0_f64, 0_f64, 1_f64 / 99_f64,   -1_f64,
+    ///     0_f64, 0_f64, 100_f64 / 99_f64,  0_f64,
+    /// );
+    /// let result = Matrix4x4::from_perspective_reversed_z_with_ndc(left, right, bottom, top, near, far, NdcConvention::WEBGPU);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The near plane maps to depth one, and the far plane maps to depth zero.
+    /// let near_point = result * Vector4::new(0_f64, 0_f64, -near, 1_f64);
+    /// let far_point = result * Vector4::new(0_f64, 0_f64, -far, 1_f64);
+    ///
+    /// assert_relative_eq!(near_point.z / near_point.w, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(far_point.z / far_point.w,   0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_reversed_z_with_ndc(left: S, right: S, bottom: S, top: S, near: S, far: S, ndc: NdcConvention) -> Self {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let gaze_sign = ndc.gaze_sign::<S>();
+        let y_axis_sign = ndc.y_axis_sign::<S>();
+        let (z_near, z_far) = ndc.depth_bounds::<S>();
+
+        let c0r0 = (two * near) / (right + left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = y_axis_sign * ((two * near) / (top + bottom));
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = -gaze_sign * ((right - left) / (right + left));
+        let c2r1 = -gaze_sign * y_axis_sign * ((top - bottom) / (top + bottom));
+        let c2r2 =  gaze_sign * ((z_near * far - z_far * near) / (far - near));
+        let c2r3 =  gaze_sign;
+
+        let c3r0 = zero;
+        let c3r1 = zero;
+        let c3r2 = ((z_far - z_near) * far * near) / (far - near);
+        let c3r3 = zero;
+
+        Self::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
+    /// Construct a new possibly asymmetric perspective projection matrix with an
+    /// infinitely distant **far plane**, based on the location of the **left plane**,
+    /// **right plane**, **bottom plane**, **top plane**, and **near plane**, for an
+    /// arbitrary normalized device coordinates convention `ndc`.
+    ///
+    /// The resulting matrix is the limit of the matrix that
+    /// [`Matrix4x4::from_perspective_with_ndc`] constructs as `far` tends to infinity.
+    /// Every point in front of the **near plane** maps inside the depth range of
+    /// `ndc`, with points at infinity mapping to the far end of the depth range.
+    ///
+    /// # Matrix Representation Of The Perspective Projection Transformation
+    ///
+    /// Let `g := ndc.gaze_sign()`, `s := ndc.y_axis_sign()`, and
+    /// `(z_near, z_far) := ndc.depth_bounds()`. The underlying matrix is a
+    /// homogeneous projective matrix with the following form
+    ///
+    /// ```text
+    /// [ m[0, 0]  0        m[2, 0]  0       ]
+    /// [ 0        m[1, 1]  m[2, 1]  0       ]
+    /// [ 0        0        m[2, 2]  m[3, 2] ]
+    /// [ 0        0        g        0       ]
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    /// m[0, 0] ==  (2 * near) / (right + left)
+    /// m[2, 0] == -g * ((right - left) / (right + left))
+    /// m[1, 1] ==  s * ((2 * near) / (top + bottom))
+    /// m[2, 1] == -g * s * ((top - bottom) / (top + bottom))
+    /// m[2, 2] ==  g * z_far
+    /// m[3, 2] ==  (z_near - z_far) * near
+    /// ```
+    ///
+    /// where the matrix entries are indexed in column-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// #
+    /// let left = 1_f64;
+    /// let right = 1_f64;
+    /// let bottom = 1_f64;
+    /// let top = 1_f64;
+    /// let near = 1_f64;
+    /// let expected = Matrix4x4::new(
+    ///     1_f64, 0_f64,  0_f64,  0_f64,
+    ///     0_f64, 1_f64,  0_f64,  0_f64,
+    ///     0_f64, 0_f64, -1_f64, -1_f64,
+    ///     0_f64, 0_f64, -2_f64,  0_f64,
+    /// );
+    /// let result = Matrix4x4::from_perspective_infinite_with_ndc(left, right, bottom, top, near, NdcConvention::OPENGL);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_infinite_with_ndc(left: S, right: S, bottom: S, top: S, near: S, ndc: NdcConvention) -> Self {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let gaze_sign = ndc.gaze_sign::<S>();
+        let y_axis_sign = ndc.y_axis_sign::<S>();
+        let (z_near, z_far) = ndc.depth_bounds::<S>();
+
+        let c0r0 = (two * near) / (right + left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = y_axis_sign * ((two * near) / (top + bottom));
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = -gaze_sign * ((right - left) / (right + left));
+        let c2r1 = -gaze_sign * y_axis_sign * ((top - bottom) / (top + bottom));
+        let c2r2 =  gaze_sign * z_far;
+        let c2r3 =  gaze_sign;
+
+        let c3r0 = zero;
+        let c3r1 = zero;
+        let c3r2 = (z_near - z_far) * near;
+        let c3r3 = zero;
+
+        Self::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
+    /// Construct a new possibly asymmetric reversed-z perspective projection matrix
+    /// with an infinitely distant **far plane**, based on the location of the
+    /// **left plane**, **right plane**, **bottom plane**, **top plane**, and
+    /// **near plane**, for an arbitrary normalized device coordinates convention `ndc`.
+    ///
+    /// The resulting matrix is the limit of the matrix that
+    /// [`Matrix4x4::from_perspective_reversed_z_with_ndc`] constructs as `far` tends
+    /// to infinity. The **near plane** maps to the far end of the depth range of `ndc`,
+    /// and points at infinity map to the near end. Combined with a floating point depth
+    /// buffer, this is the usual choice for rendering very large scenes.
+    ///
+    /// # Matrix Representation Of The Perspective Projection Transformation
+    ///
+    /// Let `g := ndc.gaze_sign()`, `s := ndc.y_axis_sign()`, and
+    /// `(z_near, z_far) := ndc.depth_bounds()`. The underlying matrix is a
+    /// homogeneous projective matrix with the following form
+    ///
+    /// ```text
+    /// [ m[0, 0]  0        m[2, 0]  0       ]
+    /// [ 0        m[1, 1]  m[2, 1]  0       ]
+    /// [ 0        0        m[2, 2]  m[3, 2] ]
+    /// [ 0        0        g        0       ]
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    /// m[0, 0] ==  (2 * near) / (right + left)
+    /// m[2, 0] == -g * ((right - left) / (right + left))
+    /// m[1, 1] ==  s * ((2 * near) / (top + bottom))
+    /// m[2, 1] == -g * s * ((top - bottom) / (top + bottom))
+    /// m[2, 2] ==  g * z_near
+    /// m[3, 2] ==  (z_far - z_near) * near
+    /// ```
+    ///
+    /// where the matrix entries are indexed in column-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// #
+    /// let left = 1_f64;
+    /// let right = 1_f64;
+    /// let bottom = 1_f64;
+    /// let top = 1_f64;
+    /// let near = 1_f64;
+    /// let expected = Matrix4x4::new(
+    ///     1_f64, 0_f64, 0_f64,  0_f64,
+    ///     0_f64, 1_f64, 0_f64,  0_f64,
+    ///     0_f64, 0_f64, 0_f64, -1_f64,
+    ///     0_f64, 0_f64, 1_f64,  0_f64,
+    /// );
+    /// let result = Matrix4x4::from_perspective_infinite_reversed_z_with_ndc(left, right, bottom, top, near, NdcConvention::WEBGPU);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn from_perspective_infinite_reversed_z_with_ndc(left: S, right: S, bottom: S, top: S, near: S, ndc: NdcConvention) -> Self {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let gaze_sign = ndc.gaze_sign::<S>();
+        let y_axis_sign = ndc.y_axis_sign::<S>();
+        let (z_near, z_far) = ndc.depth_bounds::<S>();
+
+        let c0r0 = (two * near) / (right + left);
+        let c0r1 = zero;
+        let c0r2 = zero;
+        let c0r3 = zero;
+
+        let c1r0 = zero;
+        let c1r1 = y_axis_sign * ((two * near) / (top + bottom));
+        let c1r2 = zero;
+        let c1r3 = zero;
+
+        let c2r0 = -gaze_sign * ((right - left) / (right + left));
+        let c2r1 = -gaze_sign * y_axis_sign * ((top - bottom) / (top + bottom));
+        let c2r2 =  gaze_sign * z_near;
+        let c2r3 =  gaze_sign;
+
+        let c3r0 = zero;
+        let c3r1 = zero;
+        let c3r2 = (z_far - z_near) * near;
+        let c3r3 = zero;
+
+        Self::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
     /// Construct an affine coordinate transformation matrix that transforms
     /// a coordinate system of an observer located at the position `eye` facing
     /// the position `target` into the coordinate system of an observer located
@@ -1,8 +1,13 @@
-use crate::euler::Euler;
+use crate::euler::{
+    axis_rotation_matrix,
+    Euler,
+    EulerOrder,
+};
 use crate::matrix::{
     Matrix3x3,
     Matrix4x4,
 };
+use crate::vector::Vector3;
 use cglinalg_numeric::SimdScalarFloat;
 use cglinalg_trigonometry::{
     Angle,
@@ -71,6 +76,331 @@ where
 
         euler_radians.to_matrix()
     }
+
+    /// Construct a rotation matrix from a set of Euler angles in a given
+    /// rotation order.
+    ///
+    /// See [`EulerOrder`] for the supported rotation orders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let euler_angles = Euler::new(Degrees(30_f64), Degrees(45_f64), Degrees(60_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZYX);
+    /// let expected = {
+    ///     let rotation_x = Matrix3x3::from_angle_x(Degrees(30_f64));
+    ///     let rotation_y = Matrix3x3::from_angle_y(Degrees(45_f64));
+    ///     let rotation_z = Matrix3x3::from_angle_z(Degrees(60_f64));
+    ///
+    ///     rotation_z * rotation_y * rotation_x
+    /// };
+    /// let result = Matrix3x3::from_euler_angles_with_order(&euler_angles, order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_euler_angles_with_order<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Self
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        euler_angles.to_matrix_with_order(order)
+    }
+
+    /// Extract Euler angles from a rotation matrix, in units of [`Radians`].
+    ///
+    /// The angles are in the default rotation order of [`Euler`], so that
+    /// [`Matrix3x3::from_euler_angles`] reconstructs the rotation matrix. When
+    /// the rotation is gimbal locked, the rotation about the **z-axis** is set to
+    /// zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// # use core::f64;
+    /// #
+    /// let expected = {
+    ///     let roll = Radians(f64::consts::FRAC_PI_6);
+    ///     let yaw = Radians(f64::consts::FRAC_PI_4);
+    ///     let pitch = Radians(f64::consts::FRAC_PI_3);
+    ///
+    ///     Euler::new(roll, yaw, pitch)
+    /// };
+    /// let matrix = Matrix3x3::from_euler_angles(&expected);
+    /// let result = matrix.euler_angles();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_angles(&self) -> Euler<Radians<S>> {
+        Euler::<Radians<S>>::from_matrix(self)
+    }
+
+    /// Extract Euler angles in a given rotation order from a rotation matrix, in
+    /// units of [`Radians`].
+    ///
+    /// See [`Euler::from_quaternion_with_order`] for the ranges of the angles and
+    /// the treatment of gimbal lock.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let expected = Euler::new(Radians(-0.3_f64), Radians(0.8_f64), Radians(2.6_f64));
+    /// let order = EulerOrder::extrinsic(EulerAxes::XYZ);
+    /// let matrix = Matrix3x3::from_euler_angles_with_order(&expected, order);
+    /// let result = matrix.euler_angles_with_order(order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_angles_with_order(&self, order: EulerOrder) -> Euler<Radians<S>> {
+        Euler::from_matrix_with_order(self, order)
+    }
+
+    /// Construct the matrix that maps the time derivatives of a set of Euler
+    /// angles to the angular velocity of the rotating frame, expressed in the
+    /// fixed frame.
+    ///
+    /// Let `R == R_a(angle_a) * R_b(angle_b) * R_c(angle_c)` be the rotation of
+    /// the equivalent intrinsic sequence `(a, b, c)` of the rotation order. Each
+    /// angle rate contributes a rotation about the axis that the angle rotates
+    /// about at that point in the sequence, so the angular velocity in the fixed
+    /// frame is
+    /// ```text
+    /// omega == rate_a * e_a + rate_b * R_a * e_b + rate_c * R_a * R_b * e_c
+    /// ```
+    /// where `e_a`, `e_b`, and `e_c` are the unit vectors along the coordinate
+    /// axes. The matrix acts on a vector of angle rates with the same layout as
+    /// the set of Euler angles: the components of the vector are the rates of the
+    /// `x`, `y`, and `z` fields of the [`Euler`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let roll = 0.3_f64;
+    /// let yaw = -0.6_f64;
+    /// let pitch = 1.1_f64;
+    /// let euler_angles = Euler::new(Radians(roll), Radians(yaw), Radians(pitch));
+    /// let expected = Matrix3x3::new(
+    ///     1_f64,          0_f64,                           0_f64,
+    ///     0_f64,          roll.cos(),                      roll.sin(),
+    ///     yaw.sin(),     -roll.sin() * yaw.cos(),          roll.cos() * yaw.cos(),
+    /// );
+    /// let result = Matrix3x3::euler_rates_to_angular_velocity(&euler_angles, EulerOrder::default());
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_rates_to_angular_velocity<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Self
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        let axes = order.intrinsic_sequence();
+        let fields = order.intrinsic_fields();
+        let angles = euler_angles.intrinsic_angles(order);
+        let rotations: [Matrix3x3<S>; 3] = core::array::from_fn(|i| {
+            let (sin_angle, cos_angle) = angles[i].sin_cos();
+
+            axis_rotation_matrix(axes[i], sin_angle, cos_angle)
+        });
+        let unit_axis = |axis: usize| {
+            let mut unit_axis_vector = Vector3::zero();
+            unit_axis_vector[axis] = S::one();
+
+            unit_axis_vector
+        };
+
+        let mut result = Self::zero();
+        result[fields[0]] = unit_axis(axes[0]);
+        result[fields[1]] = rotations[0] * unit_axis(axes[1]);
+        result[fields[2]] = (rotations[0] * rotations[1]) * unit_axis(axes[2]);
+
+        result
+    }
+
+    /// Construct the matrix that maps the time derivatives of a set of Euler
+    /// angles to the angular velocity of the rotating frame, expressed in the
+    /// rotating frame itself.
+    ///
+    /// This is the body frame angular velocity used in flight and vehicle
+    /// dynamics. It is the angular velocity of
+    /// [`Matrix3x3::euler_rates_to_angular_velocity`] rotated back into the
+    /// rotating frame, i.e. the matrix is `R^T * W` where `R` is the rotation
+    /// matrix of the Euler angles and `W` is the fixed frame matrix.
+    ///
+    /// # Example
+    ///
+    /// The classical aerospace yaw, pitch, roll sequence is the intrinsic **ZYX**
+    /// order. Its body angular velocity `(p, q, r)` satisfies
+    /// ```text
+    /// p == roll_rate - yaw_rate * sin(pitch)
+    /// q == pitch_rate * cos(roll) + yaw_rate * sin(roll) * cos(pitch)
+    /// r == -pitch_rate * sin(roll) + yaw_rate * cos(roll) * cos(pitch)
+    /// ```
+    /// where the roll, pitch, and yaw angles rotate about the **x-axis**,
+    /// **y-axis**, and **z-axis** respectively.
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let roll = 0.3_f64;
+    /// let pitch = -0.6_f64;
+    /// let yaw = 1.1_f64;
+    /// let euler_angles = Euler::new(Radians(roll), Radians(pitch), Radians(yaw));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZYX);
+    /// let expected = Matrix3x3::new(
+    ///      1_f64,          0_f64,                     0_f64,
+    ///      0_f64,          roll.cos(),               -roll.sin(),
+    ///     -pitch.sin(),    roll.sin() * pitch.cos(),  roll.cos() * pitch.cos(),
+    /// );
+    /// let result = Matrix3x3::euler_rates_to_body_angular_velocity(&euler_angles, order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_rates_to_body_angular_velocity<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Self
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        let rotation = euler_angles.to_matrix_with_order(order);
+        let euler_rates_to_angular_velocity = Self::euler_rates_to_angular_velocity(euler_angles, order);
+
+        rotation.transpose() * euler_rates_to_angular_velocity
+    }
+
+    /// Construct the matrix that maps the angular velocity of the rotating frame,
+    /// expressed in the fixed frame, to the time derivatives of a set of Euler
+    /// angles.
+    ///
+    /// This is the inverse of [`Matrix3x3::euler_rates_to_angular_velocity`]. The
+    /// function returns `None` when the Euler angles are gimbal locked, since the
+    /// first and third rotations are then about the same axis and their rates
+    /// cannot be distinguished. Euler angles are treated as gimbal locked when the
+    /// determinant of [`Matrix3x3::euler_rates_to_angular_velocity`] is within
+    /// the square root of machine epsilon of zero, because the Euler rates blow up
+    /// near gimbal lock long before the matrix becomes exactly singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_trigonometry::{
+    /// #     Angle,
+    /// #     Radians,
+    /// # };
+    /// #
+    /// let euler_angles = Euler::new(Radians(0.3_f64), Radians(-0.6_f64), Radians(1.1_f64));
+    /// let order = EulerOrder::default();
+    /// let matrix = Matrix3x3::euler_rates_to_angular_velocity(&euler_angles, order);
+    /// let expected = Matrix3x3::identity();
+    /// let result = Matrix3x3::angular_velocity_to_euler_rates(&euler_angles, order).unwrap() * matrix;
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The rates are undetermined in gimbal lock.
+    /// let euler_angles = Euler::new(Radians(0.3_f64), Radians::full_turn_div_4(), Radians(1.1_f64));
+    ///
+    /// assert!(Matrix3x3::angular_velocity_to_euler_rates(&euler_angles, order).is_none());
+    /// ```
+    #[inline]
+    pub fn angular_velocity_to_euler_rates<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Option<Self>
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        let euler_rates_to_angular_velocity = Self::euler_rates_to_angular_velocity(euler_angles, order);
+
+        try_inverse_unless_gimbal_locked(&euler_rates_to_angular_velocity)
+    }
+
+    /// Construct the matrix that maps the angular velocity of the rotating frame,
+    /// expressed in the rotating frame itself, to the time derivatives of a set
+    /// of Euler angles.
+    ///
+    /// This is the inverse of [`Matrix3x3::euler_rates_to_body_angular_velocity`].
+    /// The function returns `None` when the Euler angles are gimbal locked, with
+    /// the same tolerance as [`Matrix3x3::angular_velocity_to_euler_rates`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let roll = 0.3_f64;
+    /// let pitch = -0.6_f64;
+    /// let yaw = 1.1_f64;
+    /// let euler_angles = Euler::new(Radians(roll), Radians(pitch), Radians(yaw));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZYX);
+    /// let body_angular_velocity = Vector3::new(0.2_f64, -0.5_f64, 0.7_f64);
+    /// let (p, q, r) = (body_angular_velocity.x, body_angular_velocity.y, body_angular_velocity.z);
+    /// let expected = Vector3::new(
+    ///     p + (q * roll.sin() + r * roll.cos()) * pitch.tan(),
+    ///     q * roll.cos() - r * roll.sin(),
+    ///     (q * roll.sin() + r * roll.cos()) / pitch.cos(),
+    /// );
+    /// let matrix = Matrix3x3::body_angular_velocity_to_euler_rates(&euler_angles, order).unwrap();
+    /// let result = matrix * body_angular_velocity;
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn body_angular_velocity_to_euler_rates<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Option<Self>
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        let euler_rates_to_body_angular_velocity = Self::euler_rates_to_body_angular_velocity(euler_angles, order);
+
+        try_inverse_unless_gimbal_locked(&euler_rates_to_body_angular_velocity)
+    }
 }
 
 impl<S> Matrix4x4<S>
@@ -124,7 +454,7 @@ where
     ///          1_f64,
     ///     )
     /// };
-    /// let result = euler_angles.to_affine_matrix();
+    /// let result = Matrix4x4::from_euler_angles(&euler_angles);
     ///
     /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
     /// ```
@@ -142,4 +472,137 @@ where
 
         euler_radians.to_affine_matrix()
     }
+
+    /// Construct an affine rotation matrix from a set of Euler angles in a given
+    /// rotation order.
+    ///
+    /// See [`EulerOrder`] for the supported rotation orders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix4x4,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let euler_angles = Euler::new(Degrees(30_f64), Degrees(45_f64), Degrees(60_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZYX);
+    /// let expected = {
+    ///     let rotation_x = Matrix4x4::from_affine_angle_x(Degrees(30_f64));
+    ///     let rotation_y = Matrix4x4::from_affine_angle_y(Degrees(45_f64));
+    ///     let rotation_z = Matrix4x4::from_affine_angle_z(Degrees(60_f64));
+    ///
+    ///     rotation_z * rotation_y * rotation_x
+    /// };
+    /// let result = Matrix4x4::from_euler_angles_with_order(&euler_angles, order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_euler_angles_with_order<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Self
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        euler_angles.to_affine_matrix_with_order(order)
+    }
+
+    /// Extract Euler angles from the rotation block of an affine rotation
+    /// matrix, in units of [`Radians`].
+    ///
+    /// The upper left 3x3 block of the matrix must be a rotation matrix. The
+    /// translation terms of the matrix are ignored. See
+    /// [`Matrix3x3::euler_angles`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     Matrix4x4,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// # use core::f64;
+    /// #
+    /// let expected = {
+    ///     let roll = Radians(f64::consts::FRAC_PI_6);
+    ///     let yaw = Radians(f64::consts::FRAC_PI_4);
+    ///     let pitch = Radians(f64::consts::FRAC_PI_3);
+    ///
+    ///     Euler::new(roll, yaw, pitch)
+    /// };
+    /// let matrix = Matrix4x4::from_euler_angles(&expected);
+    /// let result = matrix.euler_angles();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_angles(&self) -> Euler<Radians<S>> {
+        self.rotation_block().euler_angles()
+    }
+
+    /// Extract Euler angles in a given rotation order from the rotation block of
+    /// an affine rotation matrix, in units of [`Radians`].
+    ///
+    /// The upper left 3x3 block of the matrix must be a rotation matrix. The
+    /// translation terms of the matrix are ignored. See
+    /// [`Matrix3x3::euler_angles_with_order`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix4x4,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let expected = Euler::new(Radians(-0.3_f64), Radians(0.8_f64), Radians(2.6_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::YZY);
+    /// let matrix = Matrix4x4::from_euler_angles_with_order(&expected, order);
+    /// let result = matrix.euler_angles_with_order(order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_angles_with_order(&self, order: EulerOrder) -> Euler<Radians<S>> {
+        self.rotation_block().euler_angles_with_order(order)
+    }
+
+    #[rustfmt::skip]
+    #[inline]
+    fn rotation_block(&self) -> Matrix3x3<S> {
+        Matrix3x3::new(
+            self.c0r0, self.c0r1, self.c0r2,
+            self.c1r0, self.c1r1, self.c1r2,
+            self.c2r0, self.c2r1, self.c2r2,
+        )
+    }
+}
+
+
+#[inline]
+fn try_inverse_unless_gimbal_locked<S>(matrix: &Matrix3x3<S>) -> Option<Matrix3x3<S>>
+where
+    S: SimdScalarFloat,
+{
+    // The columns of the matrix are unit vectors, so the determinant is the
+    // cosine of the middle angle of a Tait-Bryan sequence, or the sine of the
+    // middle angle of a proper Euler sequence, up to sign. Rounding in the
+    // trigonometric functions leaves a determinant of order machine epsilon
+    // at an exact lock, and the inverse is numerically meaningless well before
+    // that, so compare against the square root of machine epsilon.
+    if matrix.determinant().abs() <= S::default_epsilon().sqrt() {
+        None
+    } else {
+        matrix.try_inverse()
+    }
 }
@@ -0,0 +1,239 @@
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// The handedness of the view space orthonormal frame of a projection
+/// transformation.
+///
+/// The handedness determines which direction along the **z-axis** the viewer
+/// faces in view space.
+///
+/// * In a **right-handed** view space, the viewer faces the **negative z-axis**.
+/// * In a **left-handed** view space, the viewer faces the **positive z-axis**.
+///
+/// In both cases the **positive x-axis** points right and the **positive y-axis**
+/// points up.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    /// A left-handed view space. The viewer looks along the **positive z-axis**.
+    LeftHanded,
+    /// A right-handed view space. The viewer looks along the **negative z-axis**.
+    RightHanded,
+}
+
+/// The range of depth values in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    /// The **near plane** maps to `-1` and the **far plane** maps to `1`. This
+    /// is the depth range that OpenGL uses.
+    NegativeOneToOne,
+    /// The **near plane** maps to `0` and the **far plane** maps to `1`. This is
+    /// the depth range that Vulkan, Direct3D, Metal, and WebGPU use.
+    ZeroToOne,
+}
+
+/// The direction of the **positive y-axis** in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NdcYAxis {
+    /// The **positive y-axis** points up in normalized device coordinates. The
+    /// **top plane** maps to `y == 1`.
+    Up,
+    /// The **positive y-axis** points down in normalized device coordinates. The
+    /// **top plane** maps to `y == -1`. This is the convention that Vulkan uses.
+    Down,
+}
+
+/// A normalized device coordinates convention for a projection transformation.
+///
+/// A projection transformation maps a view volume in view space to a canonical
+/// view volume in normalized device coordinates. Different graphics APIs make
+/// different choices about the shape and orientation of the spaces on either
+/// side of the transformation. A convention captures these choices.
+///
+/// * The `handedness` of the view space: whether the viewer looks along the
+///   **negative z-axis** (right-handed) or the **positive z-axis** (left-handed).
+/// * The `depth_range` of the canonical view volume: whether the depth
+///   coordinates range over `[-1, 1]` or `[0, 1]`.
+/// * The `y_axis` of the canonical view volume: whether the **positive y-axis**
+///   points up or down.
+///
+/// In every convention, the **positive x-axis** in normalized device coordinates
+/// points right, the horizontal coordinates range over `[-1, 1]`, the vertical
+/// coordinates range over `[-1, 1]`, and the **near plane** maps to the smaller
+/// end of the depth range.
+///
+/// # Presets
+///
+/// | Preset                          | View space   | Depth range | NDC y-axis |
+/// |---------------------------------|--------------|-------------|------------|
+/// | [`NdcConvention::OPENGL`]       | right-handed | `[-1, 1]`   | up         |
+/// | [`NdcConvention::VULKAN`]       | right-handed | `[0, 1]`    | down       |
+/// | [`NdcConvention::DIRECT3D`]     | left-handed  | `[0, 1]`    | up         |
+/// | [`NdcConvention::METAL`]        | right-handed | `[0, 1]`    | up         |
+/// | [`NdcConvention::WEBGPU`]       | right-handed | `[0, 1]`    | up         |
+///
+/// The graphics APIs themselves only fix the canonical view volume. The view space
+/// handedness of each preset is the customary choice for that API.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     DepthRange,
+/// #     Handedness,
+/// #     NdcConvention,
+/// #     NdcYAxis,
+/// # };
+/// #
+/// let convention = NdcConvention::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Down);
+///
+/// assert_eq!(convention, NdcConvention::VULKAN);
+/// assert_eq!(NdcConvention::default(), NdcConvention::OPENGL);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NdcConvention {
+    /// The handedness of the view space.
+    pub handedness: Handedness,
+    /// The depth range of the canonical view volume.
+    pub depth_range: DepthRange,
+    /// The direction of the **positive y-axis** of the canonical view volume.
+    pub y_axis: NdcYAxis,
+}
+
+impl NdcConvention {
+    /// The normalized device coordinates convention that OpenGL uses.
+    pub const OPENGL: Self = Self::new(Handedness::RightHanded, DepthRange::NegativeOneToOne, NdcYAxis::Up);
+
+    /// The normalized device coordinates convention that Vulkan uses.
+    pub const VULKAN: Self = Self::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Down);
+
+    /// The normalized device coordinates convention that Direct3D uses.
+    pub const DIRECT3D: Self = Self::new(Handedness::LeftHanded, DepthRange::ZeroToOne, NdcYAxis::Up);
+
+    /// The normalized device coordinates convention that Metal uses.
+    pub const METAL: Self = Self::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Up);
+
+    /// The normalized device coordinates convention that WebGPU uses.
+    pub const WEBGPU: Self = Self::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Up);
+
+    /// Construct a new normalized device coordinates convention.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     DepthRange,
+    /// #     Handedness,
+    /// #     NdcConvention,
+    /// #     NdcYAxis,
+    /// # };
+    /// #
+    /// let convention = NdcConvention::new(Handedness::LeftHanded, DepthRange::ZeroToOne, NdcYAxis::Up);
+    ///
+    /// assert_eq!(convention.handedness, Handedness::LeftHanded);
+    /// assert_eq!(convention.depth_range, DepthRange::ZeroToOne);
+    /// assert_eq!(convention.y_axis, NdcYAxis::Up);
+    /// ```
+    #[inline]
+    pub const fn new(handedness: Handedness, depth_range: DepthRange, y_axis: NdcYAxis) -> Self {
+        Self {
+            handedness,
+            depth_range,
+            y_axis,
+        }
+    }
+
+    /// Get the depth bounds `(near, far)` of the canonical view volume.
+    ///
+    /// The first component is the depth coordinate that the **near plane** maps
+    /// to, and the second component is the depth coordinate that the **far plane**
+    /// maps to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// #
+    /// assert_eq!(NdcConvention::OPENGL.depth_bounds(), (-1_f64, 1_f64));
+    /// assert_eq!(NdcConvention::VULKAN.depth_bounds(), (0_f64, 1_f64));
+    /// ```
+    #[inline]
+    pub fn depth_bounds<S>(&self) -> (S, S)
+    where
+        S: SimdScalarFloat,
+    {
+        match self.depth_range {
+            DepthRange::NegativeOneToOne => (-S::one(), S::one()),
+            DepthRange::ZeroToOne => (S::zero(), S::one()),
+        }
+    }
+
+    /// Get the sign of the **y-axis** in normalized device coordinates.
+    ///
+    /// The sign is `1` when the **positive y-axis** points up, and `-1` when the
+    /// **positive y-axis** points down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// #
+    /// assert_eq!(NdcConvention::OPENGL.y_axis_sign::<f64>(), 1_f64);
+    /// assert_eq!(NdcConvention::VULKAN.y_axis_sign::<f64>(), -1_f64);
+    /// ```
+    #[inline]
+    pub fn y_axis_sign<S>(&self) -> S
+    where
+        S: SimdScalarFloat,
+    {
+        match self.y_axis {
+            NdcYAxis::Up => S::one(),
+            NdcYAxis::Down => -S::one(),
+        }
+    }
+
+    /// Get the sign of the **z-axis** along the viewing direction in view space.
+    ///
+    /// The sign is `-1` for a right-handed view space, where the viewer looks along
+    /// the **negative z-axis**, and `1` for a left-handed view space, where the
+    /// viewer looks along the **positive z-axis**. In particular, a point in view
+    /// space at distance `depth` in front of the viewer has a **z-coordinate** of
+    /// `gaze_sign * depth`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// #
+    /// assert_eq!(NdcConvention::OPENGL.gaze_sign::<f64>(), -1_f64);
+    /// assert_eq!(NdcConvention::DIRECT3D.gaze_sign::<f64>(), 1_f64);
+    /// ```
+    #[inline]
+    pub fn gaze_sign<S>(&self) -> S
+    where
+        S: SimdScalarFloat,
+    {
+        match self.handedness {
+            Handedness::LeftHanded => S::one(),
+            Handedness::RightHanded => -S::one(),
+        }
+    }
+}
+
+impl Default for NdcConvention {
+    #[inline]
+    fn default() -> Self {
+        Self::OPENGL
+    }
+}
+
+impl fmt::Display for NdcConvention {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "NdcConvention [handedness={:?}, depth_range={:?}, y_axis={:?}]",
+            self.handedness, self.depth_range, self.y_axis
+        )
+    }
+}
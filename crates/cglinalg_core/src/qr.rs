@@ -0,0 +1,384 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The QR decomposition of a matrix with at least as many rows as columns.
+///
+/// The decomposition of a matrix `a` with `R` rows and `C` columns, where
+/// `R >= C`, is a matrix `q` with `R` rows and `C` orthonormal columns, and an
+/// upper triangular matrix `r` with `C` rows and `C` columns such that
+/// ```text
+/// a == q * r
+/// ```
+/// The decomposition is computed with Householder reflections, which keeps `q`
+/// orthonormal to working precision regardless of the conditioning of `a`. The
+/// reflections are stored in compact form, and `q` is only formed on request.
+///
+/// The main use of the decomposition is solving linear least squares problems
+/// with [`QrDecomposition::solve`]. Unlike solving the normal equations
+/// ```text
+/// transpose(a) * a * x == transpose(a) * b
+/// ```
+/// it does not square the condition number of `a`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QrDecomposition<S, const R: usize, const C: usize> {
+    qr: Matrix<S, R, C>,
+    r_diagonal: Vector<S, C>,
+}
+
+impl<S, const R: usize, const C: usize> QrDecomposition<S, R, C>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the QR decomposition of a matrix with Householder reflections, if
+    /// the matrix has at least as many rows as columns.
+    ///
+    /// Returns `None` if the matrix has fewer rows than columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Matrix3x4,
+    /// #     Matrix4x3,
+    /// #     QrDecomposition,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x3::new(
+    ///     1_f64, 1_f64, 1_f64, 1_f64,
+    ///     1_f64, 2_f64, 3_f64, 4_f64,
+    ///     2_f64, 0_f64, 1_f64, 5_f64,
+    /// );
+    /// let qr = QrDecomposition::try_new(&matrix).unwrap();
+    /// let q = qr.q();
+    /// let r = qr.r();
+    ///
+    /// assert_relative_eq!(q * r, matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(q.tr_mul(&q), Matrix3x3::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    ///
+    /// let wide = Matrix3x4::new(
+    ///     1_f64, 1_f64, 1_f64,
+    ///     1_f64, 2_f64, 3_f64,
+    ///     2_f64, 0_f64, 1_f64,
+    ///     0_f64, 1_f64, 5_f64,
+    /// );
+    ///
+    /// assert!(QrDecomposition::try_new(&wide).is_none());
+    /// ```
+    pub fn try_new(matrix: &Matrix<S, R, C>) -> Option<Self> {
+        if R < C {
+            return None;
+        }
+
+        Some(Self::new_unchecked(matrix))
+    }
+
+    /// Compute the QR decomposition of a matrix assumed to have at least as
+    /// many rows as columns.
+    fn new_unchecked(matrix: &Matrix<S, R, C>) -> Self {
+        let two = S::one() + S::one();
+        let mut qr = *matrix;
+        let mut r_diagonal = Vector::zero();
+        for k in 0..C {
+            let norm = (k..R).fold(S::zero(), |acc, r| acc + qr[k][r] * qr[k][r]).sqrt();
+            if norm.is_zero() {
+                // The column is already zero below the diagonal, so no reflection
+                // is needed. A zero Householder vector stands for the identity.
+                continue;
+            }
+
+            // Reflect onto the multiple of the first basis vector with the sign
+            // opposite to the leading element to avoid cancellation.
+            let alpha = if qr[k][k] > S::zero() { -norm } else { norm };
+            qr[k][k] -= alpha;
+            let v_norm = (k..R).fold(S::zero(), |acc, r| acc + qr[k][r] * qr[k][r]).sqrt();
+            for r in k..R {
+                qr[k][r] /= v_norm;
+            }
+            r_diagonal[k] = alpha;
+
+            let v = qr[k];
+            for c in (k + 1)..C {
+                let dot = (k..R).fold(S::zero(), |acc, r| acc + v[r] * qr[c][r]);
+                for r in k..R {
+                    qr[c][r] -= two * dot * v[r];
+                }
+            }
+        }
+
+        Self { qr, r_diagonal }
+    }
+
+    /// Apply the Householder reflection `k` to a vector.
+    #[inline]
+    fn reflect(&self, k: usize, vector: &mut Vector<S, R>) {
+        let two = S::one() + S::one();
+        let v = &self.qr[k];
+        let dot = (k..R).fold(S::zero(), |acc, r| acc + v[r] * vector[r]);
+        for r in k..R {
+            vector[r] -= two * dot * v[r];
+        }
+    }
+
+    /// Get the orthonormal factor `q` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     Matrix3x2,
+    /// #     QrDecomposition,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     3_f64, 4_f64, 0_f64,
+    ///     1_f64, 2_f64, 2_f64,
+    /// );
+    /// let qr = QrDecomposition::try_new(&matrix).unwrap();
+    /// let q = qr.q();
+    ///
+    /// assert_relative_eq!(q.tr_mul(&q), Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn q(&self) -> Matrix<S, R, C> {
+        let mut result = Matrix::zero();
+        for c in 0..C {
+            let mut column = Vector::zero();
+            column[c] = S::one();
+            for k in (0..C).rev() {
+                self.reflect(k, &mut column);
+            }
+            result[c] = column;
+        }
+
+        result
+    }
+
+    /// Get the upper triangular factor `r` of the decomposition.
+    ///
+    /// The diagonal elements of `r` are not necessarily positive. Their signs
+    /// are chosen by the Householder reflections for numerical stability.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     Matrix3x2,
+    /// #     QrDecomposition,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     3_f64, 4_f64, 0_f64,
+    ///     1_f64, 2_f64, 2_f64,
+    /// );
+    /// let qr = QrDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Matrix2x2::new(
+    ///     -5_f64,           0_f64,
+    ///     -11_f64 / 5_f64, -2_f64 * f64::sqrt(26_f64) / 5_f64,
+    /// );
+    ///
+    /// assert_relative_eq!(qr.r(), expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn r(&self) -> Matrix<S, C, C> {
+        let mut result = Matrix::zero();
+        for c in 0..C {
+            for r in 0..c {
+                result[c][r] = self.qr[c][r];
+            }
+            result[c][c] = self.r_diagonal[c];
+        }
+
+        result
+    }
+
+    /// Determine whether the decomposed matrix has full column rank.
+    ///
+    /// The matrix has full column rank when every diagonal element of `r` is
+    /// nonzero, that is, when its columns are linearly independent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Matrix3x2,
+    /// #     QrDecomposition,
+    /// # };
+    /// #
+    /// let full_rank = Matrix3x2::new(
+    ///     1_f64, 0_f64, 0_f64,
+    ///     0_f64, 1_f64, 0_f64,
+    /// );
+    /// let rank_deficient = Matrix3x2::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     0_f64, 0_f64, 0_f64,
+    /// );
+    ///
+    /// assert!(QrDecomposition::try_new(&full_rank).unwrap().is_full_rank());
+    /// assert!(!QrDecomposition::try_new(&rank_deficient).unwrap().is_full_rank());
+    /// ```
+    pub fn is_full_rank(&self) -> bool {
+        (0..C).all(|i| !self.r_diagonal[i].is_zero())
+    }
+
+    /// Solve the linear least squares problem for `a * x == b`, if the matrix `a`
+    /// has full column rank.
+    ///
+    /// The solution `x` minimizes the residual `norm(a * x - b)`. When `a` is
+    /// square, it is the exact solution of the linear system. The problem is
+    /// solved by applying the transpose of `q` to `b` and back substituting with
+    /// `r`. Returns `None` if the columns of `a` are linearly dependent.
+    ///
+    /// # Example
+    ///
+    /// Fit a line `y == m * x + c` to four points.
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x2,
+    /// #     QrDecomposition,
+    /// #     Vector2,
+    /// #     Vector4,
+    /// # };
+    /// #
+    /// let xs = Vector4::new(0_f64, 1_f64, 2_f64, 3_f64);
+    /// let ys = Vector4::new(1_f64, 3_f64, 4_f64, 4_f64);
+    /// let matrix = Matrix4x2::from_columns(&[xs, Vector4::new(1_f64, 1_f64, 1_f64, 1_f64)]);
+    /// let qr = QrDecomposition::try_new(&matrix).unwrap();
+    /// let expected = Vector2::new(1_f64, 1.5_f64);
+    /// let result = qr.solve(&ys).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn solve(&self, b: &Vector<S, R>) -> Option<Vector<S, C>> {
+        if !self.is_full_rank() {
+            return None;
+        }
+
+        let mut y = *b;
+        for k in 0..C {
+            self.reflect(k, &mut y);
+        }
+
+        let mut result = Vector::zero();
+        for i in (0..C).rev() {
+            let mut sum = y[i];
+            for j in (i + 1)..C {
+                sum -= self.qr[j][i] * result[j];
+            }
+            result[i] = sum / self.r_diagonal[i];
+        }
+
+        Some(result)
+    }
+}
+
+impl<S, const R: usize, const C: usize> Matrix<S, R, C>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the QR decomposition of a matrix, if the matrix has at least as
+    /// many rows as columns.
+    ///
+    /// See [`QrDecomposition`] for details. Returns `None` if the matrix has
+    /// fewer rows than columns.
+    ///
+    /// # Example
+    ///
+    /// Fit the plane `z == a * x + b * y + c` to five points.
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix,
+    /// #     Vector,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix: Matrix<f64, 5, 3> = Matrix::from_columns(&[
+    ///     Vector::from([0_f64, 1_f64, 0_f64, 1_f64, 2_f64]),
+    ///     Vector::from([0_f64, 0_f64, 1_f64, 1_f64, 1_f64]),
+    ///     Vector::from([1_f64, 1_f64, 1_f64, 1_f64, 1_f64]),
+    /// ]);
+    /// let expected = Vector3::new(2_f64, -1_f64, 3_f64);
+    /// let b = matrix * expected;
+    /// let result = matrix.try_qr().unwrap().solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn try_qr(&self) -> Option<QrDecomposition<S, R, C>> {
+        QrDecomposition::try_new(self)
+    }
+
+    /// Orthonormalize the columns of a matrix with the Gram-Schmidt process, if
+    /// the columns are linearly independent.
+    ///
+    /// The columns of the result are orthonormal, and for each `k`, the first
+    /// `k` columns of the result span the same subspace as the first `k`
+    /// columns of `self`. The function uses modified Gram-Schmidt with a second
+    /// orthogonalization pass, which keeps the columns orthogonal to working
+    /// precision. Returns `None` if the columns are linearly dependent to working
+    /// precision, which is always the case when there are more columns than rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x2,
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x2::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     1_f64, 1_f64, 0_f64,
+    /// );
+    /// let expected = Matrix3x2::new(
+    ///     1_f64, 0_f64, 0_f64,
+    ///     0_f64, 1_f64, 0_f64,
+    /// );
+    /// let result = matrix.try_orthonormalize_columns().unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    ///
+    /// let dependent = Matrix3x3::from_columns(&[
+    ///     Vector3::new(1_f64, 2_f64, 3_f64),
+    ///     Vector3::new(0_f64, 1_f64, 0_f64),
+    ///     Vector3::new(2_f64, 5_f64, 6_f64),
+    /// ]);
+    ///
+    /// assert!(dependent.try_orthonormalize_columns().is_none());
+    /// ```
+    pub fn try_orthonormalize_columns(&self) -> Option<Self> {
+        if C > R {
+            return None;
+        }
+
+        let mut result = *self;
+        for c in 0..C {
+            let original_norm = self[c].norm();
+            let mut column = result[c];
+            for _ in 0..2 {
+                for j in 0..c {
+                    let projection = result[j].dot(&column);
+                    column -= result[j] * projection;
+                }
+            }
+            let norm = column.norm();
+            if norm <= S::default_epsilon() * original_norm || norm.is_zero() {
+                return None;
+            }
+            result[c] = column / norm;
+        }
+
+        Some(result)
+    }
+}
@@ -1107,6 +1107,252 @@ where
         Self::from_parts(cos_angle, _axis * sin_angle)
     }
 
+    /// Construct a unit quaternion from a rotation vector.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians. The zero vector maps to the identity
+    /// quaternion. Unlike [`Quaternion::from_axis_angle`], this does not
+    /// require normalizing the axis, so it remains accurate for arbitrarily
+    /// small angles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 2_f64));
+    /// let angle = 1.25_f64;
+    /// let scaled_axis = axis.into_inner() * angle;
+    /// let expected = Quaternion::from_axis_angle(&axis, Radians(angle));
+    /// let result = Quaternion::from_scaled_axis(&scaled_axis);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_scaled_axis(scaled_axis: &Vector3<S>) -> Self {
+        let one = S::one();
+        let two = one + one;
+        let angle_squared = scaled_axis.norm_squared();
+        let (scalar, scale) = if angle_squared <= S::default_epsilon() {
+            // Taylor expansions of `cos(angle / 2)` and `sin(angle / 2) / angle`,
+            // exact to working precision in this range.
+            let eight = two * two * two;
+            let forty_eight = eight * (two + two + two);
+
+            (one - angle_squared / eight, one / two - angle_squared / forty_eight)
+        } else {
+            let angle = angle_squared.sqrt();
+            let (sin_half_angle, cos_half_angle) = (angle / two).sin_cos();
+
+            (cos_half_angle, sin_half_angle / angle)
+        };
+
+        Self::from_parts(scalar, scaled_axis * scale)
+    }
+
+    /// Compute the rotation vector of a unit quaternion.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians, with the angle in the range `[0, pi]`.
+    /// Since the quaternions `q` and `-q` represent the same rotation, both
+    /// give the same rotation vector. For a rotation by an angle of `pi`, either
+    /// of the two opposite rotation vectors may be returned. The identity maps
+    /// to the zero vector.
+    ///
+    /// The angle is computed from the full quaternion with a two-argument
+    /// arctangent, so the result is accurate for small angles, and for angles
+    /// near `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_y());
+    /// let quaternion = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+    /// let expected = Vector3::new(0_f64, core::f64::consts::FRAC_PI_2, 0_f64);
+    /// let result = quaternion.scaled_axis();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The quaternions `q` and `-q` have the same rotation vector.
+    /// let result = (-quaternion).scaled_axis();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn scaled_axis(&self) -> Vector3<S> {
+        // Both `q` and `-q` represent the same rotation; the one with a
+        // nonnegative scalar part has a rotation angle in `[0, pi]`.
+        let (scalar, vector) = if self.scalar() < S::zero() {
+            (-self.scalar(), -self.vector())
+        } else {
+            (self.scalar(), self.vector())
+        };
+        let two = S::one() + S::one();
+        let norm_vector_squared = vector.norm_squared();
+        let scale = if norm_vector_squared <= S::default_epsilon() {
+            // The angle is `2 * atan2(|v|, s)`, and `atan2(|v|, s) / |v|` is
+            // `1 / s` to within rounding error in this range.
+            two / scalar
+        } else {
+            let norm_vector = norm_vector_squared.sqrt();
+
+            two * norm_vector.atan2(scalar) / norm_vector
+        };
+
+        vector * scale
+    }
+
+    /// Integrate a constant angular velocity expressed in the world frame over
+    /// a time step.
+    ///
+    /// The unit quaternion `self` is the orientation at the start of the time
+    /// step. The function returns the orientation after rotating for a time
+    /// `dt` with the angular velocity `angular_velocity`, whose components are
+    /// measured in the world (fixed) frame. The update uses the exponential map
+    /// ```text
+    /// q(t + dt) := from_scaled_axis(angular_velocity * dt) * q(t)
+    /// ```
+    /// which is exact for a constant angular velocity over the time step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(90_f64));
+    /// // One quarter turn per second about the world z-axis.
+    /// let angular_velocity = Vector3::new(0_f64, 0_f64, core::f64::consts::FRAC_PI_2);
+    /// let expected = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Degrees(45_f64)) * orientation;
+    /// let result = orientation.integrate_angular_velocity(&angular_velocity, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn integrate_angular_velocity(&self, angular_velocity: &Vector3<S>, dt: S) -> Self {
+        Self::from_scaled_axis(&(angular_velocity * dt)) * self
+    }
+
+    /// Integrate a constant angular velocity expressed in the body frame over
+    /// a time step.
+    ///
+    /// The unit quaternion `self` is the orientation at the start of the time
+    /// step. The function returns the orientation after rotating for a time
+    /// `dt` with the angular velocity `angular_velocity`, whose components are
+    /// measured in the body (rotating) frame, as reported by a gyroscope. The
+    /// update uses the exponential map
+    /// ```text
+    /// q(t + dt) := q(t) * from_scaled_axis(angular_velocity * dt)
+    /// ```
+    /// which is exact for a constant angular velocity over the time step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(90_f64));
+    /// // One quarter turn per second about the body z-axis.
+    /// let angular_velocity = Vector3::new(0_f64, 0_f64, core::f64::consts::FRAC_PI_2);
+    /// let expected = orientation * Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Degrees(45_f64));
+    /// let result = orientation.integrate_body_angular_velocity(&angular_velocity, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn integrate_body_angular_velocity(&self, angular_velocity: &Vector3<S>, dt: S) -> Self {
+        self * Self::from_scaled_axis(&(angular_velocity * dt))
+    }
+
+    /// Compute the constant angular velocity in the world frame that rotates
+    /// the orientation `self` into the orientation `next` over a time step `dt`.
+    ///
+    /// This is the inverse of [`Quaternion::integrate_angular_velocity`]. Both
+    /// quaternions must be unit quaternions, and the time step must be nonzero.
+    /// The rotation between the two orientations is taken along the shortest
+    /// path, so the rotation angle over the time step is at most `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64)), Degrees(30_f64));
+    /// let angular_velocity = Vector3::new(0.3_f64, -1.2_f64, 0.8_f64);
+    /// let dt = 0.1_f64;
+    /// let next = orientation.integrate_angular_velocity(&angular_velocity, dt);
+    /// let result = orientation.angular_velocity(&next, dt);
+    ///
+    /// assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn angular_velocity(&self, next: &Self, dt: S) -> Vector3<S> {
+        (next * self.conjugate()).scaled_axis() / dt
+    }
+
+    /// Compute the constant angular velocity in the body frame that rotates
+    /// the orientation `self` into the orientation `next` over a time step `dt`.
+    ///
+    /// This is the inverse of [`Quaternion::integrate_body_angular_velocity`].
+    /// Both quaternions must be unit quaternions, and the time step must be
+    /// nonzero. The rotation between the two orientations is taken along the
+    /// shortest path, so the rotation angle over the time step is at most `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(0_f64, 2_f64, 1_f64)), Degrees(-75_f64));
+    /// let angular_velocity = Vector3::new(-2_f64, 0.5_f64, 1.5_f64);
+    /// let dt = 0.05_f64;
+    /// let next = orientation.integrate_body_angular_velocity(&angular_velocity, dt);
+    /// let result = orientation.body_angular_velocity(&next, dt);
+    ///
+    /// assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn body_angular_velocity(&self, next: &Self, dt: S) -> Vector3<S> {
+        (self.conjugate() * next).scaled_axis() / dt
+    }
+
     /// Construct a quaternion from an equivalent 3x3 matrix.
     ///
     /// A quaternion can be constructed by starting from the Euler-Rodrigues
@@ -2401,6 +2647,93 @@ where
         }
     }
 
+    /// Decompose a unit quaternion into a swing and a twist about an axis.
+    ///
+    /// The twist is the rotation about `axis`, and the swing is the remaining
+    /// rotation, whose axis is perpendicular to `axis`. The function returns the
+    /// pair `(swing, twist)`, where
+    /// ```text
+    /// self == swing * twist
+    /// ```
+    /// so the twist applies first, followed by the swing. The twist is the
+    /// normalized projection of the quaternion onto the rotations about `axis`,
+    /// and it has a nonnegative scalar part, so its rotation angle lies in the
+    /// interval `[-pi, pi]`.
+    ///
+    /// When the rotation is a half turn about an axis perpendicular to `axis`, the
+    /// rotation has no twist component, and the twist is the identity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let unit_x = Unit::from_value(Vector3::unit_x());
+    /// let unit_y = Unit::from_value(Vector3::unit_y());
+    /// let expected_swing = Quaternion::from_axis_angle(&unit_x, Degrees(30_f64));
+    /// let expected_twist = Quaternion::from_axis_angle(&unit_y, Degrees(50_f64));
+    /// let quaternion = expected_swing * expected_twist;
+    /// let (swing, twist) = quaternion.swing_twist(&unit_y);
+    ///
+    /// assert_relative_eq!(swing, expected_swing, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(twist, expected_twist, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(swing * twist, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn swing_twist(&self, axis: &Unit<Vector3<S>>) -> (Self, Self) {
+        Self::swing_twist_eps(self, axis, S::default_epsilon())
+    }
+
+    #[inline]
+    fn swing_twist_eps(&self, axis: &Unit<Vector3<S>>, threshold: S) -> (Self, Self) {
+        let axis = axis.as_ref();
+        let projected = Self::from_parts(self.scalar(), axis * self.vector().dot(axis));
+        if let Some(twist) = projected.try_normalize(threshold) {
+            let twist = if twist.scalar() < S::zero() { -twist } else { twist };
+            let swing = self * twist.conjugate();
+
+            (swing, twist)
+        } else {
+            // The rotation is a half turn about an axis perpendicular to `axis`,
+            // so it has no twist component.
+            (*self, Self::identity())
+        }
+    }
+
+    /// Construct a unit quaternion from a swing and a twist.
+    ///
+    /// This is the inverse of [`Quaternion::swing_twist`]. The twist applies
+    /// first, followed by the swing, so the result is `swing * twist`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64));
+    /// let quaternion = Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(3_f64, -1_f64, 0_f64)), Degrees(100_f64));
+    /// let (swing, twist) = quaternion.swing_twist(&axis);
+    /// let result = Quaternion::from_swing_twist(&swing, &twist);
+    ///
+    /// assert_relative_eq!(result, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_swing_twist(swing: &Self, twist: &Self) -> Self {
+        swing * twist
+    }
+
 
     /// Construct a quaternion corresponding to a rotation of an observer  
     /// standing at the origin facing the direction `direction` to an observer
@@ -2905,6 +3238,219 @@ where
         Self::from_parts(q_scalar, q_vector)
     }
 
+    /// Compute the intermediate control point of a squad curve at a keyframe.
+    ///
+    /// Given three consecutive unit quaternion keyframes `previous`, `current`,
+    /// and `next`, the intermediate control point at `current` is
+    /// ```text
+    /// a == current * exp(-(ln(current^-1 * next) + ln(current^-1 * previous)) / 4)
+    /// ```
+    /// Using these control points in [`Quaternion::squad`] produces a curve that
+    /// passes through every keyframe and whose angular velocity is continuous
+    /// across keyframes. At the first or last keyframe of a sequence, pass the
+    /// keyframe itself as the missing neighbor.
+    ///
+    /// The quaternions `q` and `-q` represent the same rotation, so `previous`
+    /// and `next` are first negated if needed to lie in the same hemisphere as
+    /// `current`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// // Keyframes at evenly spaced angles about one axis have no curvature to
+    /// // correct, so the control point is the keyframe itself.
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let previous = Quaternion::from_axis_angle(&axis, Degrees(10_f64));
+    /// let current = Quaternion::from_axis_angle(&axis, Degrees(40_f64));
+    /// let next = Quaternion::from_axis_angle(&axis, Degrees(70_f64));
+    /// let result = Quaternion::squad_control_point(&previous, &current, &next);
+    ///
+    /// assert_relative_eq!(result, current, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn squad_control_point(previous: &Self, current: &Self, next: &Self) -> Self {
+        let one_fourth: S = cglinalg_numeric::cast(0.25_f64);
+        let current_inverse = current.conjugate();
+        let ln_previous = (current_inverse * current.align_hemisphere(previous)).ln();
+        let ln_next = (current_inverse * current.align_hemisphere(next)).ln();
+        let tangent = (ln_next.vector() + ln_previous.vector()) * (-one_fourth);
+
+        current * Self::from_pure(tangent).exp()
+    }
+
+    /// Compute the spherical quadrangle interpolation (squad) between two unit
+    /// quaternions.
+    ///
+    /// Squad interpolates from `self` to `other` along a smooth curve shaped by
+    /// the intermediate control points `control1` and `control2`, as in
+    /// ```text
+    /// squad(q1, a1, a2, q2, t) == slerp(slerp(q1, q2, t), slerp(a1, a2, t), 2 * t * (1 - t))
+    /// ```
+    /// The curve passes through `self` at `amount == 0` and through `other` at
+    /// `amount == 1`. To interpolate through a sequence of keyframes, compute the
+    /// control points of each keyframe with [`Quaternion::squad_control_point`].
+    /// Consecutive segments then join with continuous angular velocity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let q0 = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(0_f64));
+    /// let q1 = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(60_f64));
+    /// let q2 = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_y()), Degrees(60_f64));
+    /// let q3 = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Degrees(90_f64));
+    /// let control1 = Quaternion::squad_control_point(&q0, &q1, &q2);
+    /// let control2 = Quaternion::squad_control_point(&q1, &q2, &q3);
+    ///
+    /// assert_relative_eq!(q1.squad(&control1, &control2, &q2, 0_f64), q1, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(q1.squad(&control1, &control2, &q2, 1_f64), q2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn squad(&self, control1: &Self, control2: &Self, other: &Self, amount: S) -> Self {
+        let one = S::one();
+        let two = one + one;
+        let q_keys = self.slerp(other, amount);
+        let q_controls = q_keys.align_hemisphere(&control1.slerp(control2, amount));
+
+        q_keys.slerp(&q_controls, two * amount * (one - amount))
+    }
+
+    /// Compute the incoming and outgoing spherical Bezier control points of a
+    /// spline through unit quaternion keyframes.
+    ///
+    /// Given three consecutive unit quaternion keyframes `previous`, `current`,
+    /// and `next`, this function returns the control points on either side of
+    /// `current`, as the pair `(incoming, outgoing)`. The tangent at `current` is
+    /// the Catmull-Rom tangent
+    /// ```text
+    /// w == (ln(current^-1 * next) - ln(current^-1 * previous)) / 2
+    /// ```
+    /// and the control points are
+    /// ```text
+    /// incoming == current * exp(-w / 3)
+    /// outgoing == current * exp(w / 3)
+    /// ```
+    /// The segment from keyframe `i` to keyframe `i + 1` then uses the outgoing
+    /// control point of keyframe `i` and the incoming control point of keyframe
+    /// `i + 1` in [`Quaternion::spherical_bezier`], and consecutive segments join
+    /// with continuous angular velocity. At the first or last keyframe of a
+    /// sequence, pass the keyframe itself as the missing neighbor.
+    ///
+    /// The quaternions `previous` and `next` are first negated if needed to lie
+    /// in the same hemisphere as `current`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let previous = Quaternion::from_axis_angle(&axis, Degrees(0_f64));
+    /// let current = Quaternion::from_axis_angle(&axis, Degrees(30_f64));
+    /// let next = Quaternion::from_axis_angle(&axis, Degrees(60_f64));
+    /// let (incoming, outgoing) = Quaternion::spherical_bezier_control_points(&previous, &current, &next);
+    ///
+    /// // The control points divide the rotation between keyframes into thirds.
+    /// let expected_incoming = Quaternion::from_axis_angle(&axis, Degrees(20_f64));
+    /// let expected_outgoing = Quaternion::from_axis_angle(&axis, Degrees(40_f64));
+    ///
+    /// assert_relative_eq!(incoming, expected_incoming, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(outgoing, expected_outgoing, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn spherical_bezier_control_points(previous: &Self, current: &Self, next: &Self) -> (Self, Self) {
+        let one_sixth: S = cglinalg_numeric::cast(1_f64 / 6_f64);
+        let current_inverse = current.conjugate();
+        let ln_previous = (current_inverse * current.align_hemisphere(previous)).ln();
+        let ln_next = (current_inverse * current.align_hemisphere(next)).ln();
+        let tangent_over_three = (ln_next.vector() - ln_previous.vector()) * one_sixth;
+        let incoming = current * Self::from_pure(-tangent_over_three).exp();
+        let outgoing = current * Self::from_pure(tangent_over_three).exp();
+
+        (incoming, outgoing)
+    }
+
+    /// Evaluate the spherical cubic Bezier curve with the unit quaternion
+    /// control points `self`, `control1`, `control2`, and `other`.
+    ///
+    /// The curve is evaluated by the De Casteljau algorithm, with spherical
+    /// linear interpolation taking the place of linear interpolation. The curve
+    /// passes through `self` at `amount == 0` and through `other` at
+    /// `amount == 1`, and it leaves `self` in the direction of `control1` and
+    /// arrives at `other` from the direction of `control2`. To interpolate through
+    /// a sequence of keyframes, compute the control points of each keyframe with
+    /// [`Quaternion::spherical_bezier_control_points`].
+    ///
+    /// Each control point is first negated if needed to lie in the same
+    /// hemisphere as the one before it. The curve therefore ends at either
+    /// `other` or `-other`, which represent the same rotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// // Control points evenly spaced about one axis trace out a slerp.
+    /// let axis = Unit::from_value(Vector3::unit_y());
+    /// let q0 = Quaternion::from_axis_angle(&axis, Degrees(0_f64));
+    /// let q1 = Quaternion::from_axis_angle(&axis, Degrees(30_f64));
+    /// let q2 = Quaternion::from_axis_angle(&axis, Degrees(60_f64));
+    /// let q3 = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+    /// let expected = Quaternion::from_axis_angle(&axis, Degrees(45_f64));
+    /// let result = q0.spherical_bezier(&q1, &q2, &q3, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn spherical_bezier(&self, control1: &Self, control2: &Self, other: &Self, amount: S) -> Self {
+        let control1 = self.align_hemisphere(control1);
+        let control2 = control1.align_hemisphere(control2);
+        let other = control2.align_hemisphere(other);
+        let q01 = self.slerp(&control1, amount);
+        let q12 = control1.slerp(&control2, amount);
+        let q23 = control2.slerp(&other, amount);
+        let q012 = q01.slerp(&q12, amount);
+        let q123 = q12.slerp(&q23, amount);
+
+        q012.slerp(&q123, amount)
+    }
+
+    /// Negate the quaternion `other` if it does not lie in the same hemisphere
+    /// as `self`.
+    #[inline]
+    fn align_hemisphere(&self, other: &Self) -> Self {
+        if self.dot(other) < S::zero() {
+            -other
+        } else {
+            *other
+        }
+    }
+
     /// Returns `true` if the elements of a quaternion are all finite.
     /// Otherwise, it returns `false`.
     ///
@@ -4697,3 +5243,300 @@ where
         Unit::from_value_unchecked(-self.into_inner())
     }
 }
+
+impl<S> Unit<Quaternion<S>>
+where
+    S: SimdScalarFloat,
+{
+    /// Evaluate the squad spline through a sequence of unit quaternion
+    /// keyframes.
+    ///
+    /// The keyframes sit at the integer parameter values `0, 1, ..., n - 1`,
+    /// where `n` is the number of keyframes, and `parameter` is clamped to that
+    /// range. The spline passes through every keyframe, and the intermediate
+    /// control points are computed automatically with
+    /// [`Quaternion::squad_control_point`], so the resulting rotation curve has
+    /// continuous angular velocity. The function returns `None` when `keys` is
+    /// empty, or when `parameter` is not finite.
+    ///
+    /// Neighboring keyframes need not lie in the same hemisphere: each segment
+    /// takes the shorter path between its keyframes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let keys = [
+    ///     Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(0_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(90_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_y()), Degrees(45_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Degrees(-30_f64))),
+    /// ];
+    ///
+    /// for (i, key) in keys.iter().enumerate() {
+    ///     let result = Unit::squad_spline(&keys, i as f64).unwrap();
+    ///
+    ///     assert_relative_eq!(result, *key, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// }
+    /// assert!(Unit::<Quaternion<f64>>::squad_spline(&[], 0_f64).is_none());
+    /// assert!(Unit::squad_spline(&keys, f64::NAN).is_none());
+    /// ```
+    pub fn squad_spline(keys: &[Self], parameter: S) -> Option<Self> {
+        spline_segment(keys, parameter).map(|([q0, q1, q2, q3], amount)| {
+            let control1 = Quaternion::squad_control_point(&q0, &q1, &q2);
+            let control2 = Quaternion::squad_control_point(&q1, &q2, &q3);
+            let result = q1.squad(&control1, &control2, &q2, amount);
+
+            Unit::from_value(result)
+        })
+    }
+
+    /// Evaluate the spherical cubic Bezier spline through a sequence of unit
+    /// quaternion keyframes.
+    ///
+    /// The keyframes sit at the integer parameter values `0, 1, ..., n - 1`,
+    /// where `n` is the number of keyframes, and `parameter` is clamped to that
+    /// range. The spline passes through every keyframe, and the control points
+    /// are computed automatically with
+    /// [`Quaternion::spherical_bezier_control_points`], so the resulting rotation
+    /// curve has continuous angular velocity. The function returns `None` when
+    /// `keys` is empty, or when `parameter` is not finite.
+    ///
+    /// Neighboring keyframes need not lie in the same hemisphere: each segment
+    /// takes the shorter path between its keyframes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let keys = [
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(0_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(40_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(80_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(120_f64))),
+    /// ];
+    /// // Between interior keyframes evenly spaced about one axis, the spline
+    /// // rotates at constant speed.
+    /// let expected = Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(60_f64)));
+    /// let result = Unit::spherical_bezier_spline(&keys, 1.5_f64).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn spherical_bezier_spline(keys: &[Self], parameter: S) -> Option<Self> {
+        spline_segment(keys, parameter).map(|([q0, q1, q2, q3], amount)| {
+            let (_, control1) = Quaternion::spherical_bezier_control_points(&q0, &q1, &q2);
+            let (control2, _) = Quaternion::spherical_bezier_control_points(&q1, &q2, &q3);
+            let result = q1.spherical_bezier(&control1, &control2, &q2, amount);
+
+            Unit::from_value(result)
+        })
+    }
+
+    /// Compute the weighted mean of a collection of unit quaternions.
+    ///
+    /// The mean rotation is computed with the eigenvector method of Markley,
+    /// Cheng, Crassidis, and Oshman. The mean is the unit quaternion `q` that
+    /// maximizes
+    /// ```text
+    /// sum(i, weights[i] * dot(q, quaternions[i])^2)
+    /// ```
+    /// i.e. the rotation minimizing the weighted sum of squared chordal distances
+    /// to the given rotations. It is the eigenvector of the largest eigenvalue of
+    /// the `4x4` symmetric matrix
+    /// ```text
+    /// M == sum(i, weights[i] * quaternions[i] * transpose(quaternions[i]))
+    /// ```
+    /// Since `q` and `-q` enter `M` in the same way, the result does not depend
+    /// on the signs of the input quaternions, and unlike averaging components,
+    /// it always gives a rotation. The sign of the result is chosen to lie in the
+    /// same hemisphere as the first quaternion. For an unweighted mean, use equal
+    /// weights.
+    ///
+    /// The function returns `None` if `quaternions` is empty, if `quaternions`
+    /// and `weights` have different lengths, if any weight is negative or not
+    /// finite, or if the sum of the weights is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let quaternions = [
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(10_f64))),
+    ///     // The negation of a quaternion represents the same rotation.
+    ///     Unit::from_value(-Quaternion::from_axis_angle(&axis, Degrees(30_f64))),
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(50_f64))),
+    /// ];
+    /// let weights = [1_f64, 1_f64, 1_f64];
+    /// let expected = Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(30_f64)));
+    /// let result = Unit::weighted_mean(&quaternions, &weights).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(Unit::weighted_mean(&quaternions, &[1_f64, -1_f64, 1_f64]).is_none());
+    /// ```
+    pub fn weighted_mean(quaternions: &[Self], weights: &[S]) -> Option<Self> {
+        let total_weight = total_weight(quaternions, weights)?;
+        let mut matrix = Matrix4x4::zero();
+        for (quaternion, weight) in quaternions.iter().zip(weights.iter()) {
+            let weight = *weight / total_weight;
+            for c in 0..4 {
+                for r in 0..4 {
+                    matrix[c][r] += weight * quaternion[c] * quaternion[r];
+                }
+            }
+        }
+
+        // The eigenvalues are sorted in ascending order, so the last eigenvector
+        // belongs to the largest eigenvalue.
+        let eigen = matrix.try_symmetric_eigen()?;
+        let mean = Quaternion::from_vector(eigen.eigenvectors()[3]);
+        let first = quaternions[0].into_inner();
+
+        Some(Unit::from_value(first.align_hemisphere(&mean)))
+    }
+
+    /// Approximate the weighted mean of a collection of unit quaternions by
+    /// iterated normalized linear interpolation.
+    ///
+    /// Each iteration brings every quaternion into the same hemisphere as the
+    /// current estimate, and then takes the normalized weighted sum of the
+    /// quaternions as the next estimate, starting from the first quaternion.
+    /// The iteration stops once the signs of the quaternions no longer change,
+    /// which usually takes one or two iterations.
+    ///
+    /// This is much cheaper than [`Unit::weighted_mean`], and it gives nearly
+    /// the same result when the rotations are close to each other, as in
+    /// animation blending or filtering noisy orientation measurements. The
+    /// approximation degrades as the rotations spread apart.
+    ///
+    /// The function returns `None` if `quaternions` is empty, if `quaternions`
+    /// and `weights` have different lengths, if any weight is negative or not
+    /// finite, if the sum of the weights is not positive, or if the weighted sum
+    /// of the quaternions vanishes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let quaternions = [
+    ///     Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(20_f64))),
+    ///     Unit::from_value(-Quaternion::from_axis_angle(&axis, Degrees(40_f64))),
+    /// ];
+    /// let weights = [1_f64, 1_f64];
+    /// let expected = Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(30_f64)));
+    /// let result = Unit::weighted_mean_nlerp(&quaternions, &weights).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn weighted_mean_nlerp(quaternions: &[Self], weights: &[S]) -> Option<Self> {
+        // Each iteration either keeps or flips the signs of the quaternions, so
+        // the estimate settles after at most a few iterations.
+        const MAX_ITERATIONS: usize = 8;
+
+        let total_weight = total_weight(quaternions, weights)?;
+        let mut estimate = quaternions[0].into_inner();
+        for _ in 0..MAX_ITERATIONS {
+            let sum = quaternions
+                .iter()
+                .zip(weights.iter())
+                .fold(Quaternion::zero(), |sum, (quaternion, weight)| {
+                    sum + estimate.align_hemisphere(quaternion) * (*weight / total_weight)
+                });
+            let next_estimate = sum.try_normalize(S::default_epsilon())?;
+            let converged = next_estimate == estimate;
+            estimate = next_estimate;
+            if converged {
+                break;
+            }
+        }
+
+        Some(Unit::from_value_unchecked(estimate))
+    }
+}
+
+/// Find the spline segment containing `parameter`, returning the keyframes
+/// `[q0, q1, q2, q3]` surrounding it and the local parameter of the segment.
+///
+/// The keyframes of the segment run from `q1` to `q2`, and `q0` and `q3` are
+/// their outer neighbors, repeating the end keyframes at the ends of the
+/// sequence. Each keyframe is brought into the same hemisphere as the one
+/// before it.
+fn spline_segment<S>(keys: &[Unit<Quaternion<S>>], parameter: S) -> Option<([Quaternion<S>; 4], S)>
+where
+    S: SimdScalarFloat,
+{
+    if keys.is_empty() || !parameter.is_finite() {
+        return None;
+    }
+
+    let last = keys.len() - 1;
+    if last == 0 {
+        let key = keys[0].into_inner();
+
+        return Some(([key, key, key, key], S::zero()));
+    }
+
+    let parameter = parameter.clamp(S::zero(), cglinalg_numeric::cast(last));
+    let segment = usize::min(cglinalg_numeric::cast(parameter.floor()), last - 1);
+    let amount = parameter - cglinalg_numeric::cast(segment);
+    let q1 = keys[segment].into_inner();
+    let q0 = q1.align_hemisphere(&keys[segment.saturating_sub(1)]);
+    let q2 = q1.align_hemisphere(&keys[segment + 1]);
+    let q3 = q2.align_hemisphere(&keys[usize::min(segment + 2, last)]);
+
+    Some(([q0, q1, q2, q3], amount))
+}
+
+/// Compute the sum of the weights of a weighted mean of quaternions, if the
+/// mean is well defined.
+///
+/// The mean is well defined when there is one weight for each quaternion, every
+/// weight is finite and nonnegative, and the weights have a positive sum.
+fn total_weight<S>(quaternions: &[Unit<Quaternion<S>>], weights: &[S]) -> Option<S>
+where
+    S: SimdScalarFloat,
+{
+    if quaternions.is_empty() || quaternions.len() != weights.len() {
+        return None;
+    }
+    if weights.iter().any(|weight| !weight.is_finite() || *weight < S::zero()) {
+        return None;
+    }
+
+    let total_weight = weights.iter().fold(S::zero(), |total, weight| total + *weight);
+    if total_weight > S::zero() {
+        Some(total_weight)
+    } else {
+        None
+    }
+}
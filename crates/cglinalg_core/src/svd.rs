@@ -0,0 +1,486 @@
+use crate::lu::LuDecomposition;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The singular value decomposition of a square matrix.
+///
+/// The decomposition of a square matrix `a` with `N` rows and `N` columns is a
+/// pair of orthogonal matrices `u` and `v`, and a diagonal matrix `sigma` with
+/// nonnegative diagonal elements such that
+/// ```text
+/// a == u * sigma * transpose(v)
+/// ```
+/// The diagonal elements of `sigma` are the **singular values** of `a`, and they
+/// are sorted in descending order. The columns of `u` are the **left singular
+/// vectors**, and the columns of `v` are the **right singular vectors**.
+///
+/// The decomposition is computed with the one-sided Jacobi method, which works
+/// on `a` directly instead of on `transpose(a) * a`, so it does not square the
+/// condition number of `a`, and it computes the singular vectors to working
+/// precision. When `a` is singular, the left singular vectors for the zero
+/// singular values are completed to an orthonormal basis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SingularValueDecomposition<S, const N: usize> {
+    u: Matrix<S, N, N>,
+    singular_values: Vector<S, N>,
+    v: Matrix<S, N, N>,
+}
+
+impl<S, const N: usize> SingularValueDecomposition<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the singular value decomposition of a square matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     SingularValueDecomposition,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     0_f64, 3_f64, 0_f64,
+    ///    -2_f64, 0_f64, 0_f64,
+    ///     0_f64, 0_f64, 1_f64,
+    /// );
+    /// let svd = SingularValueDecomposition::new(&matrix);
+    /// let expected = Vector3::new(3_f64, 2_f64, 1_f64);
+    ///
+    /// assert_relative_eq!(svd.singular_values(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(svd.recompose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new(matrix: &Matrix<S, N, N>) -> Self {
+        const MAX_SWEEPS: usize = 64;
+
+        let two = S::one() + S::one();
+        let mut u = *matrix;
+        let mut v = Matrix::<S, N, N>::identity();
+        for _ in 0..MAX_SWEEPS {
+            let mut converged = true;
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    let alpha = u[p].dot(&u[p]);
+                    let beta = u[q].dot(&u[q]);
+                    let gamma = u[p].dot(&u[q]);
+                    if gamma.abs() <= S::default_epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    converged = false;
+
+                    // Rotate columns `p` and `q` to make them orthogonal, with the
+                    // smaller of the two rotation angles, with tangent `t`.
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + S::one()).sqrt());
+                    let cos = S::one() / (t * t + S::one()).sqrt();
+                    let sin = t * cos;
+                    let u_p = u[p];
+                    let u_q = u[q];
+                    u[p] = u_p * cos - u_q * sin;
+                    u[q] = u_p * sin + u_q * cos;
+                    let v_p = v[p];
+                    let v_q = v[q];
+                    v[p] = v_p * cos - v_q * sin;
+                    v[q] = v_p * sin + v_q * cos;
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+
+        let mut singular_values = Vector::zero();
+        for i in 0..N {
+            singular_values[i] = u[i].dot(&u[i]).sqrt();
+        }
+        // Sort the singular values in descending order, moving the singular
+        // vectors along with them.
+        for i in 0..N {
+            let mut max_index = i;
+            for j in (i + 1)..N {
+                if singular_values[j] > singular_values[max_index] {
+                    max_index = j;
+                }
+            }
+            if max_index != i {
+                let singular_value = singular_values[i];
+                singular_values[i] = singular_values[max_index];
+                singular_values[max_index] = singular_value;
+                u.swap_columns(i, max_index);
+                v.swap_columns(i, max_index);
+            }
+        }
+
+        let mut rank = 0;
+        while rank < N && !singular_values[rank].is_zero() {
+            u[rank] /= singular_values[rank];
+            rank += 1;
+        }
+        // The columns of `u` for zero singular values vanish, so complete the
+        // nonzero columns to an orthonormal basis with the standard basis vector
+        // farthest from their span.
+        for i in rank..N {
+            let mut best = Vector::zero();
+            let mut best_norm_squared = S::zero();
+            for k in 0..N {
+                let mut candidate = Vector::zero();
+                candidate[k] = S::one();
+                for _ in 0..2 {
+                    for j in 0..i {
+                        let projection = u[j].dot(&candidate);
+                        candidate -= u[j] * projection;
+                    }
+                }
+                let norm_squared = candidate.dot(&candidate);
+                if norm_squared > best_norm_squared {
+                    best = candidate;
+                    best_norm_squared = norm_squared;
+                }
+            }
+            u[i] = best / best_norm_squared.sqrt();
+        }
+
+        Self { u, singular_values, v }
+    }
+
+    /// Get the left singular vectors `u` of the decomposition.
+    ///
+    /// The columns of `u` are orthonormal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     SingularValueDecomposition,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     3_f64, 0_f64,
+    ///     4_f64, 5_f64,
+    /// );
+    /// let svd = SingularValueDecomposition::new(&matrix);
+    /// let u = svd.u();
+    ///
+    /// assert_relative_eq!(u.transpose() * u, Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn u(&self) -> &Matrix<S, N, N> {
+        &self.u
+    }
+
+    /// Get the singular values of the decomposition in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     SingularValueDecomposition,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     3_f64, 0_f64,
+    ///     4_f64, 5_f64,
+    /// );
+    /// let svd = SingularValueDecomposition::new(&matrix);
+    /// let expected = Vector2::new(3_f64 * f64::sqrt(5_f64), f64::sqrt(5_f64));
+    ///
+    /// assert_relative_eq!(svd.singular_values(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn singular_values(&self) -> &Vector<S, N> {
+        &self.singular_values
+    }
+
+    /// Get the right singular vectors `v` of the decomposition.
+    ///
+    /// The columns of `v` are orthonormal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     SingularValueDecomposition,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     3_f64, 0_f64,
+    ///     4_f64, 5_f64,
+    /// );
+    /// let svd = SingularValueDecomposition::new(&matrix);
+    /// let u = svd.u();
+    /// let v = svd.v();
+    /// let singular_values = svd.singular_values();
+    ///
+    /// assert_relative_eq!(v.transpose() * v, Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// for i in 0..2 {
+    ///     assert_relative_eq!(matrix * v[i], u[i] * singular_values[i], abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// }
+    /// ```
+    #[inline]
+    pub const fn v(&self) -> &Matrix<S, N, N> {
+        &self.v
+    }
+
+    /// Reconstruct the original matrix from its singular value decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     SingularValueDecomposition,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 10_f64,
+    /// );
+    /// let svd = SingularValueDecomposition::new(&matrix);
+    ///
+    /// assert_relative_eq!(svd.recompose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn recompose(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::zero();
+        for c in 0..N {
+            for r in 0..N {
+                result[c][r] = (0..N).fold(S::zero(), |acc, k| acc + self.u[k][r] * self.singular_values[k] * self.v[k][c]);
+            }
+        }
+
+        result
+    }
+}
+
+/// The polar decomposition of a square matrix into a rotation and a stretch.
+///
+/// The decomposition of a square matrix `a` is a rotation matrix `r` and a
+/// symmetric matrix `s` such that
+/// ```text
+/// a == r * s
+/// ```
+/// The rotation is always proper, that is, `det(r) == 1`, so it never contains
+/// a reflection. When `det(a) >= 0`, the stretch `s` is positive semidefinite,
+/// and `r` is the rotation closest to `a` in the Frobenius norm. When
+/// `det(a) < 0`, the reflection in `a` is moved into the stretch by negating its
+/// smallest principal stretch, which is the least distorting choice. This is the
+/// convention used for extracting rotations from deformation gradients in
+/// physics simulation, and for the Kabsch algorithm.
+///
+/// The decomposition is computed from the singular value decomposition
+/// `a == u * sigma * transpose(v)` as
+/// ```text
+/// r == u * d * transpose(v)
+/// s == v * d * sigma * transpose(v)
+/// ```
+/// where `d` is the identity matrix with its last diagonal element replaced by
+/// `det(u) * det(v)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PolarDecomposition<S, const N: usize> {
+    rotation: Matrix<S, N, N>,
+    stretch: Matrix<S, N, N>,
+}
+
+impl<S, const N: usize> PolarDecomposition<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the polar decomposition of a square matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     PolarDecomposition,
+    /// # };
+    /// #
+    /// let (sin, cos) = f64::sin_cos(0.4_f64);
+    /// let rotation = Matrix2x2::new(
+    ///      cos, sin,
+    ///     -sin, cos,
+    /// );
+    /// let stretch = Matrix2x2::new(
+    ///     2_f64,   0.5_f64,
+    ///     0.5_f64, 1_f64,
+    /// );
+    /// let matrix = rotation * stretch;
+    /// let polar = PolarDecomposition::new(&matrix);
+    ///
+    /// assert_relative_eq!(polar.rotation(), &rotation, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(polar.stretch(), &stretch, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new(matrix: &Matrix<S, N, N>) -> Self {
+        let svd = SingularValueDecomposition::new(matrix);
+        let u = svd.u();
+        let v = svd.v();
+        let mut signs = Vector::<S, N>::from_fill(S::one());
+        if N > 0 {
+            signs[N - 1] = LuDecomposition::new(u).determinant() * LuDecomposition::new(v).determinant();
+            signs[N - 1] = signs[N - 1].signum();
+        }
+
+        let mut rotation = Matrix::zero();
+        let mut stretch = Matrix::zero();
+        for c in 0..N {
+            for r in 0..N {
+                rotation[c][r] = (0..N).fold(S::zero(), |acc, k| acc + u[k][r] * signs[k] * v[k][c]);
+                stretch[c][r] = (0..N).fold(S::zero(), |acc, k| acc + v[k][r] * signs[k] * svd.singular_values()[k] * v[k][c]);
+            }
+        }
+
+        Self { rotation, stretch }
+    }
+
+    /// Get the rotation factor `r` of the decomposition.
+    ///
+    /// The rotation matrix satisfies `det(r) == 1`.
+    ///
+    /// # Example
+    ///
+    /// Find the rotation that best aligns a set of points with a rotated copy
+    /// of it with the Kabsch algorithm.
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     PolarDecomposition,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let (sin, cos) = f64::sin_cos(1.2_f64);
+    /// let rotation = Matrix3x3::new(
+    ///      cos,   0_f64, sin,
+    ///      0_f64, 1_f64, 0_f64,
+    ///     -sin,   0_f64, cos,
+    /// );
+    /// let points = [
+    ///     Vector3::new(1_f64, 0_f64, 0_f64),
+    ///     Vector3::new(0_f64, 2_f64, 0_f64),
+    ///     Vector3::new(0_f64, 0_f64, 3_f64),
+    ///     Vector3::new(-1_f64, -1_f64, -1_f64),
+    /// ];
+    /// let mut covariance = Matrix3x3::zero();
+    /// for point in points.iter() {
+    ///     let rotated = rotation * point;
+    ///     for c in 0..3 {
+    ///         for r in 0..3 {
+    ///             covariance[c][r] += rotated[r] * point[c];
+    ///         }
+    ///     }
+    /// }
+    /// let polar = PolarDecomposition::new(&covariance);
+    ///
+    /// assert_relative_eq!(polar.rotation(), &rotation, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn rotation(&self) -> &Matrix<S, N, N> {
+        &self.rotation
+    }
+
+    /// Get the symmetric stretch factor `s` of the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     PolarDecomposition,
+    /// # };
+    /// #
+    /// // A reflection across the `x`-axis followed by a scaling.
+    /// let matrix = Matrix2x2::new(
+    ///     2_f64,  0_f64,
+    ///     0_f64, -1_f64,
+    /// );
+    /// let polar = PolarDecomposition::new(&matrix);
+    ///
+    /// assert_relative_eq!(polar.rotation(), &Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(polar.stretch(), &matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn stretch(&self) -> &Matrix<S, N, N> {
+        &self.stretch
+    }
+}
+
+impl<S, const N: usize> Matrix<S, N, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the singular value decomposition of a square matrix.
+    ///
+    /// See [`SingularValueDecomposition`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// // A rank one matrix.
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 2_f64, 2_f64,
+    ///     2_f64, 4_f64, 4_f64,
+    ///     0_f64, 0_f64, 0_f64,
+    /// );
+    /// let svd = matrix.svd();
+    /// let expected = Vector3::new(3_f64 * f64::sqrt(5_f64), 0_f64, 0_f64);
+    ///
+    /// assert_relative_eq!(svd.singular_values(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(svd.recompose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn svd(&self) -> SingularValueDecomposition<S, N> {
+        SingularValueDecomposition::new(self)
+    }
+
+    /// Compute the polar decomposition of a square matrix into a rotation and
+    /// a symmetric stretch.
+    ///
+    /// See [`PolarDecomposition`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Matrix3x3;
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64,   0.2_f64, 0_f64,
+    ///     -0.3_f64, 1.1_f64, 0.1_f64,
+    ///     0_f64,   0_f64,   0.9_f64,
+    /// );
+    /// let polar = matrix.polar();
+    /// let rotation = polar.rotation();
+    /// let stretch = polar.stretch();
+    ///
+    /// assert_relative_eq!(rotation * stretch, matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(rotation.transpose() * rotation, Matrix3x3::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(rotation.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// assert!(stretch.is_symmetric());
+    /// ```
+    #[inline]
+    pub fn polar(&self) -> PolarDecomposition<S, N> {
+        PolarDecomposition::new(self)
+    }
+}
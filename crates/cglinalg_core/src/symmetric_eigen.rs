@@ -0,0 +1,310 @@
+use crate::lu::LuDecomposition;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The eigendecomposition of a real symmetric matrix.
+///
+/// Every real symmetric matrix `a` with `N` rows and `N` columns has `N` real
+/// eigenvalues and an orthonormal basis of eigenvectors. That is, there is a
+/// diagonal matrix `d` and an orthogonal matrix `v` such that
+/// ```text
+/// a == v * d * transpose(v)
+/// ```
+/// The diagonal of `d` holds the eigenvalues of `a`, and the columns of `v`
+/// hold the corresponding eigenvectors. The eigenvalues are sorted in ascending
+/// order, and the eigenvectors are oriented so that `det(v) == 1`. In particular,
+/// for a three-by-three matrix, `v` is a rotation matrix, which makes it directly
+/// usable as the orientation of a principal frame, for example via
+/// `Quaternion::from_matrix`.
+///
+/// The decomposition is computed with the cyclic Jacobi eigenvalue algorithm,
+/// which produces eigenvectors that are orthonormal to working precision, even
+/// when eigenvalues are repeated or nearly repeated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SymmetricEigen<S, const N: usize> {
+    eigenvalues: Vector<S, N>,
+    eigenvectors: Matrix<S, N, N>,
+}
+
+impl<S, const N: usize> SymmetricEigen<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the eigendecomposition of a symmetric matrix, if the matrix is
+    /// symmetric.
+    ///
+    /// Returns `None` if the matrix is not symmetric (see [`Matrix::is_symmetric`]).
+    /// Only the lower triangle of the matrix is used in the computation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     SymmetricEigen,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     2_f64, 1_f64, 0_f64,
+    ///     1_f64, 2_f64, 0_f64,
+    ///     0_f64, 0_f64, 5_f64,
+    /// );
+    /// let eigen = SymmetricEigen::try_new(&matrix).unwrap();
+    /// let expected = Vector3::new(1_f64, 3_f64, 5_f64);
+    ///
+    /// assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(eigen.recompose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    ///
+    /// let asymmetric = Matrix3x3::new(
+    ///     1_f64, 2_f64, 3_f64,
+    ///     4_f64, 5_f64, 6_f64,
+    ///     7_f64, 8_f64, 9_f64,
+    /// );
+    ///
+    /// assert!(SymmetricEigen::try_new(&asymmetric).is_none());
+    /// ```
+    pub fn try_new(matrix: &Matrix<S, N, N>) -> Option<Self> {
+        if !matrix.is_symmetric() {
+            return None;
+        }
+
+        Some(Self::new_unchecked(matrix))
+    }
+
+    /// Compute the eigendecomposition of a matrix assumed to be symmetric.
+    ///
+    /// Only the lower triangle of the matrix is used in the computation.
+    fn new_unchecked(matrix: &Matrix<S, N, N>) -> Self {
+        const MAX_SWEEPS: usize = 64;
+
+        let two = S::one() + S::one();
+        let mut a = *matrix;
+        for c in 0..N {
+            for r in 0..c {
+                a[c][r] = a[r][c];
+            }
+        }
+        let mut v = Matrix::<S, N, N>::identity();
+
+        let norm_squared = (0..N).fold(S::zero(), |acc, c| acc + (0..N).fold(S::zero(), |acc, r| acc + a[c][r] * a[c][r]));
+        let threshold = S::default_epsilon() * S::default_epsilon() * norm_squared;
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal_squared = (0..N).fold(S::zero(), |acc, q| acc + (0..q).fold(S::zero(), |acc, p| acc + a[q][p] * a[q][p]));
+            if off_diagonal_squared <= threshold {
+                break;
+            }
+
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    let a_pq = a[q][p];
+                    if a_pq.is_zero() {
+                        continue;
+                    }
+
+                    // Choose the smaller of the two rotation angles that annihilate
+                    // the element `(p, q)`, with tangent `t`.
+                    let theta = (a[q][q] - a[p][p]) / (two * a_pq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+                    let cos = S::one() / (t * t + S::one()).sqrt();
+                    let sin = t * cos;
+
+                    a[p][p] -= t * a_pq;
+                    a[q][q] += t * a_pq;
+                    a[q][p] = S::zero();
+                    a[p][q] = S::zero();
+                    for k in 0..N {
+                        if k != p && k != q {
+                            let a_kp = a[p][k];
+                            let a_kq = a[q][k];
+                            a[p][k] = cos * a_kp - sin * a_kq;
+                            a[k][p] = a[p][k];
+                            a[q][k] = sin * a_kp + cos * a_kq;
+                            a[k][q] = a[q][k];
+                        }
+                    }
+                    for k in 0..N {
+                        let v_kp = v[p][k];
+                        let v_kq = v[q][k];
+                        v[p][k] = cos * v_kp - sin * v_kq;
+                        v[q][k] = sin * v_kp + cos * v_kq;
+                    }
+                }
+            }
+        }
+
+        let mut eigenvalues = Vector::zero();
+        for i in 0..N {
+            eigenvalues[i] = a[i][i];
+        }
+        // Sort the eigenvalues in ascending order, moving the eigenvectors along
+        // with them.
+        for i in 0..N {
+            let mut min_index = i;
+            for j in (i + 1)..N {
+                if eigenvalues[j] < eigenvalues[min_index] {
+                    min_index = j;
+                }
+            }
+            if min_index != i {
+                let eigenvalue = eigenvalues[i];
+                eigenvalues[i] = eigenvalues[min_index];
+                eigenvalues[min_index] = eigenvalue;
+                v.swap_columns(i, min_index);
+            }
+        }
+        // Orient the eigenvectors so that they form a proper rotation.
+        if N > 0 && LuDecomposition::new(&v).determinant() < S::zero() {
+            v[N - 1] = -v[N - 1];
+        }
+
+        Self {
+            eigenvalues,
+            eigenvectors: v,
+        }
+    }
+
+    /// Get the eigenvalues of the matrix in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     SymmetricEigen,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     4_f64, 2_f64,
+    ///     2_f64, 1_f64,
+    /// );
+    /// let eigen = SymmetricEigen::try_new(&matrix).unwrap();
+    /// let expected = Vector2::new(0_f64, 5_f64);
+    ///
+    /// assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn eigenvalues(&self) -> &Vector<S, N> {
+        &self.eigenvalues
+    }
+
+    /// Get the eigenvectors of the matrix.
+    ///
+    /// Column `i` of the result is a unit eigenvector for eigenvalue `i`. The
+    /// columns are orthonormal, and the determinant of the result is one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     SymmetricEigen,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     4_f64, 1_f64, -2_f64,
+    ///     1_f64, 3_f64,  0_f64,
+    ///    -2_f64, 0_f64,  6_f64,
+    /// );
+    /// let eigen = SymmetricEigen::try_new(&matrix).unwrap();
+    /// let eigenvalues = eigen.eigenvalues();
+    /// let eigenvectors = eigen.eigenvectors();
+    ///
+    /// for i in 0..3 {
+    ///     assert_relative_eq!(
+    ///         matrix * eigenvectors[i],
+    ///         eigenvectors[i] * eigenvalues[i],
+    ///         abs_diff_all <= 1e-12,
+    ///         relative_all <= f64::EPSILON,
+    ///     );
+    /// }
+    /// assert_relative_eq!(eigenvectors.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn eigenvectors(&self) -> &Matrix<S, N, N> {
+        &self.eigenvectors
+    }
+
+    /// Reconstruct the original matrix from its eigendecomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix2x2,
+    /// #     SymmetricEigen,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///     1_f64, -3_f64,
+    ///    -3_f64,  2_f64,
+    /// );
+    /// let eigen = SymmetricEigen::try_new(&matrix).unwrap();
+    ///
+    /// assert_relative_eq!(eigen.recompose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn recompose(&self) -> Matrix<S, N, N> {
+        let mut result = Matrix::zero();
+        for c in 0..N {
+            for r in 0..N {
+                result[c][r] = (0..N).fold(S::zero(), |acc, k| {
+                    acc + self.eigenvectors[k][r] * self.eigenvalues[k] * self.eigenvectors[k][c]
+                });
+            }
+        }
+
+        result
+    }
+}
+
+impl<S, const N: usize> Matrix<S, N, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the eigendecomposition of a symmetric matrix, if the matrix is
+    /// symmetric.
+    ///
+    /// See [`SymmetricEigen`] for details.
+    ///
+    /// # Example
+    ///
+    /// Compute the principal axes of a set of points.
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let points = [
+    ///     Vector3::new(-2_f64, -1_f64, 0_f64),
+    ///     Vector3::new(2_f64, 1_f64, 0_f64),
+    ///     Vector3::new(-0.5_f64, 1_f64, 0_f64),
+    ///     Vector3::new(0.5_f64, -1_f64, 0_f64),
+    /// ];
+    /// let mut covariance = Matrix3x3::zero();
+    /// for point in points.iter() {
+    ///     for c in 0..3 {
+    ///         for r in 0..3 {
+    ///             covariance[c][r] += point[r] * point[c] / 4_f64;
+    ///         }
+    ///     }
+    /// }
+    /// let eigen = covariance.try_symmetric_eigen().unwrap();
+    /// let expected = Vector3::new(0_f64, 0.625_f64, 2.5_f64);
+    ///
+    /// assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn try_symmetric_eigen(&self) -> Option<SymmetricEigen<S, N>> {
+        SymmetricEigen::try_new(self)
+    }
+}
@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod cholesky_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        CholeskyDecomposition,
+        Matrix,
+        Matrix2x2,
+        Matrix3x3,
+        Matrix4x4,
+        Vector,
+        Vector3,
+        Vector4,
+    };
+
+
+    #[rustfmt::skip]
+    fn matrix4x4() -> Matrix4x4<f64> {
+        Matrix4x4::new(
+             6_f64,    1_f64, -2_f64,  0.5_f64,
+             1_f64,    5_f64,  1_f64, -1_f64,
+            -2_f64,    1_f64,  7_f64,  2_f64,
+             0.5_f64, -1_f64,  2_f64,  4_f64,
+        )
+    }
+
+    #[test]
+    fn test_factor_reproduces_matrix() {
+        let matrix = matrix4x4();
+        let cholesky = matrix.try_cholesky().unwrap();
+        let l = cholesky.l();
+
+        assert_relative_eq!(l * l.transpose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_factor_is_lower_triangular_with_positive_diagonal() {
+        let cholesky = matrix4x4().try_cholesky().unwrap();
+        let l = cholesky.l();
+        for c in 0..4 {
+            assert!(l[c][c] > 0_f64);
+            for r in 0..c {
+                assert_eq!(l[c][r], 0_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = matrix4x4();
+        let expected = Vector4::new(1_f64, -2_f64, 0.5_f64, 3_f64);
+        let b = matrix * expected;
+        let result = matrix.try_cholesky().unwrap().solve(&b);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix = matrix4x4();
+        let result = matrix.try_cholesky().unwrap().inverse();
+        let expected = matrix.try_inverse().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert!(result.is_symmetric());
+    }
+
+    #[test]
+    fn test_determinant() {
+        let matrix = matrix4x4();
+        let cholesky = matrix.try_cholesky().unwrap();
+
+        assert_relative_eq!(
+            cholesky.determinant(),
+            matrix.determinant(),
+            abs_diff <= 1e-10,
+            relative <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            cholesky.log_determinant(),
+            matrix.determinant().ln(),
+            abs_diff <= 1e-12,
+            relative <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_log_determinant_large_matrix_does_not_overflow() {
+        let matrix = Matrix3x3::from_diagonal(&Vector3::new(1e150_f64, 1e150_f64, 1e150_f64));
+        let cholesky = matrix.try_cholesky().unwrap();
+        let expected = 450_f64 * f64::ln(10_f64);
+
+        assert!(cholesky.determinant().is_infinite());
+        assert_relative_eq!(cholesky.log_determinant(), expected, abs_diff <= 1e-10, relative <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_identity_matrix() {
+        let cholesky = Matrix3x3::<f64>::identity().try_cholesky().unwrap();
+
+        assert_eq!(cholesky.l(), &Matrix3x3::identity());
+        assert_eq!(cholesky.log_determinant(), 0_f64);
+    }
+
+    #[test]
+    fn test_hilbert_matrix() {
+        let mut matrix: Matrix<f64, 6, 6> = Matrix::zero();
+        for c in 0..6 {
+            for r in 0..6 {
+                matrix[c][r] = 1_f64 / ((r + c + 1) as f64);
+            }
+        }
+        let expected: Vector<f64, 6> = Vector::from_fill(1_f64);
+        let b = matrix * expected;
+        let result = CholeskyDecomposition::try_new(&matrix).unwrap().solve(&b);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_indefinite_matrix() {
+        let matrix = Matrix3x3::new(
+            2_f64, 0_f64,  0_f64,
+            0_f64, 1_f64,  0_f64,
+            0_f64, 0_f64, -1_f64,
+        );
+
+        assert!(matrix.try_cholesky().is_none());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_positive_semidefinite_matrix() {
+        let matrix = Matrix2x2::new(
+            1_f64, 2_f64,
+            2_f64, 4_f64,
+        );
+
+        assert!(matrix.try_cholesky().is_none());
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        assert!(Matrix3x3::<f64>::zero().try_cholesky().is_none());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_asymmetric_matrix() {
+        let matrix = Matrix2x2::new(
+            2_f64, 1_f64,
+            0_f64, 2_f64,
+        );
+
+        assert!(matrix.try_cholesky().is_none());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_nan_matrix() {
+        let matrix = Matrix2x2::new(
+            f64::NAN, 0_f64,
+            0_f64,    1_f64,
+        );
+
+        assert!(matrix.try_cholesky().is_none());
+    }
+}
+
+#[cfg(test)]
+mod ldlt_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        LdltDecomposition,
+        Matrix2x2,
+        Matrix3x3,
+        Matrix4x4,
+        Vector4,
+    };
+
+
+    #[rustfmt::skip]
+    fn matrix4x4() -> Matrix4x4<f64> {
+        Matrix4x4::new(
+             6_f64,    1_f64, -2_f64,  0.5_f64,
+             1_f64,    5_f64,  1_f64, -1_f64,
+            -2_f64,    1_f64,  7_f64,  2_f64,
+             0.5_f64, -1_f64,  2_f64,  4_f64,
+        )
+    }
+
+    #[test]
+    fn test_factors_reproduce_matrix() {
+        let matrix = matrix4x4();
+        let ldlt = matrix.try_ldlt().unwrap();
+        let l = ldlt.l();
+        let d = Matrix4x4::from_diagonal(ldlt.d());
+
+        assert_relative_eq!(l * d * l.transpose(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_factor_is_unit_lower_triangular() {
+        let ldlt = matrix4x4().try_ldlt().unwrap();
+        let l = ldlt.l();
+        for c in 0..4 {
+            assert_eq!(l[c][c], 1_f64);
+            for r in 0..c {
+                assert_eq!(l[c][r], 0_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_cholesky() {
+        let matrix = matrix4x4();
+        let ldlt = matrix.try_ldlt().unwrap();
+        let cholesky = matrix.try_cholesky().unwrap();
+        for c in 0..4 {
+            let expected = cholesky.l()[c];
+            let result = ldlt.l()[c] * ldlt.d()[c].sqrt();
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        }
+        assert_relative_eq!(
+            ldlt.log_determinant(),
+            cholesky.log_determinant(),
+            abs_diff <= 1e-12,
+            relative <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = matrix4x4();
+        let expected = Vector4::new(1_f64, -2_f64, 0.5_f64, 3_f64);
+        let b = matrix * expected;
+        let result = LdltDecomposition::try_new(&matrix).unwrap().solve(&b);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix = matrix4x4();
+        let result = matrix.try_ldlt().unwrap().inverse();
+        let expected = matrix.try_inverse().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_determinant() {
+        let matrix = matrix4x4();
+        let ldlt = matrix.try_ldlt().unwrap();
+
+        assert_relative_eq!(
+            ldlt.determinant(),
+            matrix.determinant(),
+            abs_diff <= 1e-10,
+            relative <= f64::EPSILON
+        );
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_indefinite_matrix() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 0_f64,
+            2_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+        );
+
+        assert!(matrix.try_ldlt().is_none());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_asymmetric_matrix() {
+        let matrix = Matrix2x2::new(
+            2_f64, 1_f64,
+            0_f64, 2_f64,
+        );
+
+        assert!(matrix.try_ldlt().is_none());
+    }
+}
@@ -0,0 +1,441 @@
+#[cfg(test)]
+mod dual_quaternion_construction_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        DualQuaternion,
+        Matrix4x4,
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[test]
+    fn test_identity_is_identity() {
+        let identity: DualQuaternion<f64> = DualQuaternion::identity();
+
+        assert!(identity.is_identity());
+        assert_eq!(identity.rotation(), Quaternion::identity());
+        assert_eq!(identity.translation(), Vector3::zero());
+        assert_eq!(identity.to_affine_matrix(), Matrix4x4::identity());
+    }
+
+    #[test]
+    fn test_from_rotation_translation_round_trip() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 3_f64));
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(137_f64));
+        let translation = Vector3::new(-4_f64, 5_f64, 6_f64);
+        let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+        let (result_rotation, result_translation) = dual_quaternion.to_rotation_translation();
+
+        assert_eq!(result_rotation, rotation);
+        assert_relative_eq!(result_translation, translation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_rotation_translation_is_unit() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(33_f64));
+        let translation = Vector3::new(7_f64, -1_f64, 2_f64);
+        let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+
+        assert_relative_eq!(
+            dual_quaternion.real.norm(),
+            1_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            dual_quaternion.real.dot(&dual_quaternion.dual),
+            0_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_from_translation_then_rotation() {
+        let axis = Unit::from_value(Vector3::unit_y());
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(60_f64));
+        let translation = Vector3::new(1_f64, 2_f64, 3_f64);
+        let expected = DualQuaternion::from_rotation_translation(&rotation, &translation);
+        let result = DualQuaternion::from_translation(&translation) * DualQuaternion::from_rotation(&rotation);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_affine_matrix_round_trip() {
+        let matrix = Matrix4x4::from_affine_translation(&Vector3::new(-3_f64, 1_f64, 8_f64))
+            * Matrix4x4::from_affine_angle_z(Degrees(25_f64))
+            * Matrix4x4::from_affine_angle_x(Degrees(-110_f64));
+        let dual_quaternion = DualQuaternion::from_affine_matrix(&matrix);
+        let result = Matrix4x4::from(dual_quaternion);
+
+        assert_relative_eq!(result, matrix, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod dual_quaternion_arithmetic_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        DualQuaternion,
+        Matrix4x4,
+        Point3,
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn dual_quaternion1() -> DualQuaternion<f64> {
+        let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 1_f64));
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(75_f64));
+        let translation = Vector3::new(1_f64, 2_f64, -3_f64);
+
+        DualQuaternion::from_rotation_translation(&rotation, &translation)
+    }
+
+    fn dual_quaternion2() -> DualQuaternion<f64> {
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, 4_f64));
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(-160_f64));
+        let translation = Vector3::new(-5_f64, 0_f64, 2_f64);
+
+        DualQuaternion::from_rotation_translation(&rotation, &translation)
+    }
+
+    #[test]
+    fn test_apply_point_matches_affine_matrix() {
+        let dual_quaternion = dual_quaternion1();
+        let matrix = dual_quaternion.to_affine_matrix();
+        let point = Point3::new(3_f64, -7_f64, 4_f64);
+        let expected = Point3::from_homogeneous(&(matrix * point.to_homogeneous())).unwrap();
+        let result = dual_quaternion.apply_point(&point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_apply_vector_ignores_translation() {
+        let dual_quaternion = dual_quaternion1();
+        let vector = Vector3::new(3_f64, -7_f64, 4_f64);
+        let expected = dual_quaternion.rotation().to_matrix() * vector;
+        let result = dual_quaternion.apply_vector(&vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_product_composes_rigid_transformations() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+        let expected = dual_quaternion1.to_affine_matrix() * dual_quaternion2.to_affine_matrix();
+        let result = (dual_quaternion1 * dual_quaternion2).to_affine_matrix();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_product_applies_right_operand_first() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+        let point = Point3::new(1_f64, 1_f64, -2_f64);
+        let expected = dual_quaternion1.apply_point(&dual_quaternion2.apply_point(&point));
+        let result = (dual_quaternion1 * dual_quaternion2).apply_point(&point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_conjugate_of_unit_dual_quaternion_is_inverse() {
+        let dual_quaternion = dual_quaternion2();
+        let identity = DualQuaternion::identity();
+
+        assert_relative_eq!(
+            dual_quaternion * dual_quaternion.conjugate(),
+            identity,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            dual_quaternion.conjugate() * dual_quaternion,
+            identity,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_conjugate_reverses_products() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+        let expected = dual_quaternion2.conjugate() * dual_quaternion1.conjugate();
+        let result = (dual_quaternion1 * dual_quaternion2).conjugate();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_combined_conjugate_sandwich_transforms_point() {
+        let dual_quaternion = dual_quaternion1();
+        let point = Point3::new(-2_f64, 4_f64, 1_f64);
+        let point_dual_quaternion = DualQuaternion::new(Quaternion::identity(), Quaternion::from_pure(point.to_vector()));
+        let expected = dual_quaternion.apply_point(&point);
+        let sandwich = dual_quaternion * point_dual_quaternion * dual_quaternion.combined_conjugate();
+        let result = Point3::from_vector(&sandwich.dual.vector());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dual_conjugate_twice_is_identity() {
+        let dual_quaternion = DualQuaternion::new(
+            Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+            Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+        );
+
+        assert_eq!(dual_quaternion.dual_conjugate().dual_conjugate(), dual_quaternion);
+        assert_eq!(dual_quaternion.combined_conjugate().combined_conjugate(), dual_quaternion);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let dual_quaternion = DualQuaternion::new(
+            Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+            Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+        );
+        let dual_quaternion_inverse = dual_quaternion.try_inverse().unwrap();
+        let identity = DualQuaternion::identity();
+
+        assert_relative_eq!(
+            dual_quaternion * dual_quaternion_inverse,
+            identity,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            dual_quaternion_inverse * dual_quaternion,
+            identity,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_inverse_of_unit_dual_quaternion_is_conjugate() {
+        let dual_quaternion = dual_quaternion1();
+
+        assert_relative_eq!(
+            dual_quaternion.inverse(),
+            dual_quaternion.conjugate(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_non_invertible_dual_quaternion() {
+        let dual_quaternion = DualQuaternion::new(Quaternion::zero(), Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64));
+
+        assert!(dual_quaternion.try_inverse().is_none());
+    }
+
+    #[test]
+    fn test_normalize_unit_dual_quaternion() {
+        let dual_quaternion = dual_quaternion1();
+        let result = (dual_quaternion * 3_f64).normalize();
+
+        assert_relative_eq!(result, dual_quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_normalize_blend_is_rigid_transformation() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+        let blend = (dual_quaternion1 * 0.3_f64 + dual_quaternion2 * 0.7_f64).normalize();
+        let matrix = blend.to_affine_matrix();
+        let rotation_block = Matrix4x4::new(
+            matrix[0][0], matrix[0][1], matrix[0][2], 0_f64,
+            matrix[1][0], matrix[1][1], matrix[1][2], 0_f64,
+            matrix[2][0], matrix[2][1], matrix[2][2], 0_f64,
+            0_f64, 0_f64, 0_f64, 1_f64,
+        );
+
+        assert_relative_eq!(blend.real.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(blend.real.dot(&blend.dual), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            rotation_block.transpose() * rotation_block,
+            Matrix4x4::identity(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_try_normalize_zero_real_part() {
+        let dual_quaternion = DualQuaternion::new(Quaternion::zero(), Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64));
+
+        assert!(dual_quaternion.try_normalize(1e-10).is_none());
+    }
+}
+
+#[cfg(test)]
+mod dual_quaternion_interpolation_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        DualQuaternion,
+        Point3,
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn dual_quaternion1() -> DualQuaternion<f64> {
+        let axis = Unit::from_value(Vector3::new(1_f64, 0_f64, 1_f64));
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(20_f64));
+        let translation = Vector3::new(1_f64, -1_f64, 0_f64);
+
+        DualQuaternion::from_rotation_translation(&rotation, &translation)
+    }
+
+    fn dual_quaternion2() -> DualQuaternion<f64> {
+        let axis = Unit::from_value(Vector3::new(-1_f64, 3_f64, 2_f64));
+        let rotation = Quaternion::from_axis_angle(&axis, Degrees(130_f64));
+        let translation = Vector3::new(4_f64, 2_f64, -6_f64);
+
+        DualQuaternion::from_rotation_translation(&rotation, &translation)
+    }
+
+    #[test]
+    fn test_powf_zero_is_identity() {
+        let dual_quaternion = dual_quaternion2();
+
+        assert_relative_eq!(
+            dual_quaternion.powf(0_f64),
+            DualQuaternion::identity(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_powf_one_is_self() {
+        let dual_quaternion = dual_quaternion2();
+
+        assert_relative_eq!(
+            dual_quaternion.powf(1_f64),
+            dual_quaternion,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_powf_two_is_square() {
+        let dual_quaternion = dual_quaternion1();
+
+        assert_relative_eq!(
+            dual_quaternion.powf(2_f64),
+            dual_quaternion * dual_quaternion,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_powf_half_squared_is_self() {
+        let dual_quaternion = dual_quaternion2();
+        let half = dual_quaternion.powf(0.5_f64);
+
+        assert_relative_eq!(half * half, dual_quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_powf_pure_translation() {
+        let translation = Vector3::new(2_f64, -4_f64, 6_f64);
+        let dual_quaternion = DualQuaternion::from_translation(&translation);
+        let expected = DualQuaternion::from_translation(&(translation * 0.25_f64));
+        let result = dual_quaternion.powf(0.25_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_powf_takes_shortest_path() {
+        let dual_quaternion = dual_quaternion2();
+        let expected = dual_quaternion.powf(0.5_f64);
+        let result = (-dual_quaternion).powf(0.5_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sclerp_endpoints() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+
+        assert_relative_eq!(
+            dual_quaternion1.sclerp(&dual_quaternion2, 0_f64),
+            dual_quaternion1,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            dual_quaternion1.sclerp(&dual_quaternion2, 1_f64),
+            dual_quaternion2,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_sclerp_is_unit() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+        for i in 0..=10 {
+            let amount = (i as f64) / 10_f64;
+            let result = dual_quaternion1.sclerp(&dual_quaternion2, amount);
+
+            assert_relative_eq!(result.real.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(
+                result.real.dot(&result.dual),
+                0_f64,
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn test_sclerp_constant_speed() {
+        let dual_quaternion1 = dual_quaternion1();
+        let dual_quaternion2 = dual_quaternion2();
+        let quarter = dual_quaternion1.sclerp(&dual_quaternion2, 0.25_f64);
+        let half = dual_quaternion1.sclerp(&dual_quaternion2, 0.5_f64);
+        let expected = quarter.sclerp(&dual_quaternion1.sclerp(&dual_quaternion2, 0.75_f64), 0.5_f64);
+
+        assert_relative_eq!(half, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sclerp_screw_motion_about_axis() {
+        // A quarter turn about the z-axis together with an advance of 2 along it.
+        let axis = Unit::from_value(Vector3::unit_z());
+        let dual_quaternion1 = DualQuaternion::identity();
+        let dual_quaternion2 = DualQuaternion::from_rotation_translation(
+            &Quaternion::from_axis_angle(&axis, Degrees(90_f64)),
+            &Vector3::new(0_f64, 0_f64, 2_f64),
+        );
+        let point = Point3::new(1_f64, 0_f64, 0_f64);
+        let expected = Point3::new(f64::sqrt(2_f64) / 2_f64, f64::sqrt(2_f64) / 2_f64, 1_f64);
+        let result = dual_quaternion1.sclerp(&dual_quaternion2, 0.5_f64).apply_point(&point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
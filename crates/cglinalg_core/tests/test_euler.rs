@@ -1,8 +1,13 @@
 use approx_cmp::assert_relative_eq;
 use cglinalg_core::{
     Euler,
+    EulerAxes,
+    EulerFrame,
+    EulerOrder,
     Matrix3x3,
     Matrix4x4,
+    Quaternion,
+    Vector3,
 };
 use cglinalg_trigonometry::{
     Angle,
@@ -231,3 +236,416 @@ fn test_euler_angles_to_matrix_rotation_matrix_inverse4() {
 
     assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
 }
+
+
+const ALL_EULER_AXES: [EulerAxes; 12] = [
+    EulerAxes::XYZ,
+    EulerAxes::XZY,
+    EulerAxes::YXZ,
+    EulerAxes::YZX,
+    EulerAxes::ZXY,
+    EulerAxes::ZYX,
+    EulerAxes::XYX,
+    EulerAxes::XZX,
+    EulerAxes::YXY,
+    EulerAxes::YZY,
+    EulerAxes::ZXZ,
+    EulerAxes::ZYZ,
+];
+
+fn all_euler_orders() -> impl Iterator<Item = EulerOrder> {
+    ALL_EULER_AXES
+        .into_iter()
+        .flat_map(|axes| [EulerOrder::intrinsic(axes), EulerOrder::extrinsic(axes)])
+}
+
+fn axis_rotation(axis: char, angle: Radians<f64>) -> Matrix3x3<f64> {
+    match axis {
+        'x' => Matrix3x3::from_angle_x(angle),
+        'y' => Matrix3x3::from_angle_y(angle),
+        _ => Matrix3x3::from_angle_z(angle),
+    }
+}
+
+#[test]
+fn test_to_matrix_with_default_order() {
+    let euler = Euler::new(Radians(0.3_f64), Radians(-1.1_f64), Radians(2.2_f64));
+    let expected = euler.to_matrix();
+    let result = euler.to_matrix_with_order(EulerOrder::default());
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_to_affine_matrix_with_default_order() {
+    let euler = Euler::new(Radians(0.3_f64), Radians(-1.1_f64), Radians(2.2_f64));
+    let expected = euler.to_affine_matrix();
+    let result = euler.to_affine_matrix_with_order(EulerOrder::default());
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_to_matrix_with_order_tait_bryan_angles_by_axis() {
+    let euler = Euler::new(Radians(0.3_f64), Radians(-1.1_f64), Radians(2.2_f64));
+    let orders = [
+        (EulerAxes::XYZ, ['x', 'y', 'z']),
+        (EulerAxes::XZY, ['x', 'z', 'y']),
+        (EulerAxes::YXZ, ['y', 'x', 'z']),
+        (EulerAxes::YZX, ['y', 'z', 'x']),
+        (EulerAxes::ZXY, ['z', 'x', 'y']),
+        (EulerAxes::ZYX, ['z', 'y', 'x']),
+    ];
+    for (axes, [a, b, c]) in orders {
+        let angle = |axis| match axis {
+            'x' => euler.x,
+            'y' => euler.y,
+            _ => euler.z,
+        };
+        let rotation_a = axis_rotation(a, angle(a));
+        let rotation_b = axis_rotation(b, angle(b));
+        let rotation_c = axis_rotation(c, angle(c));
+        let expected_intrinsic = rotation_a * rotation_b * rotation_c;
+        let expected_extrinsic = rotation_c * rotation_b * rotation_a;
+        let result_intrinsic = euler.to_matrix_with_order(EulerOrder::intrinsic(axes));
+        let result_extrinsic = euler.to_matrix_with_order(EulerOrder::extrinsic(axes));
+
+        assert_relative_eq!(
+            result_intrinsic,
+            expected_intrinsic,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            result_extrinsic,
+            expected_extrinsic,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn test_to_matrix_with_order_proper_euler_angles_by_position() {
+    let euler = Euler::new(Radians(0.3_f64), Radians(1.1_f64), Radians(-2.2_f64));
+    let orders = [
+        (EulerAxes::XYX, ['x', 'y']),
+        (EulerAxes::XZX, ['x', 'z']),
+        (EulerAxes::YXY, ['y', 'x']),
+        (EulerAxes::YZY, ['y', 'z']),
+        (EulerAxes::ZXZ, ['z', 'x']),
+        (EulerAxes::ZYZ, ['z', 'y']),
+    ];
+    for (axes, [a, b]) in orders {
+        let rotation_first = axis_rotation(a, euler.x);
+        let rotation_second = axis_rotation(b, euler.y);
+        let rotation_third = axis_rotation(a, euler.z);
+        let expected_intrinsic = rotation_first * rotation_second * rotation_third;
+        let expected_extrinsic = rotation_third * rotation_second * rotation_first;
+        let result_intrinsic = euler.to_matrix_with_order(EulerOrder::intrinsic(axes));
+        let result_extrinsic = euler.to_matrix_with_order(EulerOrder::extrinsic(axes));
+
+        assert_relative_eq!(
+            result_intrinsic,
+            expected_intrinsic,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            result_extrinsic,
+            expected_extrinsic,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn test_to_quaternion_with_order_matches_matrix() {
+    let euler = Euler::new(Radians(0.3_f64), Radians(1.1_f64), Radians(-2.2_f64));
+    for order in all_euler_orders() {
+        let expected = euler.to_matrix_with_order(order);
+        let result = euler.to_quaternion_with_order(order).to_matrix();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+/// Construct a set of Euler angles from the angles of the rotations in the
+/// order of the sequence of axes.
+fn euler_from_sequence_angles(axes: EulerAxes, angles: [f64; 3]) -> Euler<Radians<f64>> {
+    let [first, second, third] = angles.map(Radians);
+    match axes {
+        EulerAxes::XYZ => Euler::new(first, second, third),
+        EulerAxes::XZY => Euler::new(first, third, second),
+        EulerAxes::YXZ => Euler::new(second, first, third),
+        EulerAxes::YZX => Euler::new(third, first, second),
+        EulerAxes::ZXY => Euler::new(second, third, first),
+        EulerAxes::ZYX => Euler::new(third, second, first),
+        _ => Euler::new(first, second, third),
+    }
+}
+
+#[test]
+fn test_from_matrix_with_order_round_trip() {
+    let tait_bryan_angles = [[0.3_f64, 1.1_f64, -2.2_f64], [-3.0_f64, -0.2_f64, 1.7_f64], [2.9_f64, 1.5_f64, -0.4_f64]];
+    let proper_angles = [[0.3_f64, 1.1_f64, -2.2_f64], [-3.0_f64, 0.2_f64, 1.7_f64], [2.9_f64, 3.0_f64, -0.4_f64]];
+    for order in all_euler_orders() {
+        let angles = if order.axes.is_proper() { proper_angles } else { tait_bryan_angles };
+        for sequence_angles in angles {
+            let expected = euler_from_sequence_angles(order.axes, sequence_angles);
+            let matrix = expected.to_matrix_with_order(order);
+            let result = Euler::from_matrix_with_order(&matrix, order);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_from_quaternion_with_order_round_trip_negated_quaternion() {
+    for order in all_euler_orders() {
+        let expected = euler_from_sequence_angles(order.axes, [2.5_f64, 0.9_f64, -2.8_f64]);
+        let quaternion = -expected.to_quaternion_with_order(order);
+        let result = Euler::from_quaternion_with_order(&quaternion, order);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_from_quaternion_with_order_non_unit_quaternion() {
+    let expected = Euler::new(Radians(0.5_f64), Radians(0.9_f64), Radians(-1.8_f64));
+    let order = EulerOrder::intrinsic(EulerAxes::YZY);
+    let quaternion = expected.to_quaternion_with_order(order) * 3_f64;
+    let result = Euler::from_quaternion_with_order(&quaternion, order);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_from_matrix_with_order_gimbal_lock_reconstructs_rotation() {
+    let quarter_turn = Radians::full_turn_div_4();
+    let half_turn = Radians::full_turn_div_2();
+    for order in all_euler_orders() {
+        let middle_angles = if order.axes.is_proper() {
+            [Radians(0_f64), half_turn]
+        } else {
+            [quarter_turn, -quarter_turn]
+        };
+        for middle_angle in middle_angles {
+            let euler = euler_from_sequence_angles(order.axes, [0.7_f64, middle_angle.0, 0.4_f64]);
+            let expected = euler.to_matrix_with_order(order);
+            let extracted = Euler::from_matrix_with_order(&expected, order);
+            let result = extracted.to_matrix_with_order(order);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_from_matrix_with_order_gimbal_lock_intrinsic_zeroes_third_angle() {
+    let euler = Euler::new(Radians(0.7_f64), Radians::full_turn_div_4(), Radians(0.4_f64));
+    let order = EulerOrder::intrinsic(EulerAxes::XYZ);
+    let matrix = euler.to_matrix_with_order(order);
+    let expected = Euler::new(Radians(1.1_f64), Radians::full_turn_div_4(), Radians(0_f64));
+    let result = Euler::from_matrix_with_order(&matrix, order);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_from_matrix_with_order_gimbal_lock_extrinsic_zeroes_first_angle() {
+    let euler = Euler::new(Radians(0.7_f64), Radians(0_f64), Radians(0.4_f64));
+    let order = EulerOrder::extrinsic(EulerAxes::ZXZ);
+    let matrix = euler.to_matrix_with_order(order);
+    let expected = Euler::new(Radians(0_f64), Radians(0_f64), Radians(1.1_f64));
+    let result = Euler::from_matrix_with_order(&matrix, order);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_intrinsic_order_is_reversed_extrinsic_order() {
+    let euler = Euler::new(Radians(0.3_f64), Radians(-1.1_f64), Radians(2.2_f64));
+    let pairs = [(EulerAxes::XYZ, EulerAxes::ZYX), (EulerAxes::XZY, EulerAxes::YZX), (EulerAxes::YXZ, EulerAxes::ZXY)];
+    for (axes, reversed_axes) in pairs {
+        let expected = euler.to_matrix_with_order(EulerOrder::extrinsic(reversed_axes));
+        let result = euler.to_matrix_with_order(EulerOrder::intrinsic(axes));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_to_quaternion_with_order_rotates_vector() {
+    let euler = Euler::new(Radians::full_turn_div_4(), Radians(0_f64), Radians::full_turn_div_4());
+    let order = EulerOrder::new(EulerAxes::ZXZ, EulerFrame::Intrinsic);
+    let quaternion = euler.to_quaternion_with_order(order);
+    let vector = Vector3::unit_x();
+    let expected = -Vector3::unit_x();
+    let result = (quaternion * Quaternion::from_pure(vector) * quaternion.conjugate()).vector();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+/// Compute the angular velocity of a rotation moving along a set of Euler angle
+/// rates with central differences. The angular velocity is the axial vector of
+/// the skew-symmetric matrix `dR/dt * R^T` in the fixed frame, or of
+/// `R^T * dR/dt` in the rotating frame.
+fn angular_velocity_finite_difference(
+    euler: &Euler<Radians<f64>>,
+    rates: &Vector3<f64>,
+    order: EulerOrder,
+) -> (Vector3<f64>, Vector3<f64>) {
+    let step = 1e-6_f64;
+    let rotation_at = |t: f64| {
+        let euler_t = Euler::new(
+            euler.x + Radians(rates.x * t),
+            euler.y + Radians(rates.y * t),
+            euler.z + Radians(rates.z * t),
+        );
+
+        euler_t.to_matrix_with_order(order)
+    };
+    let rotation = rotation_at(0_f64);
+    let derivative = (rotation_at(step) - rotation_at(-step)) / (2_f64 * step);
+    let axial_vector = |skew: Matrix3x3<f64>| Vector3::new(skew[1][2], skew[2][0], skew[0][1]);
+    let angular_velocity = axial_vector(derivative * rotation.transpose());
+    let body_angular_velocity = axial_vector(rotation.transpose() * derivative);
+
+    (angular_velocity, body_angular_velocity)
+}
+
+#[test]
+fn test_euler_rates_to_angular_velocity_all_orders() {
+    let euler = euler_from_sequence_angles(EulerAxes::XYZ, [0.4_f64, 0.7_f64, -1.3_f64]);
+    let rates = Vector3::new(0.3_f64, -0.8_f64, 1.1_f64);
+    for order in all_euler_orders() {
+        let (expected, _) = angular_velocity_finite_difference(&euler, &rates, order);
+        let result = Matrix3x3::euler_rates_to_angular_velocity(&euler, order) * rates;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_euler_rates_to_body_angular_velocity_all_orders() {
+    let euler = euler_from_sequence_angles(EulerAxes::XYZ, [0.4_f64, 0.7_f64, -1.3_f64]);
+    let rates = Vector3::new(0.3_f64, -0.8_f64, 1.1_f64);
+    for order in all_euler_orders() {
+        let (_, expected) = angular_velocity_finite_difference(&euler, &rates, order);
+        let result = Matrix3x3::euler_rates_to_body_angular_velocity(&euler, order) * rates;
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_angular_velocity_to_euler_rates_inverse_all_orders() {
+    let euler = euler_from_sequence_angles(EulerAxes::XYZ, [0.4_f64, 0.7_f64, -1.3_f64]);
+    let expected = Matrix3x3::identity();
+    for order in all_euler_orders() {
+        let to_angular_velocity = Matrix3x3::euler_rates_to_angular_velocity(&euler, order);
+        let to_body_angular_velocity = Matrix3x3::euler_rates_to_body_angular_velocity(&euler, order);
+        let from_angular_velocity = Matrix3x3::angular_velocity_to_euler_rates(&euler, order).unwrap();
+        let from_body_angular_velocity = Matrix3x3::body_angular_velocity_to_euler_rates(&euler, order).unwrap();
+
+        assert_relative_eq!(
+            from_angular_velocity * to_angular_velocity,
+            expected,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            from_body_angular_velocity * to_body_angular_velocity,
+            expected,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+fn gimbal_lock_middle_angles(order: EulerOrder) -> [f64; 2] {
+    if order.axes.is_proper() {
+        [0_f64, core::f64::consts::PI]
+    } else {
+        [core::f64::consts::FRAC_PI_2, -core::f64::consts::FRAC_PI_2]
+    }
+}
+
+#[test]
+fn test_angular_velocity_to_euler_rates_gimbal_lock() {
+    for order in all_euler_orders() {
+        for middle_angle in gimbal_lock_middle_angles(order) {
+            let euler = euler_from_sequence_angles(order.axes, [0.4_f64, middle_angle, -1.3_f64]);
+
+            assert!(Matrix3x3::angular_velocity_to_euler_rates(&euler, order).is_none());
+            assert!(Matrix3x3::body_angular_velocity_to_euler_rates(&euler, order).is_none());
+        }
+    }
+}
+
+#[test]
+fn test_angular_velocity_to_euler_rates_near_gimbal_lock() {
+    for order in all_euler_orders() {
+        for middle_angle in gimbal_lock_middle_angles(order) {
+            for offset in [-1e-8_f64, 1e-8_f64] {
+                let euler = euler_from_sequence_angles(order.axes, [0.4_f64, middle_angle + offset, -1.3_f64]);
+
+                assert!(Matrix3x3::angular_velocity_to_euler_rates(&euler, order).is_none());
+                assert!(Matrix3x3::body_angular_velocity_to_euler_rates(&euler, order).is_none());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_angular_velocity_to_euler_rates_away_from_gimbal_lock() {
+    for order in all_euler_orders() {
+        for middle_angle in gimbal_lock_middle_angles(order) {
+            for offset in [-1e-4_f64, 1e-4_f64] {
+                let euler = euler_from_sequence_angles(order.axes, [0.4_f64, middle_angle + offset, -1.3_f64]);
+
+                assert!(Matrix3x3::angular_velocity_to_euler_rates(&euler, order).is_some());
+                assert!(Matrix3x3::body_angular_velocity_to_euler_rates(&euler, order).is_some());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_matrix3x3_euler_angles() {
+    let roll_yz = Radians(0.3_f64);
+    let yaw_zx = Radians(-1.2_f64);
+    let pitch_xy = Radians(2.4_f64);
+    let expected = Euler::new(roll_yz, yaw_zx, pitch_xy);
+    let matrix = Matrix3x3::from_euler_angles(&expected);
+    let result = matrix.euler_angles();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_matrix4x4_euler_angles_ignores_translation() {
+    let roll_yz = Radians(0.3_f64);
+    let yaw_zx = Radians(-1.2_f64);
+    let pitch_xy = Radians(2.4_f64);
+    let expected = Euler::new(roll_yz, yaw_zx, pitch_xy);
+    let translation = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64));
+    let matrix = translation * Matrix4x4::from_euler_angles(&expected);
+    let result = matrix.euler_angles();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_matrix4x4_euler_angles_with_order() {
+    let order = EulerOrder::intrinsic(EulerAxes::ZXZ);
+    let expected = Euler::new(Radians(-2.3_f64), Radians(0.6_f64), Radians(1.4_f64));
+    let matrix = Matrix4x4::from_euler_angles_with_order(&expected, order);
+    let result = matrix.euler_angles_with_order(order);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
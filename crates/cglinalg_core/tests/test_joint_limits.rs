@@ -0,0 +1,322 @@
+#[cfg(test)]
+mod swing_twist_limits_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        SwingTwistLimits,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[test]
+    fn test_new_cone_swing_axes_are_orthonormal() {
+        let twist_axes = [
+            Vector3::unit_x(),
+            Vector3::unit_y(),
+            Vector3::unit_z(),
+            Vector3::new(1_f64, 1_f64, 1_f64),
+            Vector3::new(-3_f64, 0.1_f64, 2_f64),
+        ];
+        for twist_axis in twist_axes.iter() {
+            let twist_axis = Unit::from_value(*twist_axis);
+            let limits = SwingTwistLimits::new_cone(&twist_axis, Degrees(30_f64), Degrees(-10_f64), Degrees(10_f64)).unwrap();
+            let (swing_axis_1, swing_axis_2) = limits.swing_axes();
+
+            assert_relative_eq!(swing_axis_1.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(swing_axis_2.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(swing_axis_1.dot(swing_axis_2), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(swing_axis_1.dot(&twist_axis), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(
+                swing_axis_1.cross(swing_axis_2),
+                twist_axis.into_inner(),
+                abs_diff_all <= 1e-12,
+                relative_all <= f64::EPSILON,
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_elliptical_cone_projects_swing_axis() {
+        let twist_axis = Unit::from_value(Vector3::unit_z());
+        let swing_axis = Vector3::new(0_f64, 2_f64, 5_f64);
+        let limits = SwingTwistLimits::new_elliptical_cone(
+            &twist_axis,
+            &swing_axis,
+            Degrees(40_f64),
+            Degrees(15_f64),
+            Degrees(-20_f64),
+            Degrees(20_f64),
+        )
+        .unwrap();
+        let (swing_axis_1, swing_axis_2) = limits.swing_axes();
+
+        assert_relative_eq!(
+            swing_axis_1.into_inner(),
+            Vector3::unit_y(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            swing_axis_2.into_inner(),
+            -Vector3::unit_x(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_new_elliptical_cone_parallel_swing_axis() {
+        let twist_axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let swing_axis = Vector3::new(-2_f64, -4_f64, -6_f64);
+        let result = SwingTwistLimits::new_elliptical_cone(
+            &twist_axis,
+            &swing_axis,
+            Degrees(40_f64),
+            Degrees(15_f64),
+            Degrees(-20_f64),
+            Degrees(20_f64),
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_new_cone_negative_swing_limit() {
+        let twist_axis = Unit::from_value(Vector3::unit_z());
+        let result = SwingTwistLimits::new_cone(&twist_axis, Degrees(-30_f64), Degrees(-10_f64), Degrees(10_f64));
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_new_cone_reversed_twist_limits() {
+        let twist_axis = Unit::from_value(Vector3::unit_z());
+        let result = SwingTwistLimits::new_cone(&twist_axis, Degrees(30_f64), Degrees(10_f64), Degrees(-10_f64));
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_new_cone_zero_limits() {
+        let twist_axis = Unit::from_value(Vector3::unit_z());
+        let result = SwingTwistLimits::new_cone(&twist_axis, Degrees(0_f64), Degrees(0_f64), Degrees(0_f64));
+
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_new_elliptical_cone_negative_swing_limit() {
+        let twist_axis = Unit::from_value(Vector3::unit_z());
+        let swing_axis = Vector3::unit_x();
+        let result_1 = SwingTwistLimits::new_elliptical_cone(
+            &twist_axis,
+            &swing_axis,
+            Degrees(-40_f64),
+            Degrees(15_f64),
+            Degrees(-20_f64),
+            Degrees(20_f64),
+        );
+        let result_2 = SwingTwistLimits::new_elliptical_cone(
+            &twist_axis,
+            &swing_axis,
+            Degrees(40_f64),
+            Degrees(-15_f64),
+            Degrees(-20_f64),
+            Degrees(20_f64),
+        );
+
+        assert!(result_1.is_none());
+        assert!(result_2.is_none());
+    }
+
+    #[test]
+    fn test_new_elliptical_cone_reversed_twist_limits() {
+        let twist_axis = Unit::from_value(Vector3::unit_z());
+        let swing_axis = Vector3::unit_x();
+        let result = SwingTwistLimits::new_elliptical_cone(
+            &twist_axis,
+            &swing_axis,
+            Degrees(40_f64),
+            Degrees(15_f64),
+            Degrees(20_f64),
+            Degrees(-20_f64),
+        );
+
+        assert!(result.is_none());
+    }
+}
+
+#[cfg(test)]
+mod clamp_swing_twist_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        SwingTwistLimits,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::{
+        Degrees,
+        Radians,
+    };
+
+
+    fn limits() -> SwingTwistLimits<f64> {
+        SwingTwistLimits::new_elliptical_cone(
+            &Unit::from_value(Vector3::unit_z()),
+            &Vector3::unit_x(),
+            Degrees(60_f64),
+            Degrees(20_f64),
+            Degrees(-30_f64),
+            Degrees(45_f64),
+        )
+        .unwrap()
+    }
+
+    fn swing_angles(quaternion: &Quaternion<f64>, limits: &SwingTwistLimits<f64>) -> (f64, f64) {
+        let (swing, _) = quaternion.swing_twist(limits.twist_axis());
+        let swing_vector = swing.scaled_axis();
+        let (swing_axis_1, swing_axis_2) = limits.swing_axes();
+
+        (swing_vector.dot(swing_axis_1), swing_vector.dot(swing_axis_2))
+    }
+
+    fn twist_angle(quaternion: &Quaternion<f64>, limits: &SwingTwistLimits<f64>) -> f64 {
+        let (_, twist) = quaternion.swing_twist(limits.twist_axis());
+
+        twist.scaled_axis().dot(limits.twist_axis())
+    }
+
+    #[test]
+    fn test_identity_is_within_limits() {
+        let quaternion = Quaternion::<f64>::identity();
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits());
+
+        assert_eq!(result, quaternion);
+        assert!(!clamped);
+    }
+
+    #[test]
+    fn test_rotation_within_limits_is_unchanged() {
+        let limits = limits();
+        let twist = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(40_f64));
+        let swing = Quaternion::from_scaled_axis(&Vector3::new(0.5_f64, 0.2_f64, 0_f64));
+        let quaternion = swing * twist;
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+
+        assert_eq!(result, quaternion);
+        assert!(!clamped);
+    }
+
+    #[test]
+    fn test_twist_above_maximum() {
+        let limits = limits();
+        let quaternion = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(100_f64));
+        let expected = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(45_f64));
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert!(clamped);
+    }
+
+    #[test]
+    fn test_twist_below_minimum() {
+        let limits = limits();
+        let quaternion = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(-170_f64));
+        let expected = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(-30_f64));
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert!(clamped);
+    }
+
+    #[test]
+    fn test_swing_along_major_axis() {
+        let limits = limits();
+        let unit_x = Unit::from_value(Vector3::unit_x());
+        let quaternion = Quaternion::from_axis_angle(&unit_x, Degrees(80_f64));
+        let expected = Quaternion::from_axis_angle(&unit_x, Degrees(60_f64));
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert!(clamped);
+    }
+
+    #[test]
+    fn test_swing_along_minor_axis() {
+        let limits = limits();
+        let unit_y = Unit::from_value(Vector3::unit_y());
+        let quaternion = Quaternion::from_axis_angle(&unit_y, Degrees(-50_f64));
+        let expected = Quaternion::from_axis_angle(&unit_y, Degrees(-20_f64));
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert!(clamped);
+    }
+
+    #[test]
+    fn test_clamped_swing_lies_on_elliptical_cone() {
+        let limits = limits();
+        let (swing_limit_1, swing_limit_2) = limits.swing_limits();
+        let twist = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(10_f64));
+        let swing = Quaternion::from_scaled_axis(&Vector3::new(0.9_f64, -0.8_f64, 0_f64));
+        let quaternion = swing * twist;
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+        let (phi_1, phi_2) = swing_angles(&result, &limits);
+        let ellipse = (phi_1 / swing_limit_1.0).powi(2) + (phi_2 / swing_limit_2.0).powi(2);
+
+        assert!(clamped);
+        assert_relative_eq!(ellipse, 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(phi_1 / phi_2, -0.9_f64 / 0.8_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(
+            twist_angle(&result, &limits),
+            Radians::from(Degrees(10_f64)).0,
+            abs_diff <= 1e-12,
+            relative <= f64::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_swing_and_twist_clamped_together() {
+        let limits = limits();
+        let (swing_limit_1, _) = limits.swing_limits();
+        let (_, twist_max) = limits.twist_limits();
+        let twist = Quaternion::from_axis_angle(limits.twist_axis(), Degrees(120_f64));
+        let swing = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(150_f64));
+        let quaternion = swing * twist;
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+        let (phi_1, phi_2) = swing_angles(&result, &limits);
+
+        assert!(clamped);
+        assert_relative_eq!(phi_1, swing_limit_1.0, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(phi_2, 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(
+            twist_angle(&result, &limits),
+            twist_max.0,
+            abs_diff <= 1e-12,
+            relative <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_clamped_rotation_is_unit_and_in_same_hemisphere() {
+        let limits = limits();
+        let quaternion = -Quaternion::from_scaled_axis(&Vector3::new(2_f64, -1_f64, 1.5_f64));
+        let (result, clamped) = quaternion.clamp_swing_twist(&limits);
+
+        assert!(clamped);
+        assert_relative_eq!(result.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert!(result.dot(&quaternion) >= 0_f64);
+    }
+
+    #[test]
+    fn test_clamping_is_idempotent() {
+        let limits = limits();
+        let quaternion = Quaternion::from_scaled_axis(&Vector3::new(-1.4_f64, 0.7_f64, 2.2_f64));
+        let (once, _) = quaternion.clamp_swing_twist(&limits);
+        let (twice, _) = once.clamp_swing_twist(&limits);
+
+        assert_relative_eq!(twice, once, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod lu_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        LuDecomposition,
+        Matrix,
+        Matrix1x1,
+        Matrix3x3,
+        Matrix4x4,
+        Vector,
+        Vector1,
+        Vector4,
+    };
+
+
+    #[rustfmt::skip]
+    fn matrix4x4() -> Matrix4x4<f64> {
+        Matrix4x4::new(
+            1_f64, 4_f64, 7_f64,  8_f64,
+            2_f64, 5_f64, 8_f64,  4_f64,
+            5_f64, 6_f64, 11_f64, 4_f64,
+            9_f64, 3_f64, 13_f64, 5_f64,
+        )
+    }
+
+    fn hilbert4x4() -> Matrix4x4<f64> {
+        let mut result = Matrix4x4::zero();
+        for c in 0..4 {
+            for r in 0..4 {
+                result[c][r] = 1_f64 / ((r + c + 1) as f64);
+            }
+        }
+
+        result
+    }
+
+    #[rustfmt::skip]
+    fn matrix6x6() -> Matrix<f64, 6, 6> {
+        Matrix::from([
+            [ 2_f64, -1_f64,  0_f64,  3_f64,  1_f64,  0_f64],
+            [-1_f64,  4_f64,  2_f64,  0_f64, -2_f64,  1_f64],
+            [ 0_f64,  2_f64,  5_f64, -1_f64,  0_f64,  3_f64],
+            [ 1_f64,  0_f64, -1_f64,  6_f64,  2_f64, -2_f64],
+            [ 7_f64, -2_f64,  0_f64,  2_f64,  3_f64,  1_f64],
+            [ 0_f64,  1_f64,  3_f64, -2_f64,  1_f64,  8_f64],
+        ])
+    }
+
+    #[test]
+    fn test_factors_reproduce_matrix() {
+        let matrix = matrix4x4();
+        let lu = LuDecomposition::new(&matrix);
+
+        assert_relative_eq!(
+            lu.p() * matrix,
+            lu.l() * lu.u(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_lower_factor_is_bounded_by_partial_pivoting() {
+        let lu = LuDecomposition::new(&matrix4x4());
+        let l = lu.l();
+        for c in 0..4 {
+            for r in 0..4 {
+                assert!(l[c][r].abs() <= 1_f64);
+            }
+        }
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_determinant_matches_closed_form() {
+        let matrix3x3 = Matrix3x3::new(
+             2_f64, -3_f64, -2_f64,
+             1_f64, -1_f64,  1_f64,
+            -1_f64,  2_f64,  2_f64,
+        );
+        let matrix4x4 = matrix4x4();
+
+        assert_relative_eq!(matrix3x3.lu().determinant(), matrix3x3.determinant(), abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(matrix4x4.lu().determinant(), matrix4x4.determinant(), abs_diff <= 1e-10, relative <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_determinant_sign_of_permutation() {
+        let matrix = Matrix3x3::new(
+            0_f64, 1_f64, 0_f64,
+            1_f64, 0_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+        );
+
+        assert_eq!(matrix.lu().determinant(), -1_f64);
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = matrix4x4();
+        let expected = Vector4::new(1_f64, -2_f64, 3_f64, -4_f64);
+        let b = matrix * expected;
+        let result = matrix.lu().solve(&b).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_solve_ill_conditioned() {
+        let matrix = hilbert4x4();
+        let expected = Vector4::new(1_f64, 1_f64, 1_f64, 1_f64);
+        let b = matrix * expected;
+        let result = matrix.lu().solve(&b).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(matrix * result, b, abs_diff_all <= 1e-14, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_solve_one_by_one() {
+        let matrix = Matrix1x1::new(4_f64);
+        let b = Vector1::new(2_f64);
+        let expected = Vector1::new(0.5_f64);
+        let result = matrix.lu().solve(&b).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_solve_six_by_six() {
+        let matrix = matrix6x6();
+        let expected = Vector::from([1_f64, 2_f64, -1_f64, 0.5_f64, -3_f64, 2_f64]);
+        let b = matrix * expected;
+        let result = matrix.lu().solve(&b).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix = matrix4x4();
+        let expected = matrix.try_inverse().unwrap();
+        let result = matrix.lu().try_inverse().unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            matrix * result,
+            Matrix4x4::identity(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_inverse_six_by_six() {
+        let matrix = matrix6x6();
+        let result = matrix.lu().inverse();
+        for c in 0..6 {
+            let mut expected = Vector::zero();
+            expected[c] = 1_f64;
+
+            assert_relative_eq!(matrix * result[c], expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_singular_matrix() {
+        let matrix = Matrix4x4::new(
+            1_f64, 2_f64, 3_f64, 4_f64,
+            5_f64, 6_f64, 7_f64, 8_f64,
+            2_f64, 4_f64, 6_f64, 8_f64,
+            0_f64, 1_f64, 0_f64, 1_f64,
+        );
+        let lu = matrix.lu();
+        let b = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert_eq!(lu.determinant(), 0_f64);
+        assert!(!lu.is_invertible());
+        assert!(lu.solve(&b).is_none());
+        assert!(lu.try_inverse().is_none());
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let lu = Matrix3x3::<f64>::zero().lu();
+
+        assert_eq!(lu.determinant(), 0_f64);
+        assert!(!lu.is_invertible());
+        assert_eq!(lu.l(), Matrix3x3::identity());
+        assert_eq!(lu.u(), Matrix3x3::zero());
+        assert_eq!(lu.permutation(), &[0, 1, 2]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    #[should_panic]
+    fn test_inverse_singular_panics() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            2_f64, 4_f64, 6_f64,
+            0_f64, 1_f64, 1_f64,
+        );
+        let _ = matrix.lu().inverse();
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod qr_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Matrix2x2,
+        Matrix3x3,
+        Matrix4x2,
+        Matrix4x3,
+        Matrix4x4,
+        QrDecomposition,
+        Vector2,
+        Vector3,
+        Vector4,
+    };
+
+
+    #[rustfmt::skip]
+    fn matrix4x3() -> Matrix4x3<f64> {
+        Matrix4x3::new(
+            1_f64, -2_f64, 0_f64,  3_f64,
+            4_f64,  1_f64, 1_f64, -1_f64,
+            0_f64,  5_f64, 2_f64,  2_f64,
+        )
+    }
+
+    #[rustfmt::skip]
+    fn matrix6x3() -> Matrix<f64, 6, 3> {
+        Matrix::from([
+            [1_f64, 2_f64, -1_f64, 0_f64, 3_f64,  1_f64],
+            [0_f64, 1_f64,  4_f64, 2_f64, -1_f64, 1_f64],
+            [5_f64, 0_f64,  1_f64, 1_f64, 2_f64, -3_f64],
+        ])
+    }
+
+    #[test]
+    fn test_factors_reproduce_matrix() {
+        let matrix = matrix4x3();
+        let qr = matrix.try_qr().unwrap();
+
+        assert_relative_eq!(qr.q() * qr.r(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_q_is_orthonormal() {
+        let q = matrix4x3().try_qr().unwrap().q();
+
+        assert_relative_eq!(
+            q.tr_mul(&q),
+            Matrix3x3::identity(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_r_is_upper_triangular() {
+        let r = matrix4x3().try_qr().unwrap().r();
+        for c in 0..3 {
+            for r_index in (c + 1)..3 {
+                assert_eq!(r[c][r_index], 0_f64);
+            }
+        }
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_square_matrix() {
+        let matrix = Matrix4x4::new(
+            1_f64, 4_f64, 7_f64,  8_f64,
+            2_f64, 5_f64, 8_f64,  4_f64,
+            5_f64, 6_f64, 11_f64, 4_f64,
+            9_f64, 3_f64, 13_f64, 5_f64,
+        );
+        let qr = matrix.try_qr().unwrap();
+        let expected = Vector4::new(1_f64, -1_f64, 2_f64, 0.5_f64);
+        let b = matrix * expected;
+        let result = qr.solve(&b).unwrap();
+
+        assert_relative_eq!(qr.q() * qr.r(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_solve_consistent_system() {
+        let matrix = matrix4x3();
+        let expected = Vector3::new(2_f64, -1_f64, 0.5_f64);
+        let b = matrix * expected;
+        let result = matrix.try_qr().unwrap().solve(&b).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_solve_residual_is_orthogonal_to_columns() {
+        let matrix = matrix4x3();
+        let b = Vector4::new(1_f64, 0_f64, -2_f64, 5_f64);
+        let result = matrix.try_qr().unwrap().solve(&b).unwrap();
+        let residual = matrix * result - b;
+
+        assert_relative_eq!(
+            matrix.transpose() * residual,
+            Vector3::zero(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_solve_matches_normal_equations() {
+        let matrix = matrix4x3();
+        let b = Vector4::new(3_f64, -1_f64, 2_f64, 1_f64);
+        let normal_matrix = matrix.tr_mul(&matrix);
+        let expected = normal_matrix.try_inverse().unwrap() * (matrix.transpose() * b);
+        let result = matrix.try_qr().unwrap().solve(&b).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_solve_ill_conditioned_line_fit() {
+        // Fitting a line far from the origin makes the normal equations nearly
+        // singular, while the least squares problem itself stays well posed.
+        let xs = Vector4::new(1000_f64, 1001_f64, 1002_f64, 1003_f64);
+        let ones = Vector4::new(1_f64, 1_f64, 1_f64, 1_f64);
+        let matrix = Matrix4x2::from_columns(&[xs, ones]);
+        let expected = Vector2::new(0.5_f64, -200_f64);
+        let b = matrix * expected;
+        let result = matrix.try_qr().unwrap().solve(&b).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-9, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_six_by_three() {
+        let matrix = matrix6x3();
+        let qr = QrDecomposition::try_new(&matrix).unwrap();
+        let q = qr.q();
+        let r = qr.r();
+        let expected = Vector3::new(-1_f64, 2_f64, 3_f64);
+        let b = matrix * expected;
+        let result = qr.solve(&b).unwrap();
+
+        for c in 0..3 {
+            assert_relative_eq!(q * r[c], matrix[c], abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+            for j in 0..3 {
+                let expected_dot = if c == j { 1_f64 } else { 0_f64 };
+
+                assert_relative_eq!(q[c].dot(&q[j]), expected_dot, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            }
+        }
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        let matrix = Matrix4x2::from_columns(&[Vector4::new(1_f64, 2_f64, 3_f64, 4_f64), Vector4::zero()]);
+        let qr = matrix.try_qr().unwrap();
+
+        assert!(!qr.is_full_rank());
+        assert!(qr.solve(&Vector4::new(1_f64, 1_f64, 1_f64, 1_f64)).is_none());
+        assert_relative_eq!(qr.q() * qr.r(), matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let qr = Matrix2x2::<f64>::zero().try_qr().unwrap();
+
+        assert!(!qr.is_full_rank());
+        assert_eq!(qr.q(), Matrix2x2::identity());
+        assert_eq!(qr.r(), Matrix2x2::zero());
+    }
+
+    #[test]
+    fn test_more_columns_than_rows() {
+        let matrix: Matrix<f64, 2, 3> = Matrix::zero();
+
+        assert!(QrDecomposition::try_new(&matrix).is_none());
+        assert!(matrix.try_qr().is_none());
+    }
+}
+
+#[cfg(test)]
+mod orthonormalize_columns_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Matrix2x3,
+        Matrix3x3,
+        Matrix4x3,
+        Vector,
+        Vector4,
+    };
+
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_orthonormalize_columns_is_orthonormal() {
+        let matrix = Matrix4x3::new(
+            1_f64, -2_f64, 0_f64,  3_f64,
+            4_f64,  1_f64, 1_f64, -1_f64,
+            0_f64,  5_f64, 2_f64,  2_f64,
+        );
+        let result = matrix.try_orthonormalize_columns().unwrap();
+
+        assert_relative_eq!(result.tr_mul(&result), Matrix3x3::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_orthonormalize_columns_matches_qr_up_to_sign() {
+        let matrix = Matrix4x3::new(
+            2_f64, 1_f64,  0_f64, 1_f64,
+            1_f64, 3_f64, -1_f64, 0_f64,
+            0_f64, 1_f64,  4_f64, 2_f64,
+        );
+        let result = matrix.try_orthonormalize_columns().unwrap();
+        let qr = matrix.try_qr().unwrap();
+        let q = qr.q();
+        let r = qr.r();
+        for c in 0..3 {
+            let expected = q[c] * r[c][c].signum();
+
+            assert_relative_eq!(result[c], expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize_columns_preserves_span() {
+        let first = Vector4::new(1_f64, 1_f64, 0_f64, 0_f64);
+        let second = Vector4::new(2_f64, 0_f64, 1_f64, 0_f64);
+        let matrix = Matrix::from_columns(&[first, second]);
+        let result = matrix.try_orthonormalize_columns().unwrap();
+
+        assert_relative_eq!(
+            result[0],
+            first / f64::sqrt(2_f64),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(result[1].dot(&first), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_orthonormalize_nearly_parallel_columns() {
+        let epsilon = 1e-8_f64;
+        let matrix: Matrix<f64, 3, 3> = Matrix::from_columns(&[
+            Vector::from([1_f64, epsilon, 0_f64]),
+            Vector::from([1_f64, 0_f64, epsilon]),
+            Vector::from([1_f64, 0_f64, 0_f64]),
+        ]);
+        let result = matrix.try_orthonormalize_columns().unwrap();
+
+        assert_relative_eq!(
+            result.tr_mul(&result),
+            Matrix3x3::identity(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_orthonormalize_dependent_columns() {
+        let first = Vector4::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let second = Vector4::new(0_f64, 1_f64, 0_f64, 1_f64);
+        let matrix = Matrix4x3::from_columns(&[first, second, first * 2_f64 - second * 3_f64]);
+
+        assert!(matrix.try_orthonormalize_columns().is_none());
+    }
+
+    #[test]
+    fn test_orthonormalize_zero_column() {
+        let matrix = Matrix4x3::from_columns(&[Vector4::unit_x(), Vector4::<f64>::zero(), Vector4::unit_y()]);
+
+        assert!(matrix.try_orthonormalize_columns().is_none());
+    }
+
+    #[test]
+    fn test_orthonormalize_more_columns_than_rows() {
+        let matrix = Matrix2x3::new(1_f64, 0_f64, 0_f64, 1_f64, 1_f64, 1_f64);
+
+        assert!(matrix.try_orthonormalize_columns().is_none());
+    }
+}
@@ -376,6 +376,857 @@ mod slerp_tests {
     }
 }
 
+#[cfg(test)]
+mod quaternion_spline_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn keys() -> [Unit<Quaternion<f64>>; 5] {
+        [
+            Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(0_f64))),
+            Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(80_f64))),
+            Unit::from_value(Quaternion::from_axis_angle(
+                &Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64)),
+                Degrees(120_f64),
+            )),
+            Unit::from_value(Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Degrees(-60_f64))),
+            Unit::from_value(Quaternion::from_axis_angle(
+                &Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64)),
+                Degrees(150_f64),
+            )),
+        ]
+    }
+
+    /// Estimate the body frame angular velocity of a rotation curve from two
+    /// nearby samples a parameter distance `step` apart.
+    fn angular_velocity(q_start: &Unit<Quaternion<f64>>, q_end: &Unit<Quaternion<f64>>, step: f64) -> Vector3<f64> {
+        let mut difference = q_start.conjugate() * q_end.into_inner();
+        if difference.scalar() < 0_f64 {
+            difference = -difference;
+        }
+
+        // For small angles, `vector(difference) ~= angle / 2 * axis`.
+        difference.vector() * (2_f64 / step)
+    }
+
+    #[test]
+    fn test_squad_endpoints() {
+        let keys = keys();
+        let q0 = keys[0].into_inner();
+        let q1 = keys[1].into_inner();
+        let q2 = keys[2].into_inner();
+        let q3 = keys[3].into_inner();
+        let control1 = Quaternion::squad_control_point(&q0, &q1, &q2);
+        let control2 = Quaternion::squad_control_point(&q1, &q2, &q3);
+
+        assert_relative_eq!(
+            q1.squad(&control1, &control2, &q2, 0_f64),
+            q1,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            q1.squad(&control1, &control2, &q2, 1_f64),
+            q2,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_squad_with_keys_as_control_points_is_slerp() {
+        let keys = keys();
+        let q1 = keys[1].into_inner();
+        let q2 = keys[2].into_inner();
+        for i in 0..=10 {
+            let amount = (i as f64) / 10_f64;
+            let expected = q1.slerp(&q2, amount);
+            let result = q1.squad(&q1, &q2, &q2, amount);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_squad_control_point_aligns_hemispheres() {
+        let keys = keys();
+        let q0 = keys[0].into_inner();
+        let q1 = keys[1].into_inner();
+        let q2 = keys[2].into_inner();
+        let expected = Quaternion::squad_control_point(&q0, &q1, &q2);
+        let result = Quaternion::squad_control_point(&(-q0), &q1, &(-q2));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_spherical_bezier_endpoints() {
+        let keys = keys();
+        let q0 = keys[0].into_inner();
+        let q1 = keys[1].into_inner();
+        let q2 = keys[2].into_inner();
+        let q3 = keys[3].into_inner();
+
+        assert_relative_eq!(
+            q0.spherical_bezier(&q1, &q2, &q3, 0_f64),
+            q0,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            q0.spherical_bezier(&q1, &q2, &q3, 1_f64),
+            q3,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_spherical_bezier_end_tangents() {
+        let keys = keys();
+        let q0 = keys[0];
+        let q1 = keys[1];
+        let q2 = keys[2];
+        let q3 = keys[3];
+        let step = 1e-6_f64;
+        let curve = |amount: f64| Unit::from_value(q0.spherical_bezier(&q1, &q2, &q3, amount));
+        // The curve leaves each end at three times the rate of a slerp to the
+        // adjacent control point.
+        let expected_start = (q0.conjugate() * q1.into_inner()).ln().vector() * 6_f64;
+        let result_start = angular_velocity(&curve(0_f64), &curve(step), step);
+        let expected_end = (q2.conjugate() * q3.into_inner()).ln().vector() * 6_f64;
+        let result_end = angular_velocity(&curve(1_f64 - step), &curve(1_f64), step);
+
+        assert_relative_eq!(result_start, expected_start, abs_diff_all <= 1e-4, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_end, expected_end, abs_diff_all <= 1e-4, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_squad_spline_interpolates_keys() {
+        let keys = keys();
+        for (i, key) in keys.iter().enumerate() {
+            let result = Unit::squad_spline(&keys, i as f64).unwrap();
+
+            assert_relative_eq!(result, *key, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_spherical_bezier_spline_interpolates_keys() {
+        let keys = keys();
+        for (i, key) in keys.iter().enumerate() {
+            let result = Unit::spherical_bezier_spline(&keys, i as f64).unwrap();
+
+            assert_relative_eq!(result, *key, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_squad_spline_continuous_angular_velocity() {
+        let keys = keys();
+        let step = 1e-6_f64;
+        let curve = |parameter: f64| Unit::squad_spline(&keys, parameter).unwrap();
+        for i in 1..(keys.len() - 1) {
+            let parameter = i as f64;
+            let incoming = angular_velocity(&curve(parameter - step), &curve(parameter), step);
+            let outgoing = angular_velocity(&curve(parameter), &curve(parameter + step), step);
+
+            assert_relative_eq!(incoming, outgoing, abs_diff_all <= 1e-4, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_spherical_bezier_spline_continuous_angular_velocity() {
+        let keys = keys();
+        let step = 1e-6_f64;
+        let curve = |parameter: f64| Unit::spherical_bezier_spline(&keys, parameter).unwrap();
+        for i in 1..(keys.len() - 1) {
+            let parameter = i as f64;
+            let incoming = angular_velocity(&curve(parameter - step), &curve(parameter), step);
+            let outgoing = angular_velocity(&curve(parameter), &curve(parameter + step), step);
+
+            assert_relative_eq!(incoming, outgoing, abs_diff_all <= 1e-4, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_squad_and_spherical_bezier_splines_share_key_tangents() {
+        let keys = keys();
+        let step = 1e-6_f64;
+        for i in 1..(keys.len() - 1) {
+            let parameter = i as f64;
+            let squad_start = Unit::squad_spline(&keys, parameter).unwrap();
+            let squad_end = Unit::squad_spline(&keys, parameter + step).unwrap();
+            let bezier_start = Unit::spherical_bezier_spline(&keys, parameter).unwrap();
+            let bezier_end = Unit::spherical_bezier_spline(&keys, parameter + step).unwrap();
+            let expected = angular_velocity(&squad_start, &squad_end, step);
+            let result = angular_velocity(&bezier_start, &bezier_end, step);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-4, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_spline_ignores_key_signs() {
+        let keys = keys();
+        let flipped_keys = [keys[0], -keys[1], keys[2], -keys[3], -keys[4]];
+        for i in 0..=40 {
+            let parameter = (i as f64) / 10_f64;
+            let squad = Unit::squad_spline(&keys, parameter).unwrap();
+            let flipped_squad = Unit::squad_spline(&flipped_keys, parameter).unwrap();
+            let bezier = Unit::spherical_bezier_spline(&keys, parameter).unwrap();
+            let flipped_bezier = Unit::spherical_bezier_spline(&flipped_keys, parameter).unwrap();
+
+            assert_relative_eq!(
+                flipped_squad.to_matrix(),
+                squad.to_matrix(),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(
+                flipped_bezier.to_matrix(),
+                bezier.to_matrix(),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn test_spline_clamps_parameter() {
+        let keys = keys();
+
+        assert_relative_eq!(
+            Unit::squad_spline(&keys, -1_f64).unwrap(),
+            keys[0],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            Unit::squad_spline(&keys, 10_f64).unwrap(),
+            keys[4],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            Unit::spherical_bezier_spline(&keys, -1_f64).unwrap(),
+            keys[0],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            Unit::spherical_bezier_spline(&keys, 10_f64).unwrap(),
+            keys[4],
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_spline_single_key() {
+        let keys = [keys()[2]];
+
+        assert_eq!(Unit::squad_spline(&keys, 0.5_f64), Some(keys[0]));
+        assert_eq!(Unit::spherical_bezier_spline(&keys, 0.5_f64), Some(keys[0]));
+    }
+
+    #[test]
+    fn test_spline_no_keys() {
+        let keys: [Unit<Quaternion<f64>>; 0] = [];
+
+        assert!(Unit::squad_spline(&keys, 0_f64).is_none());
+        assert!(Unit::spherical_bezier_spline(&keys, 0_f64).is_none());
+    }
+
+    #[test]
+    fn test_spline_non_finite_parameter() {
+        let keys = keys();
+        for parameter in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(Unit::squad_spline(&keys, parameter).is_none());
+            assert!(Unit::spherical_bezier_spline(&keys, parameter).is_none());
+            assert!(Unit::squad_spline(&keys[..1], parameter).is_none());
+            assert!(Unit::spherical_bezier_spline(&keys[..1], parameter).is_none());
+        }
+    }
+}
+
+#[cfg(test)]
+mod swing_twist_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn quaternions() -> [Quaternion<f64>; 5] {
+        [
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), Degrees(40_f64)),
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(-2_f64, 0_f64, 1_f64)), Degrees(170_f64)),
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64)), Degrees(-95_f64)),
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(5_f64, -3_f64, 1_f64)), Degrees(260_f64)),
+            Quaternion::identity(),
+        ]
+    }
+
+    fn axes() -> [Unit<Vector3<f64>>; 4] {
+        [
+            Unit::from_value(Vector3::unit_x()),
+            Unit::from_value(Vector3::unit_y()),
+            Unit::from_value(Vector3::unit_z()),
+            Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64)),
+        ]
+    }
+
+    #[test]
+    fn test_swing_twist_recomposes() {
+        for quaternion in quaternions() {
+            for axis in axes() {
+                let (swing, twist) = quaternion.swing_twist(&axis);
+                let result = Quaternion::from_swing_twist(&swing, &twist);
+
+                assert_relative_eq!(result, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swing_twist_unit_parts() {
+        for quaternion in quaternions() {
+            for axis in axes() {
+                let (swing, twist) = quaternion.swing_twist(&axis);
+
+                assert_relative_eq!(swing.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                assert_relative_eq!(twist.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_twist_rotates_about_axis() {
+        for quaternion in quaternions() {
+            for axis in axes() {
+                let (_, twist) = quaternion.swing_twist(&axis);
+
+                assert_relative_eq!(
+                    twist.vector().cross(&axis),
+                    Vector3::zero(),
+                    abs_diff_all <= 1e-10,
+                    relative_all <= f64::EPSILON
+                );
+                assert!(twist.scalar() >= 0_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swing_rotates_about_perpendicular_axis() {
+        for quaternion in quaternions() {
+            for axis in axes() {
+                let (swing, _) = quaternion.swing_twist(&axis);
+
+                assert_relative_eq!(
+                    swing.vector().dot(&axis),
+                    0_f64,
+                    abs_diff_all <= 1e-10,
+                    relative_all <= f64::EPSILON
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_swing_moves_axis_like_rotation() {
+        for quaternion in quaternions() {
+            for axis in axes() {
+                let (swing, _) = quaternion.swing_twist(&axis);
+                let expected = quaternion.to_matrix() * axis.into_inner();
+                let result = swing.to_matrix() * axis.into_inner();
+
+                assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swing_twist_pure_twist() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let quaternion = Quaternion::from_axis_angle(&axis, Degrees(130_f64));
+        let (swing, twist) = quaternion.swing_twist(&axis);
+
+        assert_relative_eq!(swing, Quaternion::identity(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(twist, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_swing_twist_pure_swing() {
+        let axis = Unit::from_value(Vector3::unit_z());
+        let quaternion = Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64)), Degrees(70_f64));
+        let (swing, twist) = quaternion.swing_twist(&axis);
+
+        assert_relative_eq!(swing, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(twist, Quaternion::identity(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_swing_twist_perpendicular_half_turn() {
+        let axis = Unit::from_value(Vector3::unit_z());
+        let quaternion = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(180_f64));
+        let (swing, twist) = quaternion.swing_twist(&axis);
+
+        assert_eq!(swing, quaternion);
+        assert_eq!(twist, Quaternion::identity());
+    }
+
+    #[test]
+    fn test_swing_twist_ignores_quaternion_sign() {
+        for quaternion in quaternions() {
+            for axis in axes() {
+                let (swing, twist) = quaternion.swing_twist(&axis);
+                let (negated_swing, negated_twist) = (-quaternion).swing_twist(&axis);
+
+                assert_relative_eq!(negated_swing, -swing, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                assert_relative_eq!(negated_twist, twist, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod weighted_mean_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix4x4,
+        Quaternion,
+        Unit,
+        Vector3,
+        Vector4,
+    };
+    use cglinalg_trigonometry::{
+        Degrees,
+        Radians,
+    };
+
+
+    fn quaternions() -> [Unit<Quaternion<f64>>; 5] {
+        [
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), Degrees(40_f64)),
+            -Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(-2_f64, 0_f64, 1_f64)), Degrees(70_f64)),
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64)), Degrees(-95_f64)),
+            Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(5_f64, -3_f64, 1_f64)), Degrees(110_f64)),
+            -Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64)), Degrees(20_f64)),
+        ]
+        .map(Unit::from_value)
+    }
+
+    fn weights() -> [f64; 5] {
+        [1_f64, 2_f64, 0.5_f64, 3_f64, 1.5_f64]
+    }
+
+    fn negate(quaternion: &Unit<Quaternion<f64>>) -> Unit<Quaternion<f64>> {
+        Unit::from_value(-quaternion.into_inner())
+    }
+
+    fn to_vector(quaternion: &Quaternion<f64>) -> Vector4<f64> {
+        Vector4::new(quaternion[0], quaternion[1], quaternion[2], quaternion[3])
+    }
+
+    fn attitude_matrix(quaternions: &[Unit<Quaternion<f64>>], weights: &[f64]) -> Matrix4x4<f64> {
+        let mut matrix = Matrix4x4::zero();
+        for (quaternion, weight) in quaternions.iter().zip(weights.iter()) {
+            for c in 0..4 {
+                for r in 0..4 {
+                    matrix[c][r] += weight * quaternion[c] * quaternion[r];
+                }
+            }
+        }
+
+        matrix
+    }
+
+    #[test]
+    fn test_weighted_mean_single_quaternion() {
+        let quaternion = quaternions()[3];
+        let result = Unit::weighted_mean(&[quaternion], &[2_f64]).unwrap();
+
+        assert_relative_eq!(result, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_is_unit() {
+        let result = Unit::weighted_mean(&quaternions(), &weights()).unwrap();
+
+        assert_relative_eq!(result.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_is_dominant_eigenvector() {
+        let quaternions = quaternions();
+        let weights = weights();
+        let matrix = attitude_matrix(&quaternions, &weights);
+        let mean = to_vector(&Unit::weighted_mean(&quaternions, &weights).unwrap());
+        let eigenvalue = mean.dot(&(matrix * mean));
+
+        assert_relative_eq!(
+            matrix * mean,
+            mean * eigenvalue,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        for quaternion in quaternions {
+            let vector = to_vector(&quaternion);
+
+            assert!(vector.dot(&(matrix * vector)) <= eigenvalue);
+        }
+    }
+
+    #[test]
+    fn test_weighted_mean_same_axis() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let quaternions = [
+            Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(0_f64))),
+            Unit::from_value(Quaternion::from_axis_angle(&axis, Degrees(60_f64))),
+        ];
+        let weights = [3_f64, 1_f64];
+        // The chordal mean of rotations about one axis averages the doubled half
+        // angles on the unit circle.
+        let angle = Radians(f64::atan2(
+            f64::sin(f64::to_radians(60_f64)),
+            3_f64 + f64::cos(f64::to_radians(60_f64)),
+        ));
+        let expected = Unit::from_value(Quaternion::from_axis_angle(&axis, angle));
+        let result = Unit::weighted_mean(&quaternions, &weights).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_ignores_signs() {
+        let quaternions = quaternions();
+        let weights = weights();
+        let flipped_quaternions = quaternions.map(|quaternion| negate(&quaternion));
+        let expected = negate(&Unit::weighted_mean(&quaternions, &weights).unwrap());
+        let result = Unit::weighted_mean(&flipped_quaternions, &weights).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_zero_weight() {
+        let quaternions = quaternions();
+        let weights = [0_f64, 0_f64, 0_f64, 1_f64, 0_f64];
+        let expected = quaternions[3];
+        let result = Unit::weighted_mean(&quaternions, &weights).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_scale_invariant_weights() {
+        let quaternions = quaternions();
+        let weights = weights();
+        let scaled_weights = weights.map(|weight| weight * 7_f64);
+        let expected = Unit::weighted_mean(&quaternions, &weights).unwrap();
+        let result = Unit::weighted_mean(&quaternions, &scaled_weights).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_invalid_input() {
+        let quaternions = quaternions();
+
+        assert!(Unit::<Quaternion<f64>>::weighted_mean(&[], &[]).is_none());
+        assert!(Unit::weighted_mean(&quaternions, &[1_f64, 2_f64]).is_none());
+        assert!(Unit::weighted_mean(&quaternions, &[0_f64; 5]).is_none());
+        assert!(Unit::<Quaternion<f64>>::weighted_mean_nlerp(&[], &[]).is_none());
+        assert!(Unit::weighted_mean_nlerp(&quaternions, &[1_f64, 2_f64]).is_none());
+        assert!(Unit::weighted_mean_nlerp(&quaternions, &[0_f64; 5]).is_none());
+    }
+
+    #[test]
+    fn test_weighted_mean_negative_weight() {
+        let quaternions = quaternions();
+        // The weights sum to a positive value, but one of them is negative.
+        let weights = [1_f64, 2_f64, -0.5_f64, 3_f64, 1.5_f64];
+
+        assert!(Unit::weighted_mean(&quaternions, &weights).is_none());
+        assert!(Unit::weighted_mean_nlerp(&quaternions, &weights).is_none());
+    }
+
+    #[test]
+    fn test_weighted_mean_non_finite_weight() {
+        let quaternions = quaternions();
+
+        for weight in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let weights = [1_f64, 2_f64, weight, 3_f64, 1.5_f64];
+
+            assert!(Unit::weighted_mean(&quaternions, &weights).is_none());
+            assert!(Unit::weighted_mean_nlerp(&quaternions, &weights).is_none());
+        }
+    }
+
+    #[test]
+    fn test_weighted_mean_nlerp_single_quaternion() {
+        let quaternion = quaternions()[1];
+        let result = Unit::weighted_mean_nlerp(&[quaternion], &[1_f64]).unwrap();
+
+        assert_relative_eq!(result, quaternion, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_nlerp_two_quaternions_is_nlerp() {
+        let quaternions = quaternions();
+        let q1 = quaternions[0];
+        let q2 = quaternions[4];
+        let amount = 0.3_f64;
+        let expected = Unit::from_value(q1.nlerp(&(-q2.into_inner()), amount));
+        let result = Unit::weighted_mean_nlerp(&[q1, q2], &[1_f64 - amount, amount]).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_nlerp_approximates_weighted_mean() {
+        let axis = Unit::from_value(Vector3::new(0_f64, 3_f64, 4_f64));
+        let base = Quaternion::from_axis_angle(&axis, Degrees(75_f64));
+        let quaternions = [
+            base,
+            -(base * Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_x()), Degrees(3_f64))),
+            base * Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_y()), Degrees(-2_f64)),
+            -(base * Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Degrees(4_f64))),
+        ]
+        .map(Unit::from_value);
+        let weights = [1_f64, 2_f64, 1_f64, 0.5_f64];
+        let expected = Unit::weighted_mean(&quaternions, &weights).unwrap();
+        let result = Unit::weighted_mean_nlerp(&quaternions, &weights).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-4, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_weighted_mean_nlerp_ignores_signs() {
+        let quaternions = quaternions();
+        let weights = weights();
+        let flipped_quaternions = [quaternions[0], negate(&quaternions[1]), quaternions[2], negate(&quaternions[3]), quaternions[4]];
+        let expected = Unit::weighted_mean_nlerp(&quaternions, &weights).unwrap();
+        let result = Unit::weighted_mean_nlerp(&flipped_quaternions, &weights).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod scaled_axis_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Normed,
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Radians;
+    use core::f64;
+
+
+    #[test]
+    fn test_from_scaled_axis_zero_is_identity() {
+        let expected = Quaternion::identity();
+        let result = Quaternion::from_scaled_axis(&Vector3::<f64>::zero());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_scaled_axis_identity_is_zero() {
+        let expected = Vector3::zero();
+        let result = Quaternion::<f64>::identity().scaled_axis();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_scaled_axis_is_unit() {
+        let scaled_axis = Vector3::new(4_f64, -7_f64, 2_f64);
+        let result = Quaternion::from_scaled_axis(&scaled_axis).norm();
+
+        assert_relative_eq!(result, 1_f64, abs_diff <= 1e-15, relative <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_round_trip() {
+        let expected = Vector3::new(-0.9_f64, 1.3_f64, 0.4_f64);
+        let result = Quaternion::from_scaled_axis(&expected).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-15, relative_all <= 4_f64 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_round_trip_small_angle() {
+        let expected = Vector3::new(3e-12_f64, -5e-12_f64, 1e-12_f64);
+        let result = Quaternion::from_scaled_axis(&expected).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 0_f64, relative_all <= 4_f64 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_scaled_axis_tiny_angle_keeps_vector_part() {
+        let scaled_axis = Vector3::new(2e-20_f64, 0_f64, -4e-20_f64);
+        let expected = Quaternion::from_parts(1_f64, Vector3::new(1e-20_f64, 0_f64, -2e-20_f64));
+        let result = Quaternion::from_scaled_axis(&scaled_axis);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_scaled_axis_round_trip_near_half_turn() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 2_f64));
+        let expected = axis.into_inner() * (f64::consts::PI - 1e-9_f64);
+        let result = Quaternion::from_scaled_axis(&expected).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-15, relative_all <= 4_f64 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_half_turn() {
+        let axis = Unit::from_value(Vector3::new(0_f64, 3_f64, 4_f64));
+        let quaternion = Quaternion::from_axis_angle(&axis, Radians(f64::consts::PI));
+        let result = quaternion.scaled_axis();
+
+        assert_relative_eq!(result.norm(), f64::consts::PI, abs_diff <= 1e-15, relative <= f64::EPSILON);
+        assert_relative_eq!(
+            result.normalize().dot(&axis).abs(),
+            1_f64,
+            abs_diff <= 1e-15,
+            relative <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_scaled_axis_negated_quaternion() {
+        let quaternion = Quaternion::from_scaled_axis(&Vector3::new(0.2_f64, 0.3_f64, -0.6_f64));
+        let expected = quaternion.scaled_axis();
+        let result = (-quaternion).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-15, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_wraps_angle_past_half_turn() {
+        let quaternion = Quaternion::from_axis_angle(&Unit::from_value(Vector3::unit_z()), Radians(3_f64 * f64::consts::FRAC_PI_2));
+        let expected = Vector3::new(0_f64, 0_f64, -f64::consts::FRAC_PI_2);
+        let result = quaternion.scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-15, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_round_trip_f32() {
+        let expected = Vector3::new(1e-4_f32, 2e-4_f32, -1e-4_f32);
+        let result = Quaternion::from_scaled_axis(&expected).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 0_f32, relative_all <= 4_f32 * f32::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod angular_velocity_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn orientation() -> Quaternion<f64> {
+        Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, -2_f64, 3_f64)), Degrees(63_f64))
+    }
+
+    #[test]
+    fn test_integrate_zero_angular_velocity() {
+        let expected = orientation();
+        let result = expected.integrate_angular_velocity(&Vector3::zero(), 0.1_f64);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_integrate_angular_velocity_steps_compose() {
+        let angular_velocity = Vector3::new(0.7_f64, -1.1_f64, 2.3_f64);
+        let expected = orientation().integrate_angular_velocity(&angular_velocity, 1_f64);
+        let mut result = orientation();
+        for _ in 0..100 {
+            result = result.integrate_angular_velocity(&angular_velocity, 0.01_f64);
+        }
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_integrate_body_angular_velocity_steps_compose() {
+        let angular_velocity = Vector3::new(-1.5_f64, 0.4_f64, 0.9_f64);
+        let expected = orientation().integrate_body_angular_velocity(&angular_velocity, 1_f64);
+        let mut result = orientation();
+        for _ in 0..100 {
+            result = result.integrate_body_angular_velocity(&angular_velocity, 0.01_f64);
+        }
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_body_and_world_angular_velocity_agree() {
+        // The body frame angular velocity is the world frame angular velocity
+        // expressed in the coordinates of the body.
+        let orientation = orientation();
+        let world_angular_velocity = Vector3::new(0.2_f64, 1.6_f64, -0.5_f64);
+        let body_angular_velocity = orientation.conjugate().to_matrix() * world_angular_velocity;
+        let expected = orientation.integrate_angular_velocity(&world_angular_velocity, 0.25_f64);
+        let result = orientation.integrate_body_angular_velocity(&body_angular_velocity, 0.25_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_round_trip() {
+        let angular_velocity = Vector3::new(3_f64, -4_f64, 1_f64);
+        let dt = 0.02_f64;
+        let next = orientation().integrate_angular_velocity(&angular_velocity, dt);
+        let result = orientation().angular_velocity(&next, dt);
+
+        assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_body_angular_velocity_round_trip() {
+        let angular_velocity = Vector3::new(-0.5_f64, 2_f64, 6_f64);
+        let dt = 0.2_f64;
+        let next = orientation().integrate_body_angular_velocity(&angular_velocity, dt);
+        let result = orientation().body_angular_velocity(&next, dt);
+
+        assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_ignores_quaternion_sign() {
+        let next = orientation().integrate_angular_velocity(&Vector3::new(1_f64, 1_f64, 0_f64), 0.5_f64);
+        let expected = orientation().angular_velocity(&next, 0.5_f64);
+        let result = orientation().angular_velocity(&(-next), 0.5_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+}
+
 #[cfg(test)]
 mod arg_tests {
     use approx_cmp::assert_relative_eq;
@@ -0,0 +1,327 @@
+#[cfg(test)]
+mod singular_value_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Matrix2x2,
+        Matrix3x3,
+        SingularValueDecomposition,
+        Vector2,
+        Vector3,
+    };
+
+
+    fn assert_singular_value_decomposition<const N: usize>(matrix: &Matrix<f64, N, N>, tolerance: f64) {
+        let svd = SingularValueDecomposition::new(matrix);
+        let u = svd.u();
+        let v = svd.v();
+        let singular_values = svd.singular_values();
+        for i in 0..N {
+            assert_relative_eq!(
+                *matrix * v[i],
+                u[i] * singular_values[i],
+                abs_diff_all <= tolerance,
+                relative_all <= f64::EPSILON,
+            );
+            for j in 0..N {
+                let expected = if i == j { 1_f64 } else { 0_f64 };
+
+                assert_relative_eq!(u[i].dot(&u[j]), expected, abs_diff <= 1e-12, relative <= f64::EPSILON);
+                assert_relative_eq!(v[i].dot(&v[j]), expected, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            }
+        }
+        for i in 0..N {
+            assert!(singular_values[i] >= 0_f64);
+        }
+        for i in 1..N {
+            assert!(singular_values[i - 1] >= singular_values[i]);
+        }
+        assert_relative_eq!(svd.recompose(), *matrix, abs_diff_all <= tolerance, relative_all <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_singular_values2x2() {
+        let matrix = Matrix2x2::new(
+            3_f64, 0_f64,
+            4_f64, 5_f64,
+        );
+        let svd = matrix.svd();
+        let sqrt_5 = f64::sqrt(5_f64);
+        let expected = Vector2::new(3_f64 * sqrt_5, sqrt_5);
+
+        assert_relative_eq!(svd.singular_values(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_general3x3() {
+        let matrix = Matrix3x3::new(
+            1_f64,  2_f64, 3_f64,
+            4_f64, -5_f64, 6_f64,
+            7_f64,  8_f64, 10_f64,
+        );
+        let svd = matrix.svd();
+        let product = (0..3).fold(1_f64, |acc, i| acc * svd.singular_values()[i]);
+
+        assert_relative_eq!(product, matrix.determinant().abs(), abs_diff <= 1e-10, relative <= f64::EPSILON);
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_singular_values_match_eigenvalues_of_normal_matrix() {
+        let matrix = Matrix3x3::new(
+             2_f64, -1_f64, 0.5_f64,
+             0_f64,  3_f64, 1_f64,
+            -1_f64,  1_f64, 4_f64,
+        );
+        let svd = matrix.svd();
+        let eigen = matrix.tr_mul(&matrix).try_symmetric_eigen().unwrap();
+        for i in 0..3 {
+            let singular_value = svd.singular_values()[i];
+            let eigenvalue = eigen.eigenvalues()[2 - i];
+
+            assert_relative_eq!(singular_value * singular_value, eigenvalue, abs_diff <= 1e-10, relative <= f64::EPSILON);
+        }
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_reflection() {
+        let matrix = Matrix3x3::new(
+            1_f64, 0_f64,  0_f64,
+            0_f64, 2_f64,  0_f64,
+            0_f64, 0_f64, -3_f64,
+        );
+        let svd = matrix.svd();
+        let expected = Vector3::new(3_f64, 2_f64, 1_f64);
+
+        assert_eq!(svd.singular_values(), &expected);
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rank_deficient() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 3_f64,
+            4_f64, 5_f64, 6_f64,
+            7_f64, 8_f64, 9_f64,
+        );
+        let svd = matrix.svd();
+
+        assert_relative_eq!(svd.singular_values()[2], 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rank_one() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 2_f64,
+            2_f64, 4_f64, 4_f64,
+            0_f64, 0_f64, 0_f64,
+        );
+        let svd = matrix.svd();
+        let expected = Vector3::new(3_f64 * f64::sqrt(5_f64), 0_f64, 0_f64);
+
+        assert_relative_eq!(svd.singular_values(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let matrix = Matrix3x3::<f64>::zero();
+        let svd = matrix.svd();
+
+        assert_eq!(svd.singular_values(), &Vector3::zero());
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[test]
+    fn test_identity_matrix() {
+        let matrix = Matrix3x3::<f64>::identity();
+        let svd = matrix.svd();
+
+        assert_eq!(svd.singular_values(), &Vector3::new(1_f64, 1_f64, 1_f64));
+        assert_eq!(svd.u(), &Matrix3x3::identity());
+        assert_eq!(svd.v(), &Matrix3x3::identity());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_widely_separated_singular_values() {
+        let matrix = Matrix3x3::new(
+            1e6_f64, 0_f64,   0_f64,
+            1_f64,   1_f64,   0_f64,
+            0_f64,   1e-3_f64, 1e-6_f64,
+        );
+
+        assert_singular_value_decomposition(&matrix, 1e-9);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_four_by_four() {
+        let matrix: Matrix<f64, 4, 4> = Matrix::from([
+            [ 4_f64, 1_f64, -2_f64,  2_f64],
+            [ 0_f64, 2_f64,  0_f64,  1_f64],
+            [-2_f64, 3_f64,  3_f64, -2_f64],
+            [ 1_f64, 1_f64, -2_f64, -1_f64],
+        ]);
+
+        assert_singular_value_decomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_singular_values_f32() {
+        let matrix = Matrix2x2::new(
+            2_f32, 0_f32,
+            0_f32, -1_f32,
+        );
+        let svd = matrix.svd();
+        let expected = Vector2::new(2_f32, 1_f32);
+
+        assert_relative_eq!(svd.singular_values(), &expected, abs_diff_all <= 1e-6, relative_all <= f32::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod polar_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Matrix2x2,
+        Matrix3x3,
+        PolarDecomposition,
+        Vector3,
+    };
+
+
+    fn assert_polar_decomposition<const N: usize>(matrix: &Matrix<f64, N, N>, tolerance: f64) {
+        let polar = PolarDecomposition::new(matrix);
+        let rotation = polar.rotation();
+        let stretch = polar.stretch();
+        for c in 0..N {
+            assert_relative_eq!(
+                *rotation * stretch[c],
+                matrix[c],
+                abs_diff_all <= tolerance,
+                relative_all <= f64::EPSILON
+            );
+            for j in 0..N {
+                let expected = if c == j { 1_f64 } else { 0_f64 };
+
+                assert_relative_eq!(rotation[c].dot(&rotation[j]), expected, abs_diff <= 1e-12, relative <= f64::EPSILON);
+                assert_relative_eq!(stretch[c][j], stretch[j][c], abs_diff <= tolerance, relative <= f64::EPSILON);
+            }
+        }
+        assert_relative_eq!(rotation.lu().determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    fn rotation3x3(angle: f64) -> Matrix3x3<f64> {
+        let (sin, cos) = f64::sin_cos(angle);
+        let sqrt_3 = f64::sqrt(3_f64);
+        let x = 1_f64 / sqrt_3;
+        let one_minus_cos = 1_f64 - cos;
+
+        // A rotation about the axis `(1, 1, 1) / sqrt(3)`.
+        Matrix3x3::new(
+            cos + x * x * one_minus_cos,     x * x * one_minus_cos + x * sin, x * x * one_minus_cos - x * sin,
+            x * x * one_minus_cos - x * sin, cos + x * x * one_minus_cos,     x * x * one_minus_cos + x * sin,
+            x * x * one_minus_cos + x * sin, x * x * one_minus_cos - x * sin, cos + x * x * one_minus_cos,
+        )
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_recovers_rotation_and_stretch() {
+        let rotation = rotation3x3(0.7_f64);
+        let stretch = Matrix3x3::new(
+            2_f64,   0.3_f64, 0_f64,
+            0.3_f64, 1_f64,   0.2_f64,
+            0_f64,   0.2_f64, 0.5_f64,
+        );
+        let matrix = rotation * stretch;
+        let polar = matrix.polar();
+
+        assert_relative_eq!(polar.rotation(), &rotation, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(polar.stretch(), &stretch, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_polar_decomposition(&matrix, 1e-12);
+    }
+
+    #[test]
+    fn test_rotation_matrix() {
+        let rotation = rotation3x3(2_f64);
+        let polar = rotation.polar();
+
+        assert_relative_eq!(polar.rotation(), &rotation, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            polar.stretch(),
+            &Matrix3x3::identity(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_stretch_is_positive_semidefinite_for_positive_determinant() {
+        let matrix = rotation3x3(-1.1_f64) * Matrix3x3::from_diagonal(&Vector3::new(0.5_f64, 3_f64, 1_f64));
+        let polar = matrix.polar();
+        let eigen = polar.stretch().try_symmetric_eigen().unwrap();
+
+        assert_polar_decomposition(&matrix, 1e-12);
+        for i in 0..3 {
+            assert!(eigen.eigenvalues()[i] > 0_f64);
+        }
+    }
+
+    #[test]
+    fn test_inverted_element_stretch_has_one_negative_principal_stretch() {
+        let diagonal = Matrix3x3::from_diagonal(&Vector3::new(2_f64, -0.5_f64, 1_f64));
+        let rotation = rotation3x3(0.4_f64);
+        let matrix = rotation * diagonal;
+        let polar = matrix.polar();
+
+        assert_relative_eq!(polar.rotation(), &rotation, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(polar.stretch(), &diagonal, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_polar_decomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_singular_matrix() {
+        let matrix = Matrix3x3::new(
+            1_f64, 0_f64, 0_f64,
+            0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 0_f64,
+        );
+        let polar = matrix.polar();
+
+        assert_polar_decomposition(&matrix, 1e-12);
+        assert_relative_eq!(polar.stretch(), &matrix, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let matrix = Matrix2x2::<f64>::zero();
+
+        assert_polar_decomposition(&matrix, 1e-12);
+        assert_eq!(matrix.polar().stretch(), &Matrix2x2::zero());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_shear() {
+        let matrix = Matrix2x2::new(
+            1_f64, 0_f64,
+            1_f64, 1_f64,
+        );
+
+        assert_polar_decomposition(&matrix, 1e-12);
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod symmetric_eigen_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Matrix2x2,
+        Matrix3x3,
+        SymmetricEigen,
+        Vector2,
+        Vector3,
+    };
+
+
+    fn assert_eigendecomposition<const N: usize>(matrix: &Matrix<f64, N, N>, tolerance: f64) {
+        let eigen = SymmetricEigen::try_new(matrix).unwrap();
+        let eigenvalues = eigen.eigenvalues();
+        let eigenvectors = eigen.eigenvectors();
+        for i in 0..N {
+            assert_relative_eq!(
+                *matrix * eigenvectors[i],
+                eigenvectors[i] * eigenvalues[i],
+                abs_diff_all <= tolerance,
+                relative_all <= f64::EPSILON,
+            );
+            for j in 0..N {
+                let expected = if i == j { 1_f64 } else { 0_f64 };
+
+                assert_relative_eq!(
+                    eigenvectors[i].dot(&eigenvectors[j]),
+                    expected,
+                    abs_diff <= 1e-12,
+                    relative <= f64::EPSILON
+                );
+            }
+        }
+        for i in 1..N {
+            assert!(eigenvalues[i - 1] <= eigenvalues[i]);
+        }
+        assert_relative_eq!(eigenvectors.lu().determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(eigen.recompose(), *matrix, abs_diff_all <= tolerance, relative_all <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eigenvalues2x2() {
+        let matrix = Matrix2x2::new(
+            3_f64, 1_f64,
+            1_f64, 3_f64,
+        );
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let expected = Vector2::new(2_f64, 4_f64);
+
+        assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_eigendecomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eigenvalues3x3() {
+        let matrix = Matrix3x3::new(
+             2_f64, -1_f64,  0_f64,
+            -1_f64,  2_f64, -1_f64,
+             0_f64, -1_f64,  2_f64,
+        );
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let sqrt_2 = f64::sqrt(2_f64);
+        let expected = Vector3::new(2_f64 - sqrt_2, 2_f64, 2_f64 + sqrt_2);
+
+        assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_eigendecomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_general_symmetric3x3() {
+        let matrix = Matrix3x3::new(
+             4_f64, -2_f64, 1_f64,
+            -2_f64,  7_f64, 3_f64,
+             1_f64,  3_f64, -5_f64,
+        );
+
+        assert_eigendecomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_diagonal_matrix() {
+        let matrix = Matrix3x3::new(
+            3_f64, 0_f64,  0_f64,
+            0_f64, 1_f64,  0_f64,
+            0_f64, 0_f64, -2_f64,
+        );
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let expected = Vector3::new(-2_f64, 1_f64, 3_f64);
+
+        assert_eq!(eigen.eigenvalues(), &expected);
+        assert_eigendecomposition(&matrix, 1e-12);
+    }
+
+    #[test]
+    fn test_identity_matrix() {
+        let matrix = Matrix3x3::<f64>::identity();
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+
+        assert_eq!(eigen.eigenvalues(), &Vector3::new(1_f64, 1_f64, 1_f64));
+        assert_eq!(eigen.eigenvectors(), &Matrix3x3::identity());
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let matrix = Matrix3x3::<f64>::zero();
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+
+        assert_eq!(eigen.eigenvalues(), &Vector3::zero());
+        assert_eq!(eigen.eigenvectors(), &Matrix3x3::identity());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_repeated_eigenvalues() {
+        let matrix = Matrix3x3::new(
+            2_f64, 1_f64, 1_f64,
+            1_f64, 2_f64, 1_f64,
+            1_f64, 1_f64, 2_f64,
+        );
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let expected = Vector3::new(1_f64, 1_f64, 4_f64);
+
+        assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_eigendecomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_widely_separated_eigenvalues() {
+        let matrix = Matrix3x3::new(
+            1e8_f64,  1e-2_f64, 0_f64,
+            1e-2_f64, 1_f64,    1e-6_f64,
+            0_f64,    1e-6_f64, 1e-8_f64,
+        );
+
+        assert_eigendecomposition(&matrix, 1e-7);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rotated_diagonal_matrix() {
+        // A matrix with known eigenvectors: the columns of an orthogonal matrix.
+        let (sin, cos) = f64::sin_cos(0.3_f64);
+        let rotation = Matrix3x3::new(
+             cos,  sin,  0_f64,
+            -sin,  cos,  0_f64,
+             0_f64, 0_f64, 1_f64,
+        );
+        let diagonal = Matrix3x3::from_diagonal(&Vector3::new(5_f64, -1_f64, 2_f64));
+        let matrix = rotation * diagonal * rotation.transpose();
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let eigenvectors = eigen.eigenvectors();
+
+        let expected = Vector3::new(-1_f64, 2_f64, 5_f64);
+
+        assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(eigenvectors[0].dot(&rotation[1]).abs(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(eigenvectors[1].dot(&rotation[2]).abs(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(eigenvectors[2].dot(&rotation[0]).abs(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_four_by_four() {
+        let matrix: Matrix<f64, 4, 4> = Matrix::from([
+            [ 4_f64, 1_f64, -2_f64, 2_f64],
+            [ 1_f64, 2_f64,  0_f64, 1_f64],
+            [-2_f64, 0_f64,  3_f64, -2_f64],
+            [ 2_f64, 1_f64, -2_f64, -1_f64],
+        ]);
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let trace = (0..4).fold(0_f64, |acc, i| acc + eigen.eigenvalues()[i]);
+
+        assert_relative_eq!(trace, matrix.trace(), abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_eigendecomposition(&matrix, 1e-12);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_asymmetric_matrix() {
+        let matrix = Matrix3x3::new(
+            1_f64, 2_f64, 0_f64,
+            0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+        );
+
+        assert!(matrix.try_symmetric_eigen().is_none());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eigenvalues_f32() {
+        let matrix = Matrix2x2::new(
+            2_f32, 1_f32,
+            1_f32, 2_f32,
+        );
+        let eigen = matrix.try_symmetric_eigen().unwrap();
+        let expected = Vector2::new(1_f32, 3_f32);
+
+        assert_relative_eq!(eigen.eigenvalues(), &expected, abs_diff_all <= 1e-6, relative_all <= f32::EPSILON);
+    }
+}
@@ -0,0 +1,552 @@
+use crate::isometry::Isometry3;
+use crate::projection::{
+    Orthographic3,
+    Perspective3,
+};
+use cglinalg_core::{
+    Handedness,
+    Matrix4x4,
+    Point3,
+    Unit,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A single cascade of a cascaded shadow map for a directional light.
+///
+/// Cascaded shadow maps split the viewing frustum of a camera into slices along
+/// its gaze direction, and render a separate shadow map for each slice, so that
+/// slices close to the camera receive more shadow map texels per unit area than
+/// slices far away from it. A shadow cascade consists of the depth range of its
+/// slice of the camera's viewing frustum, together with the view transformation
+/// and the orthographic projection of the directional light that cover the slice.
+///
+/// # Fitting
+///
+/// The light view transformation looks along the light direction, and the
+/// orthographic projection is a square in light space enclosing the bounding sphere
+/// of the slice, padded by one texel. The size of the square depends only on the
+/// shape of the slice, so the size of a shadow map texel stays exactly the same as
+/// the camera moves or rotates. The corner of the square is snapped to whole texels
+/// on a grid anchored at the world space origin, so a camera that moves without
+/// rotating moves the shadow map by a whole number of texels. Together, these remove
+/// the shimmering of shadow edges. The depth range of the orthographic projection
+/// only covers the slice itself, so shadow
+/// casters lying between the light and the slice must be kept by enabling depth
+/// clamping when rendering the shadow map, or by extending the **near plane** of
+/// the projection.
+///
+/// The light view transformation and the orthographic projection follow the
+/// normalized device coordinates convention of the camera's perspective projection.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Unit,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_transform::{
+/// #     Isometry3,
+/// #     Perspective3,
+/// #     ShadowCascade,
+/// # };
+/// # use cglinalg_trigonometry::Degrees;
+/// #
+/// let perspective = Perspective3::from_vfov(Degrees(60_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64);
+/// let eye = Point3::new(0_f64, 2_f64, 5_f64);
+/// let target = Point3::new(0_f64, 0_f64, 0_f64);
+/// let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+/// let light_direction = Unit::from_value(Vector3::new(1_f64, -2_f64, -1_f64));
+/// let lambda = 0.75_f64;
+/// let resolution = 2048;
+/// let cascades: [ShadowCascade<f64>; 4] = ShadowCascade::from_perspective(&perspective, &view, &light_direction, lambda, resolution).unwrap();
+///
+/// assert_relative_eq!(cascades[0].near(), 0.1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(cascades[3].far(), 100_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+///
+/// // Every cascade covers the camera's view of the world within its slice.
+/// for cascade in cascades.iter() {
+///     let distance = (cascade.near() + cascade.far()) / 2_f64;
+///     let point = view.inverse_apply_point(&Point3::new(0_f64, 0_f64, -distance));
+///     let light_clip = cascade.view_projection() * point.to_homogeneous();
+///
+///     assert!(light_clip.x.abs() <= 1_f64 && light_clip.y.abs() <= 1_f64 && light_clip.z.abs() <= 1_f64);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShadowCascade<S> {
+    near: S,
+    far: S,
+    light_view: Isometry3<S>,
+    projection: Orthographic3<S>,
+}
+
+impl<S> ShadowCascade<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the split distances of `N` shadow cascades covering the depth
+    /// range `[near, far]` of a viewing frustum.
+    ///
+    /// The split distances blend the logarithmic split scheme with the uniform
+    /// split scheme. The far distance of the cascade `i` is given by
+    /// ```text
+    /// split[i] == lambda * near * (far / near)^((i + 1) / N)
+    ///     + (1 - lambda) * (near + (far - near) * (i + 1) / N)
+    /// ```
+    /// where the blending parameter `lambda` lies in `[0, 1]`. The value `lambda == 1`
+    /// gives the logarithmic split scheme, which distributes shadow map resolution
+    /// evenly in screen space, and `lambda == 0` gives the uniform split scheme.
+    /// The cascade `i` covers the depth range `[split[i - 1], split[i]]`, with the
+    /// first cascade starting at `near`, and the last cascade ending at `far`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::ShadowCascade;
+    /// #
+    /// let near = 1_f64;
+    /// let far = 1000_f64;
+    /// let logarithmic: [f64; 3] = ShadowCascade::split_distances(near, far, 1_f64);
+    /// let uniform: [f64; 3] = ShadowCascade::split_distances(near, far, 0_f64);
+    ///
+    /// assert_relative_eq!(logarithmic[0], 10_f64,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(logarithmic[1], 100_f64,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(logarithmic[2], 1000_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(uniform[0],     334_f64,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(uniform[1],     667_f64,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(uniform[2],     1000_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn split_distances<const N: usize>(near: S, far: S, lambda: S) -> [S; N] {
+        let one = S::one();
+        let count: S = cglinalg_numeric::cast(N);
+        let mut splits = [far; N];
+        // The last split is exactly `far` without any rounding.
+        for (i, split) in splits.iter_mut().enumerate().take(N.saturating_sub(1)) {
+            let index: S = cglinalg_numeric::cast(i + 1);
+            let fraction = index / count;
+            let logarithmic = near * (far / near).powf(fraction);
+            let uniform = near + (far - near) * fraction;
+            *split = lambda * logarithmic + (one - lambda) * uniform;
+        }
+
+        splits
+    }
+
+    /// Construct `N` shadow cascades covering the viewing frustum of a camera for
+    /// a directional light.
+    ///
+    /// # Parameters
+    ///
+    /// * `perspective` is the perspective projection of the camera.
+    /// * `view` is the view transformation of the camera, mapping world space to
+    ///   the camera's view space.
+    /// * `light_direction` is the direction the light travels in world space.
+    /// * `lambda` is the blending parameter of the split scheme described in
+    ///   [`ShadowCascade::split_distances`].
+    /// * `resolution` is the width and height of each shadow map in texels.
+    ///
+    /// The cascades cover the depth range from the **near plane** to the **far plane**
+    /// of the perspective projection. This function returns `None` when the
+    /// resolution is less than two texels, or when the perspective projection has an
+    /// infinitely distant **far plane**. In the latter case, split a finite shadow
+    /// distance with [`ShadowCascade::split_distances`] and fit each slice with
+    /// [`ShadowCascade::fit`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov_infinite(Degrees(60_f64), 16_f64 / 9_f64, 0.1_f64, NdcConvention::OPENGL);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(-Vector3::unit_y());
+    /// let cascades: Option<[ShadowCascade<f64>; 4]> = ShadowCascade::from_perspective(&perspective, &view, &light_direction, 0.5_f64, 1024);
+    ///
+    /// assert!(cascades.is_none());
+    /// ```
+    pub fn from_perspective<const N: usize>(
+        perspective: &Perspective3<S>,
+        view: &Isometry3<S>,
+        light_direction: &Unit<Vector3<S>>,
+        lambda: S,
+        resolution: u32,
+    ) -> Option<[Self; N]> {
+        if resolution < 2 || perspective.is_infinite() {
+            return None;
+        }
+
+        let near = perspective.near();
+        let splits = Self::split_distances::<N>(near, perspective.far(), lambda);
+        let cascades = core::array::from_fn(|i| {
+            let slice_near = if i == 0 { near } else { splits[i - 1] };
+            let slice_far = splits[i];
+
+            Self::fit_unchecked(perspective, view, light_direction, slice_near, slice_far, resolution)
+        });
+
+        Some(cascades)
+    }
+
+    /// Fit a shadow cascade to the slice of the viewing frustum of a camera
+    /// between the view space distances `near` and `far` along the camera's
+    /// gaze direction, for a directional light.
+    ///
+    /// The parameters `perspective`, `view`, `light_direction`, and `resolution` have
+    /// the same meaning as in [`ShadowCascade::from_perspective`]. The slice does not
+    /// need to lie between the **near plane** and the **far plane** of the perspective
+    /// projection. This function returns `None` when the resolution is less than two
+    /// texels, since the cascade reserves one texel of padding for snapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(-Vector3::unit_z());
+    /// let resolution = 1024;
+    /// let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 1_f64, 10_f64, resolution).unwrap();
+    /// let projection = cascade.projection();
+    ///
+    /// // The bounding sphere of the slice is centered on the gaze direction at a
+    /// // distance of `5.5`, and it passes through the corners of the far end of the
+    /// // slice. The cascade covers its diameter, padded by one texel for snapping.
+    /// let radius = f64::sqrt(10_f64 * 10_f64 + 10_f64 * 10_f64 + 4.5_f64 * 4.5_f64);
+    /// let expected = 2_f64 * radius * 1024_f64 / 1023_f64;
+    /// let width = projection.left() + projection.right();
+    /// let height = projection.bottom() + projection.top();
+    ///
+    /// assert_relative_eq!(width, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(height, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(projection.far() - projection.near(), 9_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // A single texel leaves no room for snapping.
+    /// assert!(ShadowCascade::fit(&perspective, &view, &light_direction, 1_f64, 10_f64, 1).is_none());
+    /// ```
+    pub fn fit(
+        perspective: &Perspective3<S>,
+        view: &Isometry3<S>,
+        light_direction: &Unit<Vector3<S>>,
+        near: S,
+        far: S,
+        resolution: u32,
+    ) -> Option<Self> {
+        if resolution < 2 {
+            return None;
+        }
+
+        Some(Self::fit_unchecked(perspective, view, light_direction, near, far, resolution))
+    }
+
+    /// Fit a shadow cascade to a slice of the viewing frustum of a camera for a
+    /// shadow map with a resolution of at least two texels.
+    fn fit_unchecked(
+        perspective: &Perspective3<S>,
+        view: &Isometry3<S>,
+        light_direction: &Unit<Vector3<S>>,
+        near: S,
+        far: S,
+        resolution: u32,
+    ) -> Self {
+        let ndc = perspective.ndc_convention();
+        let gaze_sign = ndc.gaze_sign::<S>();
+        let direction = light_direction.as_ref();
+        // Any up direction not parallel to the light direction gives a valid light
+        // view. Texel snapping only requires the light view to stay fixed as the
+        // camera moves.
+        let threshold: S = cglinalg_numeric::cast(0.99_f64);
+        let up = if direction.y.abs() < threshold {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_x()
+        };
+        let origin = Point3::origin();
+        let light_rotation = match ndc.handedness {
+            Handedness::RightHanded => Isometry3::look_to_rh(&origin, direction, &up),
+            Handedness::LeftHanded => Isometry3::look_to_lh(&origin, direction, &up),
+        };
+
+        let view_corners = Self::slice_corners(perspective, near, far);
+        let mut min_depth = S::infinity();
+        let mut max_depth = S::neg_infinity();
+        for view_corner in view_corners.iter() {
+            let depth = light_rotation.apply_point(&view.inverse_apply_point(view_corner)).z * gaze_sign;
+            min_depth = S::min(min_depth, depth);
+            max_depth = S::max(max_depth, depth);
+        }
+
+        // The bounding sphere of the slice is computed in view space, so that its
+        // radius does not depend on the position and orientation of the camera.
+        let one_half = S::one() / (S::one() + S::one());
+        let count: S = cglinalg_numeric::cast(view_corners.len());
+        let view_center = view_corners
+            .iter()
+            .fold(Point3::origin(), |acc, view_corner| acc + view_corner.to_vector() / count);
+        let radius = view_corners
+            .iter()
+            .fold(S::zero(), |acc, view_corner| S::max(acc, (view_corner - view_center).norm()));
+        // Pad the diameter of the bounding sphere by one texel, so that the square
+        // still contains the sphere after its corner snaps down to the texel grid.
+        let resolution: S = cglinalg_numeric::cast(resolution);
+        let texel_size = (radius + radius) / (resolution - S::one());
+        let extent = texel_size * resolution;
+        let half_extent = extent * one_half;
+
+        // Snap the corner of the square down to whole texels on a grid anchored at
+        // the origin of light space, which is the world space origin.
+        let center = light_rotation.apply_point(&view.inverse_apply_point(&view_center));
+        let mut min_x = center.x - radius;
+        let mut min_y = center.y - radius;
+        if texel_size > S::zero() {
+            min_x = (min_x / texel_size).floor() * texel_size;
+            min_y = (min_y / texel_size).floor() * texel_size;
+        }
+
+        // Move the light to the center of the near face of the light space box, so
+        // that the orthographic projection is symmetric with its near plane at the light.
+        let center_x = min_x + half_extent;
+        let center_y = min_y + half_extent;
+        let light_eye = light_rotation.inverse_apply_point(&Point3::new(center_x, center_y, min_depth * gaze_sign));
+        let light_view = match ndc.handedness {
+            Handedness::RightHanded => Isometry3::look_to_rh(&light_eye, direction, &up),
+            Handedness::LeftHanded => Isometry3::look_to_lh(&light_eye, direction, &up),
+        };
+        let projection = Orthographic3::new_with_ndc(
+            half_extent,
+            half_extent,
+            half_extent,
+            half_extent,
+            S::zero(),
+            max_depth - min_depth,
+            ndc,
+        );
+
+        Self {
+            near,
+            far,
+            light_view,
+            projection,
+        }
+    }
+
+    /// Compute the view space corners of the slice of the viewing frustum of a
+    /// perspective camera between the view space distances `near` and `far`.
+    fn slice_corners(perspective: &Perspective3<S>, near: S, far: S) -> [Point3<S>; 8] {
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let frustum_near = perspective.near();
+        let left = perspective.left() / frustum_near;
+        let right = perspective.right() / frustum_near;
+        let bottom = perspective.bottom() / frustum_near;
+        let top = perspective.top() / frustum_near;
+        // A skewed projection shears the frustum along the x-axis in proportion to
+        // the height of each corner.
+        let shear = perspective.shear();
+        let corner = |x: S, y: S, distance: S| Point3::new((x + shear * y) * distance, y * distance, S::zero()) + gaze * distance;
+
+        [
+            corner(-left, -bottom, near),
+            corner(right, -bottom, near),
+            corner(-left, top, near),
+            corner(right, top, near),
+            corner(-left, -bottom, far),
+            corner(right, -bottom, far),
+            corner(-left, top, far),
+            corner(right, top, far),
+        ]
+    }
+
+    /// Get the view space distance from the camera where the slice of the
+    /// shadow cascade begins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(-Vector3::unit_y());
+    /// let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 5_f64, 20_f64, 1024).unwrap();
+    ///
+    /// assert_eq!(cascade.near(), 5_f64);
+    /// ```
+    #[inline]
+    pub const fn near(&self) -> S {
+        self.near
+    }
+
+    /// Get the view space distance from the camera where the slice of the
+    /// shadow cascade ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(-Vector3::unit_y());
+    /// let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 5_f64, 20_f64, 1024).unwrap();
+    ///
+    /// assert_eq!(cascade.far(), 20_f64);
+    /// ```
+    #[inline]
+    pub const fn far(&self) -> S {
+        self.far
+    }
+
+    /// Get the view transformation of the light, mapping world space to the
+    /// light's view space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(Vector3::new(1_f64, -1_f64, 0_f64));
+    /// let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 5_f64, 20_f64, 1024).unwrap();
+    /// let expected = -Vector3::unit_z();
+    /// let result = cascade.light_view().apply_vector(light_direction.as_ref());
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn light_view(&self) -> &Isometry3<S> {
+        &self.light_view
+    }
+
+    /// Get the orthographic projection of the light.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(-Vector3::unit_y());
+    /// let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 5_f64, 20_f64, 1024).unwrap();
+    /// let projection = cascade.projection();
+    ///
+    /// assert_eq!(projection.left(), projection.right());
+    /// assert_eq!(projection.bottom(), projection.top());
+    /// ```
+    #[inline]
+    pub const fn projection(&self) -> &Orthographic3<S> {
+        &self.projection
+    }
+
+    /// Get the view-projection matrix of the light, which maps world space to
+    /// the clip space of the shadow map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     ShadowCascade,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::identity();
+    /// let light_direction = Unit::from_value(-Vector3::unit_y());
+    /// let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 5_f64, 20_f64, 1024).unwrap();
+    /// let expected = cascade.projection().matrix() * cascade.light_view().to_affine_matrix();
+    /// let result = cascade.view_projection();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn view_projection(&self) -> Matrix4x4<S> {
+        self.projection.matrix() * self.light_view.to_affine_matrix()
+    }
+}
+
+impl<S> fmt::Display for ShadowCascade<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "ShadowCascade [near={}, far={}, light_view={}, projection={}]",
+            self.near, self.far, self.light_view, self.projection
+        )
+    }
+}
@@ -0,0 +1,192 @@
+use cglinalg_core::Vector3;
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A face of a cubemap.
+///
+/// OpenGL, Vulkan, and Direct3D share the same cubemap layout. The faces are
+/// stored in the order `+X`, `-X`, `+Y`, `-Y`, `+Z`, `-Z`, and each face is
+/// named after the world space axis its center points along. A lookup direction
+/// `r` selects the face of its component `ma` with the largest magnitude, and the
+/// texture coordinates `(s, t)` on that face, with `s` increasing along the columns
+/// and `t` increasing along the rows of the face image starting from its first row,
+/// are given by `s == (sc / |ma| + 1) / 2` and `t == (tc / |ma| + 1) / 2` where
+///
+/// | Face | `sc`  | `tc`  | `ma`  |
+/// |------|-------|-------|-------|
+/// | `+X` | `-rz` | `-ry` | `+rx` |
+/// | `-X` | `+rz` | `-ry` | `-rx` |
+/// | `+Y` | `+rx` | `+rz` | `+ry` |
+/// | `-Y` | `+rx` | `-rz` | `-ry` |
+/// | `+Z` | `+rx` | `-ry` | `+rz` |
+/// | `-Z` | `-rx` | `-ry` | `-rz` |
+///
+/// Rendering into a cubemap face requires a view transformation whose gaze
+/// direction is the face direction, and whose up direction makes the rendered
+/// image agree with this layout. See [`Isometry3::cubemap_face_views_rh`] and
+/// [`Isometry3::cubemap_face_views_lh`].
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::Vector3;
+/// # use cglinalg_transform::CubemapFace;
+/// #
+/// let face = CubemapFace::NegativeY;
+///
+/// assert_eq!(face.index(), 3);
+/// assert_eq!(face.direction::<f64>(), -Vector3::unit_y());
+/// assert_eq!(CubemapFace::ALL[face.index()], face);
+/// ```
+///
+/// [`Isometry3::cubemap_face_views_rh`]: crate::Isometry3::cubemap_face_views_rh
+/// [`Isometry3::cubemap_face_views_lh`]: crate::Isometry3::cubemap_face_views_lh
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CubemapFace {
+    /// The face centered on the **positive x-axis**.
+    PositiveX,
+    /// The face centered on the **negative x-axis**.
+    NegativeX,
+    /// The face centered on the **positive y-axis**.
+    PositiveY,
+    /// The face centered on the **negative y-axis**.
+    NegativeY,
+    /// The face centered on the **positive z-axis**.
+    PositiveZ,
+    /// The face centered on the **negative z-axis**.
+    NegativeZ,
+}
+
+impl CubemapFace {
+    /// The faces of a cubemap in the order of their array layers.
+    pub const ALL: [Self; 6] = [Self::PositiveX, Self::NegativeX, Self::PositiveY, Self::NegativeY, Self::PositiveZ, Self::NegativeZ];
+
+    /// Get the array layer index of the face in a cubemap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::CubemapFace;
+    /// #
+    /// assert_eq!(CubemapFace::PositiveX.index(), 0);
+    /// assert_eq!(CubemapFace::NegativeX.index(), 1);
+    /// assert_eq!(CubemapFace::PositiveY.index(), 2);
+    /// assert_eq!(CubemapFace::NegativeY.index(), 3);
+    /// assert_eq!(CubemapFace::PositiveZ.index(), 4);
+    /// assert_eq!(CubemapFace::NegativeZ.index(), 5);
+    /// ```
+    #[inline]
+    pub const fn index(self) -> usize {
+        match self {
+            Self::PositiveX => 0,
+            Self::NegativeX => 1,
+            Self::PositiveY => 2,
+            Self::NegativeY => 3,
+            Self::PositiveZ => 4,
+            Self::NegativeZ => 5,
+        }
+    }
+
+    /// Get the world space direction from the center of the cubemap to the
+    /// center of the face.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::CubemapFace;
+    /// #
+    /// assert_eq!(CubemapFace::PositiveZ.direction::<f64>(), Vector3::unit_z());
+    /// assert_eq!(CubemapFace::NegativeX.direction::<f64>(), -Vector3::unit_x());
+    /// ```
+    #[inline]
+    pub fn direction<S>(self) -> Vector3<S>
+    where
+        S: SimdScalarFloat,
+    {
+        match self {
+            Self::PositiveX => Vector3::unit_x(),
+            Self::NegativeX => -Vector3::unit_x(),
+            Self::PositiveY => Vector3::unit_y(),
+            Self::NegativeY => -Vector3::unit_y(),
+            Self::PositiveZ => Vector3::unit_z(),
+            Self::NegativeZ => -Vector3::unit_z(),
+        }
+    }
+
+    /// Get the world space up direction of the **right-handed** view
+    /// transformation that renders the face.
+    ///
+    /// The up direction points from the center of the face towards its last row,
+    /// because a right-handed view of the face agrees with the cubemap layout only
+    /// when the view space up direction points along increasing rows. This is the
+    /// convention used by OpenGL, whose first framebuffer row lies at the bottom
+    /// of the window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::CubemapFace;
+    /// #
+    /// assert_eq!(CubemapFace::PositiveX.up_rh::<f64>(), -Vector3::unit_y());
+    /// assert_eq!(CubemapFace::PositiveY.up_rh::<f64>(), Vector3::unit_z());
+    /// ```
+    #[inline]
+    pub fn up_rh<S>(self) -> Vector3<S>
+    where
+        S: SimdScalarFloat,
+    {
+        match self {
+            Self::PositiveX => -Vector3::unit_y(),
+            Self::NegativeX => -Vector3::unit_y(),
+            Self::PositiveY => Vector3::unit_z(),
+            Self::NegativeY => -Vector3::unit_z(),
+            Self::PositiveZ => -Vector3::unit_y(),
+            Self::NegativeZ => -Vector3::unit_y(),
+        }
+    }
+
+    /// Get the world space up direction of the **left-handed** view
+    /// transformation that renders the face.
+    ///
+    /// The up direction points from the center of the face towards its first row,
+    /// because a left-handed view of the face agrees with the cubemap layout only
+    /// when the view space up direction points along decreasing rows. This is the
+    /// convention used by Direct3D, whose first framebuffer row lies at the top
+    /// of the window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::CubemapFace;
+    /// #
+    /// assert_eq!(CubemapFace::PositiveX.up_lh::<f64>(), Vector3::unit_y());
+    /// assert_eq!(CubemapFace::PositiveY.up_lh::<f64>(), -Vector3::unit_z());
+    /// ```
+    #[inline]
+    pub fn up_lh<S>(self) -> Vector3<S>
+    where
+        S: SimdScalarFloat,
+    {
+        -self.up_rh()
+    }
+}
+
+impl fmt::Display for CubemapFace {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Self::PositiveX => "+X",
+            Self::NegativeX => "-X",
+            Self::PositiveY => "+Y",
+            Self::NegativeY => "-Y",
+            Self::PositiveZ => "+Z",
+            Self::NegativeZ => "-Z",
+        };
+
+        write!(formatter, "CubemapFace [{}]", name)
+    }
+}
@@ -0,0 +1,571 @@
+use crate::isometry::Isometry3;
+use crate::projection::{
+    Orthographic3,
+    Perspective3,
+};
+use cglinalg_core::{
+    Matrix4x4,
+    NdcConvention,
+    Point3,
+    Vector3,
+    Vector4,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// The result of classifying a geometric object against a viewing frustum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// The object lies entirely inside the frustum.
+    Inside,
+    /// The object lies entirely outside the frustum.
+    Outside,
+    /// The object straddles the boundary of the frustum.
+    Intersecting,
+}
+
+/// A viewing frustum in three dimensions.
+///
+/// A viewing frustum is the region of world space that a projection transformation
+/// maps into the canonical view volume of its normalized device coordinates
+/// convention. The frustum is represented by the six planes bounding it, which
+/// are extracted from the view-projection matrix using the Gribb-Hartmann method.
+///
+/// # Planes
+///
+/// Each plane is represented by a vector `[a, b, c, d]` such that a point `p`
+/// lies on the inner side of the plane when
+///
+/// ```text
+/// a * p.x + b * p.y + c * p.z + d >= 0
+/// ```
+///
+/// The normal vector `[a, b, c]` of each plane is a unit vector pointing into the
+/// frustum, so that the left-hand side is the signed distance from `p` to the plane.
+/// Let `(z_min, z_max)` be the depth range of the normalized device coordinates
+/// convention. The planes are ordered by the boundary of the canonical view volume
+/// that they map to
+///
+/// ```text
+/// [x == -1, x == 1, y == -1, y == 1, z == z_min, z == z_max]
+/// ```
+///
+/// For a projection with an infinitely distant **far plane**, the corresponding
+/// plane contains every point. It has a zero normal vector and an infinite offset.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::Point3;
+/// # use cglinalg_transform::{
+/// #     Containment,
+/// #     Frustum3,
+/// #     Isometry3,
+/// #     Perspective3,
+/// # };
+/// # use cglinalg_trigonometry::Degrees;
+/// #
+/// let vfov = Degrees(90_f64);
+/// let aspect_ratio = 1_f64;
+/// let near = 1_f64;
+/// let far = 100_f64;
+/// let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+/// let view = Isometry3::identity();
+/// let frustum = Frustum3::from_perspective(&projection, &view);
+///
+/// assert_eq!(frustum.classify_point(&Point3::new(0_f64, 0_f64, -10_f64)), Containment::Inside);
+/// assert_eq!(frustum.classify_point(&Point3::new(0_f64, 0_f64, 10_f64)), Containment::Outside);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Frustum3<S> {
+    planes: [Vector4<S>; 6],
+    view_projection: Matrix4x4<S>,
+    ndc: NdcConvention,
+}
+
+impl<S> Frustum3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct the viewing frustum of a view-projection matrix `view_projection`
+    /// for the normalized device coordinates convention `ndc`.
+    ///
+    /// The view-projection matrix maps world space to clip space. Only the depth
+    /// range of `ndc` matters for the shape of the frustum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// #     Vector4,
+    /// # };
+    /// # use cglinalg_transform::Frustum3;
+    /// #
+    /// let view_projection = Matrix4x4::from_orthographic(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 3_f64);
+    /// let frustum = Frustum3::from_matrix(&view_projection, NdcConvention::OPENGL);
+    /// let expected = [
+    ///     Vector4::new( 1_f64,  0_f64,  0_f64, 1_f64),
+    ///     Vector4::new(-1_f64,  0_f64,  0_f64, 1_f64),
+    ///     Vector4::new( 0_f64,  1_f64,  0_f64, 1_f64),
+    ///     Vector4::new( 0_f64, -1_f64,  0_f64, 1_f64),
+    ///     Vector4::new( 0_f64,  0_f64, -1_f64, -1_f64),
+    ///     Vector4::new( 0_f64,  0_f64,  1_f64, 3_f64),
+    /// ];
+    /// let result = frustum.planes();
+    ///
+    /// for i in 0..6 {
+    ///     assert_relative_eq!(result[i], expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// }
+    /// ```
+    pub fn from_matrix(view_projection: &Matrix4x4<S>, ndc: NdcConvention) -> Self {
+        // A point `p` in world space lies inside the frustum exactly when its
+        // clip space coordinates `q == view_projection * p` satisfy
+        // ```text
+        // -q.w <= q.x <= q.w
+        // -q.w <= q.y <= q.w
+        // z_min * q.w <= q.z <= z_max * q.w
+        // ```
+        // Each inequality is linear in the homogeneous coordinates of `p`, so each
+        // one is a plane whose coefficients are a combination of the rows of the
+        // view-projection matrix.
+        let (z_min, z_max) = ndc.depth_bounds::<S>();
+        let row_x = view_projection.row(0);
+        let row_y = view_projection.row(1);
+        let row_z = view_projection.row(2);
+        let row_w = view_projection.row(3);
+        let planes = [
+            Self::normalize_plane(&(row_w + row_x)),
+            Self::normalize_plane(&(row_w - row_x)),
+            Self::normalize_plane(&(row_w + row_y)),
+            Self::normalize_plane(&(row_w - row_y)),
+            Self::normalize_plane(&(row_z - row_w * z_min)),
+            Self::normalize_plane(&(row_w * z_max - row_z)),
+        ];
+
+        Self {
+            planes,
+            view_projection: *view_projection,
+            ndc,
+        }
+    }
+
+    /// Construct the viewing frustum of a perspective projection `projection`
+    /// viewed through the view transformation `view`.
+    ///
+    /// The view transformation maps world space into the view space of the camera,
+    /// such as the isometries constructed by [`Isometry3::look_at_rh`] and
+    /// [`Isometry3::look_at_lh`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Containment,
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 1_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let eye = Point3::new(10_f64, 0_f64, 0_f64);
+    /// let target = Point3::new(20_f64, 0_f64, 0_f64);
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    /// let frustum = Frustum3::from_perspective(&projection, &view);
+    ///
+    /// assert_eq!(frustum.classify_point(&Point3::new(50_f64, 0_f64, 0_f64)), Containment::Inside);
+    /// assert_eq!(frustum.classify_point(&Point3::new(0_f64, 0_f64, 0_f64)), Containment::Outside);
+    /// ```
+    pub fn from_perspective(projection: &Perspective3<S>, view: &Isometry3<S>) -> Self {
+        let view_projection = projection.matrix() * view.to_affine_matrix();
+
+        Self::from_matrix(&view_projection, projection.ndc_convention())
+    }
+
+    /// Construct the viewing frustum of an orthographic projection `projection`
+    /// viewed through the view transformation `view`.
+    ///
+    /// The view transformation maps world space into the view space of the camera,
+    /// such as the isometries constructed by [`Isometry3::look_at_rh`] and
+    /// [`Isometry3::look_at_lh`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Containment,
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// # };
+    /// #
+    /// let projection = Orthographic3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 10_f64);
+    /// let eye = Point3::new(0_f64, 5_f64, 0_f64);
+    /// let target = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let up = Vector3::unit_z();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    /// let frustum = Frustum3::from_orthographic(&projection, &view);
+    ///
+    /// assert_eq!(frustum.classify_point(&Point3::new(0_f64, 0_f64, 0_f64)), Containment::Inside);
+    /// assert_eq!(frustum.classify_point(&Point3::new(2_f64, 0_f64, 0_f64)), Containment::Outside);
+    /// ```
+    pub fn from_orthographic(projection: &Orthographic3<S>, view: &Isometry3<S>) -> Self {
+        let view_projection = projection.matrix() * view.to_affine_matrix();
+
+        Self::from_matrix(&view_projection, projection.ndc_convention())
+    }
+
+    /// Rescale a plane so that its normal vector is a unit vector.
+    ///
+    /// A plane with a zero normal vector either contains every point or no point
+    /// at all, depending on the sign of its offset. We represent it by an infinite
+    /// offset so that the signed distance of every point to the plane is infinite
+    /// with the same sign.
+    #[inline]
+    fn normalize_plane(plane: &Vector4<S>) -> Vector4<S> {
+        let normal = plane.contract();
+        let norm = normal.norm();
+        if norm == S::zero() {
+            let offset = if plane[3] >= S::zero() { S::infinity() } else { -S::infinity() };
+
+            Vector4::new(S::zero(), S::zero(), S::zero(), offset)
+        } else {
+            plane / norm
+        }
+    }
+
+    /// Get the bounding planes of the frustum.
+    ///
+    /// See the documentation of [`Frustum3`] for the representation and the order
+    /// of the planes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::{
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 1_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let frustum = Frustum3::from_perspective(&projection, &Isometry3::identity());
+    ///
+    /// for plane in frustum.planes().iter() {
+    ///     assert!((plane.contract().norm() - 1_f64).abs() <= 1e-10);
+    /// }
+    /// ```
+    #[inline]
+    pub const fn planes(&self) -> &[Vector4<S>; 6] {
+        &self.planes
+    }
+
+    /// Get the view-projection matrix that the frustum was constructed from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// # use cglinalg_transform::Frustum3;
+    /// #
+    /// let view_projection = Matrix4x4::from_perspective(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+    /// let frustum = Frustum3::from_matrix(&view_projection, NdcConvention::OPENGL);
+    ///
+    /// assert_eq!(frustum.view_projection(), &view_projection);
+    /// ```
+    #[inline]
+    pub const fn view_projection(&self) -> &Matrix4x4<S> {
+        &self.view_projection
+    }
+
+    /// Get the normalized device coordinates convention of the frustum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     NdcConvention,
+    /// # };
+    /// # use cglinalg_transform::Frustum3;
+    /// #
+    /// let view_projection = Matrix4x4::from_perspective(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+    /// let frustum = Frustum3::from_matrix(&view_projection, NdcConvention::VULKAN);
+    ///
+    /// assert_eq!(frustum.ndc_convention(), NdcConvention::VULKAN);
+    /// ```
+    #[inline]
+    pub const fn ndc_convention(&self) -> NdcConvention {
+        self.ndc
+    }
+
+    /// Get the eight corner points of the frustum in world space.
+    ///
+    /// The corners are the images of the corners of the canonical view volume
+    /// under the inverse of the view-projection matrix. Let `(z_min, z_max)` be the
+    /// depth range of the normalized device coordinates convention. The corners
+    /// are ordered by their normalized device coordinates
+    ///
+    /// ```text
+    /// [
+    ///     [-1, -1, z_min], [1, -1, z_min], [1, 1, z_min], [-1, 1, z_min],
+    ///     [-1, -1, z_max], [1, -1, z_max], [1, 1, z_max], [-1, 1, z_max],
+    /// ]
+    /// ```
+    ///
+    /// The function returns `None` if the view-projection matrix is not invertible,
+    /// or if some of the corners lie at infinity, as they do for a projection with
+    /// an infinitely distant **far plane**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// # };
+    /// #
+    /// let projection = Perspective3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 10_f64);
+    /// let frustum = Frustum3::from_perspective(&projection, &Isometry3::identity());
+    /// let expected = [
+    ///     Point3::new(-1_f64,  -1_f64,  -1_f64),
+    ///     Point3::new( 1_f64,  -1_f64,  -1_f64),
+    ///     Point3::new( 1_f64,   1_f64,  -1_f64),
+    ///     Point3::new(-1_f64,   1_f64,  -1_f64),
+    ///     Point3::new(-10_f64, -10_f64, -10_f64),
+    ///     Point3::new( 10_f64, -10_f64, -10_f64),
+    ///     Point3::new( 10_f64,  10_f64, -10_f64),
+    ///     Point3::new(-10_f64,  10_f64, -10_f64),
+    /// ];
+    /// let result = frustum.corners().unwrap();
+    ///
+    /// for i in 0..8 {
+    ///     assert_relative_eq!(result[i], expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// }
+    ///
+    /// let infinite = Perspective3::new_infinite(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, NdcConvention::OPENGL);
+    /// let frustum = Frustum3::from_perspective(&infinite, &Isometry3::identity());
+    ///
+    /// assert!(frustum.corners().is_none());
+    /// ```
+    pub fn corners(&self) -> Option<[Point3<S>; 8]> {
+        // A bounding plane with an infinite offset lies at infinity, and so do
+        // the corners on it.
+        if self.planes.iter().any(|plane| plane[3].is_infinite()) {
+            return None;
+        }
+
+        let inverse = self.view_projection.try_inverse()?;
+        let one = S::one();
+        let (z_min, z_max) = self.ndc.depth_bounds::<S>();
+        let ndc_corners = [
+            Vector4::new(-one, -one, z_min, one),
+            Vector4::new(one, -one, z_min, one),
+            Vector4::new(one, one, z_min, one),
+            Vector4::new(-one, one, z_min, one),
+            Vector4::new(-one, -one, z_max, one),
+            Vector4::new(one, -one, z_max, one),
+            Vector4::new(one, one, z_max, one),
+            Vector4::new(-one, one, z_max, one),
+        ];
+        let mut corners = [Point3::origin(); 8];
+        for i in 0..8 {
+            let corner = inverse * ndc_corners[i];
+            corners[i] = Point3::from_homogeneous(&corner)?;
+        }
+
+        Some(corners)
+    }
+
+    /// Compute the signed distance from a point to each plane of the frustum.
+    #[inline]
+    fn signed_distances(&self, point: &Point3<S>) -> [S; 6] {
+        let mut distances = [S::zero(); 6];
+        for (distance, plane) in distances.iter_mut().zip(self.planes.iter()) {
+            *distance = plane[0] * point[0] + plane[1] * point[1] + plane[2] * point[2] + plane[3];
+        }
+
+        distances
+    }
+
+    /// Classify a point against the frustum.
+    ///
+    /// The function returns [`Containment::Inside`] when the point lies inside
+    /// or on the boundary of the frustum, and [`Containment::Outside`] otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_transform::{
+    /// #     Containment,
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// # };
+    /// #
+    /// let projection = Orthographic3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 10_f64);
+    /// let frustum = Frustum3::from_orthographic(&projection, &Isometry3::identity());
+    ///
+    /// assert_eq!(frustum.classify_point(&Point3::new(0_f64, 0_f64, -5_f64)), Containment::Inside);
+    /// assert_eq!(frustum.classify_point(&Point3::new(0.5_f64, 0.5_f64, -1.5_f64)), Containment::Inside);
+    /// assert_eq!(frustum.classify_point(&Point3::new(0_f64, 0_f64, -11_f64)), Containment::Outside);
+    /// ```
+    pub fn classify_point(&self, point: &Point3<S>) -> Containment {
+        let distances = self.signed_distances(point);
+        for distance in distances {
+            if distance < S::zero() {
+                return Containment::Outside;
+            }
+        }
+
+        Containment::Inside
+    }
+
+    /// Classify a sphere with center `center` and radius `radius` against
+    /// the frustum.
+    ///
+    /// The test is conservative: a sphere lying outside the frustum near one of
+    /// its edges or corners may be classified as [`Containment::Intersecting`].
+    /// A sphere classified as [`Containment::Inside`] or [`Containment::Outside`]
+    /// is always classified correctly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_transform::{
+    /// #     Containment,
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// # };
+    /// #
+    /// let projection = Orthographic3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 10_f64);
+    /// let frustum = Frustum3::from_orthographic(&projection, &Isometry3::identity());
+    ///
+    /// assert_eq!(frustum.classify_sphere(&Point3::new(0_f64, 0_f64, -5_f64), 0.5_f64), Containment::Inside);
+    /// assert_eq!(frustum.classify_sphere(&Point3::new(0_f64, 0_f64, -5_f64), 2_f64), Containment::Intersecting);
+    /// assert_eq!(frustum.classify_sphere(&Point3::new(5_f64, 0_f64, -5_f64), 2_f64), Containment::Outside);
+    /// ```
+    pub fn classify_sphere(&self, center: &Point3<S>, radius: S) -> Containment {
+        let distances = self.signed_distances(center);
+        let mut result = Containment::Inside;
+        for distance in distances {
+            if distance < -radius {
+                return Containment::Outside;
+            } else if distance < radius {
+                result = Containment::Intersecting;
+            }
+        }
+
+        result
+    }
+
+    /// Classify an axis-aligned bounding box with minimum corner `min` and maximum
+    /// corner `max` against the frustum.
+    ///
+    /// The test is conservative: a box lying outside the frustum near one of its
+    /// edges or corners may be classified as [`Containment::Intersecting`]. A box
+    /// classified as [`Containment::Inside`] or [`Containment::Outside`] is always
+    /// classified correctly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_transform::{
+    /// #     Containment,
+    /// #     Frustum3,
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// # };
+    /// #
+    /// let projection = Orthographic3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 10_f64);
+    /// let frustum = Frustum3::from_orthographic(&projection, &Isometry3::identity());
+    /// let inside = (Point3::new(-0.5_f64, -0.5_f64, -6_f64), Point3::new(0.5_f64, 0.5_f64, -4_f64));
+    /// let intersecting = (Point3::new(0.5_f64, -0.5_f64, -6_f64), Point3::new(1.5_f64, 0.5_f64, -4_f64));
+    /// let outside = (Point3::new(2_f64, -0.5_f64, -6_f64), Point3::new(3_f64, 0.5_f64, -4_f64));
+    ///
+    /// assert_eq!(frustum.classify_aabb(&inside.0, &inside.1), Containment::Inside);
+    /// assert_eq!(frustum.classify_aabb(&intersecting.0, &intersecting.1), Containment::Intersecting);
+    /// assert_eq!(frustum.classify_aabb(&outside.0, &outside.1), Containment::Outside);
+    /// ```
+    pub fn classify_aabb(&self, min: &Point3<S>, max: &Point3<S>) -> Containment {
+        // For each plane, the corner of the box furthest along the plane normal
+        // decides whether the box lies entirely outside the plane, and the corner
+        // furthest against the plane normal decides whether the box lies entirely
+        // inside the plane.
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let mut positive = Point3::origin();
+            let mut negative = Point3::origin();
+            for i in 0..3 {
+                if plane[i] >= S::zero() {
+                    positive[i] = max[i];
+                    negative[i] = min[i];
+                } else {
+                    positive[i] = min[i];
+                    negative[i] = max[i];
+                }
+            }
+
+            let normal = Vector3::new(plane[0], plane[1], plane[2]);
+            let positive_distance = normal.dot(&positive.to_vector()) + plane[3];
+            let negative_distance = normal.dot(&negative.to_vector()) + plane[3];
+            if positive_distance < S::zero() {
+                return Containment::Outside;
+            } else if negative_distance < S::zero() {
+                result = Containment::Intersecting;
+            }
+        }
+
+        result
+    }
+}
+
+impl<S> fmt::Display for Frustum3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Frustum3 [planes=[{}, {}, {}, {}, {}, {}]]",
+            self.planes[0], self.planes[1], self.planes[2], self.planes[3], self.planes[4], self.planes[5]
+        )
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cubemap::CubemapFace;
 use crate::rotation::{
     Rotation,
     Rotation2,
@@ -14,9 +15,12 @@ use cglinalg_core::{
     DimAdd,
     DimLt,
     DimMul,
+    DualQuaternion,
     Matrix,
+    Matrix3x3,
     Point,
     Point3,
+    Quaternion,
     ShapeConstraint,
     Unit,
     Vector,
@@ -864,6 +868,46 @@ where
     }
 }
 
+impl<S> From<DualQuaternion<S>> for Isometry3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(dual_quaternion: DualQuaternion<S>) -> Isometry3<S> {
+        Isometry3::from_dual_quaternion(&dual_quaternion)
+    }
+}
+
+impl<S> From<&DualQuaternion<S>> for Isometry3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(dual_quaternion: &DualQuaternion<S>) -> Isometry3<S> {
+        Isometry3::from_dual_quaternion(dual_quaternion)
+    }
+}
+
+impl<S> From<Isometry3<S>> for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(isometry: Isometry3<S>) -> DualQuaternion<S> {
+        isometry.to_dual_quaternion()
+    }
+}
+
+impl<S> From<&Isometry3<S>> for DualQuaternion<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(isometry: &Isometry3<S>) -> DualQuaternion<S> {
+        isometry.to_dual_quaternion()
+    }
+}
+
 impl<S, const N: usize> ops::Mul<Point<S, N>> for Isometry<S, N>
 where
     S: SimdScalarFloat,
@@ -1241,6 +1285,94 @@ where
         Self::from_parts(&translation, &rotation)
     }
 
+    /// Construct a new isometry from a rotation vector and a displacement vector.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians. See [`Rotation3::from_scaled_axis`]
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// #
+    /// let scaled_axis = Vector3::new(0.5_f64, -1_f64, 0.25_f64);
+    /// let distance = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let translation = Translation3::from_vector(&distance);
+    /// let rotation = Rotation3::from_scaled_axis(&scaled_axis);
+    /// let expected = Isometry3::from_parts(&translation, &rotation);
+    /// let result = Isometry3::from_scaled_axis_translation(&scaled_axis, &distance);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_scaled_axis_translation(scaled_axis: &Vector3<S>, distance: &Vector3<S>) -> Self {
+        Self {
+            rotation: Rotation3::from_scaled_axis(scaled_axis),
+            translation: Translation3::from_vector(distance),
+        }
+    }
+
+    /// Construct a new isometry from a rotation vector.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians. See [`Rotation3::from_scaled_axis`]
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// # };
+    /// #
+    /// let scaled_axis = Vector3::new(0_f64, 0_f64, 1.5_f64);
+    /// let rotation = Rotation3::from_scaled_axis(&scaled_axis);
+    /// let expected = Isometry3::from_rotation(&rotation);
+    /// let result = Isometry3::from_scaled_axis(&scaled_axis);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_scaled_axis(scaled_axis: &Vector3<S>) -> Self {
+        let translation = Translation3::identity();
+        let rotation = Rotation3::from_scaled_axis(scaled_axis);
+
+        Self::from_parts(&translation, &rotation)
+    }
+
+    /// Compute the rotation vector of the rotational part of the isometry.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians, with the angle in the range `[0, pi]`.
+    /// See [`Rotation3::scaled_axis`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let scaled_axis = Vector3::new(-0.75_f64, 0.5_f64, 2_f64);
+    /// let distance = Vector3::new(4_f64, 5_f64, 6_f64);
+    /// let isometry = Isometry3::from_scaled_axis_translation(&scaled_axis, &distance);
+    /// let result = isometry.scaled_axis();
+    ///
+    /// assert_relative_eq!(result, scaled_axis, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn scaled_axis(&self) -> Vector3<S> {
+        self.rotation.scaled_axis()
+    }
+
     /// Construct an isometry from a rotation angle in the **yz-plane** about
     /// the **x-axis**.
     ///
@@ -1778,6 +1910,410 @@ where
 
         Self::from_parts(&translation, &rotation)
     }
+
+    /// Construct the six **right-handed** view transformations that render the
+    /// faces of a cubemap centered at the position `center`.
+    ///
+    /// The view transformations are ordered by the array layers of the faces, as
+    /// in [`CubemapFace::ALL`]. Each view transformation looks from `center` along
+    /// the direction of its face, with the up direction [`CubemapFace::up_rh`].
+    ///
+    /// Cubemaps store their faces in a left-handed layout, so a right-handed view
+    /// of a face renders it correctly only when the view space up direction maps
+    /// to increasing framebuffer rows. Render each face with the projection
+    /// [`Perspective3::new_cubemap`] using the following normalized device
+    /// coordinates conventions.
+    ///
+    /// | Graphics API  | First framebuffer row | Normalized device coordinates convention                                          |
+    /// |---------------|-----------------------|-----------------------------------------------------------------------------------|
+    /// | OpenGL        | `y == -1`             | [`NdcConvention::OPENGL`]                                                         |
+    /// | Vulkan        | `y == -1`             | `NdcConvention::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Up)`   |
+    /// | Metal, WebGPU | `y == 1`              | `NdcConvention::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Down)` |
+    ///
+    /// For Direct3D, or any left-handed view space, use
+    /// [`Isometry3::cubemap_face_views_lh`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     CubemapFace,
+    /// #     Isometry3,
+    /// # };
+    /// #
+    /// let center = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let views = Isometry3::cubemap_face_views_rh(&center);
+    ///
+    /// for face in CubemapFace::ALL {
+    ///     let view = views[face.index()];
+    ///     let gaze = -Vector3::unit_z();
+    ///
+    ///     assert_relative_eq!(view.apply_point(&center), Point3::origin(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///     assert_relative_eq!(view.inverse_apply_vector(&gaze), face.direction(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///     assert_relative_eq!(view.inverse_apply_vector(&Vector3::unit_y()), face.up_rh(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// }
+    /// ```
+    ///
+    /// [`CubemapFace::ALL`]: crate::CubemapFace::ALL
+    /// [`CubemapFace::up_rh`]: crate::CubemapFace::up_rh
+    /// [`Perspective3::new_cubemap`]: crate::Perspective3::new_cubemap
+    /// [`NdcConvention::OPENGL`]: cglinalg_core::NdcConvention::OPENGL
+    pub fn cubemap_face_views_rh(center: &Point3<S>) -> [Self; 6] {
+        CubemapFace::ALL.map(|face| Self::look_to_rh(center, &face.direction(), &face.up_rh()))
+    }
+
+    /// Construct the six **left-handed** view transformations that render the
+    /// faces of a cubemap centered at the position `center`.
+    ///
+    /// The view transformations are ordered by the array layers of the faces, as
+    /// in [`CubemapFace::ALL`]. Each view transformation looks from `center` along
+    /// the direction of its face, with the up direction [`CubemapFace::up_lh`].
+    ///
+    /// A left-handed view of a face renders it correctly when the view space up
+    /// direction maps to the first framebuffer row, as it does for Direct3D with
+    /// [`NdcConvention::DIRECT3D`]. Render each face with the projection
+    /// [`Perspective3::new_cubemap`]. For right-handed view spaces, use
+    /// [`Isometry3::cubemap_face_views_rh`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     CubemapFace,
+    /// #     Isometry3,
+    /// # };
+    /// #
+    /// let center = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let views = Isometry3::cubemap_face_views_lh(&center);
+    ///
+    /// for face in CubemapFace::ALL {
+    ///     let view = views[face.index()];
+    ///     let gaze = Vector3::unit_z();
+    ///
+    ///     assert_relative_eq!(view.apply_point(&center), Point3::origin(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///     assert_relative_eq!(view.inverse_apply_vector(&gaze), face.direction(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///     assert_relative_eq!(view.inverse_apply_vector(&Vector3::unit_y()), face.up_lh(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// }
+    /// ```
+    ///
+    /// [`CubemapFace::ALL`]: crate::CubemapFace::ALL
+    /// [`CubemapFace::up_lh`]: crate::CubemapFace::up_lh
+    /// [`Perspective3::new_cubemap`]: crate::Perspective3::new_cubemap
+    /// [`NdcConvention::DIRECT3D`]: cglinalg_core::NdcConvention::DIRECT3D
+    pub fn cubemap_face_views_lh(center: &Point3<S>) -> [Self; 6] {
+        CubemapFace::ALL.map(|face| Self::look_to_lh(center, &face.direction(), &face.up_lh()))
+    }
+
+    /// Construct an isometry from the rigid transformation of a unit dual
+    /// quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     DualQuaternion,
+    /// #     Point3,
+    /// #     Quaternion,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Isometry3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let rotation = Quaternion::from_axis_angle(&axis, Degrees(90_f64));
+    /// let translation = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// let isometry = Isometry3::from_dual_quaternion(&dual_quaternion);
+    /// let point = Point3::new(1_f64, 0_f64, 0_f64);
+    /// let expected = dual_quaternion.apply_point(&point);
+    /// let result = isometry.apply_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_dual_quaternion(dual_quaternion: &DualQuaternion<S>) -> Self {
+        let (rotation, translation) = dual_quaternion.to_rotation_translation();
+
+        Self::from_parts(&Translation3::from_vector(&translation), &Rotation3::from_quaternion(&rotation))
+    }
+
+    /// Convert an isometry into a unit dual quaternion representing the same
+    /// rigid transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Isometry3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+    /// let distance = Vector3::new(-2_f64, 0_f64, 5_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(120_f64), &distance);
+    /// let dual_quaternion = isometry.to_dual_quaternion();
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = isometry.apply_point(&point);
+    /// let result = dual_quaternion.apply_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_dual_quaternion(&self) -> DualQuaternion<S> {
+        let rotation = Quaternion::from(&self.rotation);
+
+        DualQuaternion::from_rotation_translation(&rotation, self.translation.vector())
+    }
+
+    /// Construct an isometry from a twist using the exponential map of **SE(3)**.
+    ///
+    /// The twist is a six-dimensional vector `[rho; phi]`, where the first three
+    /// components `rho` are the translational part, and the last three components
+    /// `phi` are the rotation vector. The rotation of the resulting isometry is
+    /// `Rotation3::exp(phi)`, and its translation is
+    /// `Rotation3::left_jacobian(phi) * rho`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Vector,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// #
+    /// let twist = Vector::from([1_f64, -2_f64, 0.5_f64, 0_f64, 0_f64, 0_f64]);
+    /// let expected = Isometry3::from_translation(&Translation3::new(1_f64, -2_f64, 0.5_f64));
+    /// let result = Isometry3::exp(&twist);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn exp(twist: &Vector<S, 6>) -> Self {
+        let rho = crate::lie::sub_vector3(twist, 0);
+        let phi = crate::lie::sub_vector3(twist, 3);
+        let rotation = Rotation3::exp(&phi);
+        let translation = crate::lie::so3_left_jacobian(&phi) * rho;
+
+        Self::from_parts(&Translation3::from_vector(&translation), &rotation)
+    }
+
+    /// Compute the twist of an isometry using the logarithm map of **SE(3)**.
+    ///
+    /// This is the inverse of [`Isometry3::exp`]. The twist is a six-dimensional
+    /// vector `[rho; phi]`, where `phi` is the rotation vector of the rotation
+    /// with an angle in the range `[0, pi]`, and `rho` is the translational part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let twist = Vector::from([0.5_f64, 1_f64, -1.5_f64, 0.3_f64, -0.6_f64, 0.9_f64]);
+    /// let isometry = Isometry3::exp(&twist);
+    /// let result = isometry.log();
+    ///
+    /// assert_relative_eq!(result, twist, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Vector<S, 6> {
+        let phi = self.rotation.log();
+        let rho = crate::lie::so3_left_jacobian_inverse(&phi) * self.translation.vector();
+
+        Vector::from([rho[0], rho[1], rho[2], phi[0], phi[1], phi[2]])
+    }
+
+    /// Compute the left Jacobian of **SE(3)** at a twist.
+    ///
+    /// The twist is laid out as `[rho; phi]`, as in [`Isometry3::exp`]. The left
+    /// Jacobian relates a perturbation of the twist to a perturbation of the
+    /// isometry applied on the left, i.e. to first order
+    /// ```text
+    /// exp(xi + delta) == exp(left_jacobian(xi) * delta) * exp(xi)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let twist = Vector::from([0.2_f64, -0.4_f64, 1_f64, 0.7_f64, 0.1_f64, -0.3_f64]);
+    /// let isometry = Isometry3::exp(&twist);
+    /// let delta = Vector::from([1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64]);
+    /// let expected = isometry.adjoint() * (Isometry3::right_jacobian(&twist) * delta);
+    /// let result = Isometry3::left_jacobian(&twist) * delta;
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left_jacobian(twist: &Vector<S, 6>) -> Matrix<S, 6, 6> {
+        let rho = crate::lie::sub_vector3(twist, 0);
+        let phi = crate::lie::sub_vector3(twist, 3);
+        let jacobian = crate::lie::so3_left_jacobian(&phi);
+        let q = crate::lie::se3_left_jacobian_q(&rho, &phi);
+        let mut result = Matrix::zero();
+        crate::lie::set_block(&mut result, 0, 0, &jacobian);
+        crate::lie::set_block(&mut result, 0, 3, &q);
+        crate::lie::set_block(&mut result, 3, 3, &jacobian);
+
+        result
+    }
+
+    /// Compute the right Jacobian of **SE(3)** at a twist.
+    ///
+    /// The twist is laid out as `[rho; phi]`, as in [`Isometry3::exp`]. The right
+    /// Jacobian relates a perturbation of the twist to a perturbation of the
+    /// isometry applied on the right, i.e. to first order
+    /// ```text
+    /// exp(xi + delta) == exp(xi) * exp(right_jacobian(xi) * delta)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let twist = Vector::from([1_f64, 0_f64, -2_f64, 0.4_f64, 0.4_f64, 0.2_f64]);
+    /// let expected = Isometry3::left_jacobian(&(-twist));
+    /// let result = Isometry3::right_jacobian(&twist);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right_jacobian(twist: &Vector<S, 6>) -> Matrix<S, 6, 6> {
+        Self::left_jacobian(&(-twist))
+    }
+
+    /// Compute the inverse of the left Jacobian of **SE(3)** at a twist.
+    ///
+    /// The inverse exists for rotation angles less than `2 * pi`, which covers
+    /// every twist returned by [`Isometry3::log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let twist = Vector::from([-0.3_f64, 0.8_f64, 0.1_f64, 1.2_f64, -0.5_f64, 0.4_f64]);
+    /// let jacobian = Isometry3::left_jacobian(&twist);
+    /// let jacobian_inverse = Isometry3::left_jacobian_inverse(&twist);
+    /// let vector = Vector::from([1_f64, -1_f64, 2_f64, 0_f64, 3_f64, -2_f64]);
+    /// let result = jacobian_inverse * (jacobian * vector);
+    ///
+    /// assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left_jacobian_inverse(twist: &Vector<S, 6>) -> Matrix<S, 6, 6> {
+        let rho = crate::lie::sub_vector3(twist, 0);
+        let phi = crate::lie::sub_vector3(twist, 3);
+        let jacobian_inverse = crate::lie::so3_left_jacobian_inverse(&phi);
+        let q = crate::lie::se3_left_jacobian_q(&rho, &phi);
+        let upper_right = -(jacobian_inverse * q * jacobian_inverse);
+        let mut result = Matrix::zero();
+        crate::lie::set_block(&mut result, 0, 0, &jacobian_inverse);
+        crate::lie::set_block(&mut result, 0, 3, &upper_right);
+        crate::lie::set_block(&mut result, 3, 3, &jacobian_inverse);
+
+        result
+    }
+
+    /// Compute the inverse of the right Jacobian of **SE(3)** at a twist.
+    ///
+    /// The inverse exists for rotation angles less than `2 * pi`, which covers
+    /// every twist returned by [`Isometry3::log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let twist = Vector::from([2_f64, 0.5_f64, -1_f64, -0.9_f64, 0.3_f64, 1.1_f64]);
+    /// let jacobian = Isometry3::right_jacobian(&twist);
+    /// let jacobian_inverse = Isometry3::right_jacobian_inverse(&twist);
+    /// let vector = Vector::from([0_f64, 1_f64, 1_f64, -2_f64, 0.5_f64, 4_f64]);
+    /// let result = jacobian * (jacobian_inverse * vector);
+    ///
+    /// assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right_jacobian_inverse(twist: &Vector<S, 6>) -> Matrix<S, 6, 6> {
+        Self::left_jacobian_inverse(&(-twist))
+    }
+
+    /// Compute the adjoint matrix of an isometry.
+    ///
+    /// The adjoint matrix maps twists expressed in the local frame of the
+    /// isometry to the parent frame, i.e.
+    /// ```text
+    /// isometry * exp(xi) * inverse(isometry) == exp(adjoint(isometry) * xi)
+    /// ```
+    /// For a twist laid out as `[rho; phi]`, the adjoint matrix is the
+    /// block matrix `[[R, t^ * R], [0, R]]`, where `R` is the rotation matrix,
+    /// and `t^` is the cross product matrix of the translation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Isometry3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+    /// let distance = Vector3::new(3_f64, 1_f64, -2_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(50_f64), &distance);
+    /// let twist = Vector::from([0.1_f64, 0.2_f64, 0.3_f64, -0.4_f64, 0.5_f64, 0.6_f64]);
+    /// let expected = isometry * Isometry3::exp(&twist) * isometry.inverse();
+    /// let result = Isometry3::exp(&(isometry.adjoint() * twist));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn adjoint(&self) -> Matrix<S, 6, 6> {
+        let rotation = self.rotation.matrix();
+        let translation_cross_rotation = Matrix3x3::cross_matrix(self.translation.vector()) * rotation;
+        let mut result = Matrix::zero();
+        crate::lie::set_block(&mut result, 0, 0, rotation);
+        crate::lie::set_block(&mut result, 0, 3, &translation_cross_rotation);
+        crate::lie::set_block(&mut result, 3, 3, rotation);
+
+        result
+    }
 }
 
 
@@ -0,0 +1,147 @@
+use cglinalg_core::Vector2;
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A low-discrepancy sequence of sub-pixel jitter offsets.
+///
+/// Temporal anti-aliasing, temporal upscaling, and checkerboard rendering offset
+/// the projection transformation by a different sub-pixel amount every frame, so
+/// that the samples of successive frames cover each pixel evenly. A jitter sequence
+/// generates these offsets. Each sample is an offset in pixel units lying in the
+/// square `[-1/2, 1/2) x [-1/2, 1/2)` centered on the pixel center. Pass the samples
+/// to [`Perspective3::with_jitter`] or [`Orthographic3::with_jitter`] to offset a
+/// projection.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::Vector2;
+/// # use cglinalg_transform::JitterSequence;
+/// #
+/// let sequence = JitterSequence::Halton23;
+/// let expected = [
+///     Vector2::new(0_f64,          -1_f64 / 6_f64),
+///     Vector2::new(-1_f64 / 4_f64,  1_f64 / 6_f64),
+///     Vector2::new(1_f64 / 4_f64,  -7_f64 / 18_f64),
+///     Vector2::new(-3_f64 / 8_f64, -1_f64 / 18_f64),
+/// ];
+///
+/// for i in 0..4 {
+///     let result: Vector2<f64> = sequence.sample(i);
+///
+///     assert_relative_eq!(result, expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// }
+/// ```
+///
+/// [`Perspective3::with_jitter`]: crate::Perspective3::with_jitter
+/// [`Orthographic3::with_jitter`]: crate::Orthographic3::with_jitter
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JitterSequence {
+    /// The two dimensional Halton sequence with bases two and three.
+    ///
+    /// The sample with index `i` is the Halton point with index `i + 1`, which
+    /// skips the degenerate first point of the sequence that lies on a corner of
+    /// the pixel.
+    Halton23,
+    /// The two dimensional R2 sequence of Roberts, a Kronecker sequence based on
+    /// the plastic number.
+    ///
+    /// The sample with index `i` is the point `fract(1/2 + i * alpha)` with
+    /// `alpha == [1 / g, 1 / g^2]`, where `g` is the plastic number. The first
+    /// sample lies at the pixel center.
+    R2,
+}
+
+impl JitterSequence {
+    /// Get the sample with index `index` of the jitter sequence.
+    ///
+    /// The sample is an offset in pixel units lying in the square
+    /// `[-1/2, 1/2) x [-1/2, 1/2)`. Renderers typically cycle through the first
+    /// few samples, for instance by passing `frame_index % 8` as the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector2;
+    /// # use cglinalg_transform::JitterSequence;
+    /// #
+    /// let sequence = JitterSequence::R2;
+    /// let alpha_x = 0.7548776662466927_f64;
+    /// let alpha_y = 0.5698402909980532_f64;
+    /// let expected = Vector2::new((0.5_f64 + alpha_x).fract() - 0.5_f64, (0.5_f64 + alpha_y).fract() - 0.5_f64);
+    /// let result: Vector2<f64> = sequence.sample(1);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// for i in 0..64 {
+    ///     let sample: Vector2<f64> = sequence.sample(i);
+    ///
+    ///     assert!(sample.x >= -0.5_f64 && sample.x < 0.5_f64);
+    ///     assert!(sample.y >= -0.5_f64 && sample.y < 0.5_f64);
+    /// }
+    /// ```
+    pub fn sample<S>(&self, index: usize) -> Vector2<S>
+    where
+        S: SimdScalarFloat,
+    {
+        let one_half = S::one() / (S::one() + S::one());
+        match *self {
+            JitterSequence::Halton23 => {
+                let x = radical_inverse::<S>(index + 1, 2);
+                let y = radical_inverse::<S>(index + 1, 3);
+
+                Vector2::new(x - one_half, y - one_half)
+            }
+            JitterSequence::R2 => {
+                // The plastic number `g` is the unique real root of `x^3 == x + 1`.
+                let g: S = cglinalg_numeric::cast(1.324_717_957_244_746_f64);
+                let alpha_x = S::one() / g;
+                let alpha_y = alpha_x * alpha_x;
+                let n: S = cglinalg_numeric::cast(index);
+                let x = (one_half + n * alpha_x).fract();
+                let y = (one_half + n * alpha_y).fract();
+
+                Vector2::new(x - one_half, y - one_half)
+            }
+        }
+    }
+}
+
+impl fmt::Display for JitterSequence {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JitterSequence::Halton23 => write!(formatter, "Halton(2, 3)"),
+            JitterSequence::R2 => write!(formatter, "R2"),
+        }
+    }
+}
+
+/// Compute the radical inverse of `index` in the base `base`.
+///
+/// The radical inverse mirrors the digits of `index` in base `base` about the
+/// radix point, so that `index == d[k] ... d[1] d[0]` maps to
+/// `0.d[0] d[1] ... d[k]`. It is the `index`-th element of the van der Corput
+/// sequence in the base `base`.
+#[inline]
+fn radical_inverse<S>(index: usize, base: usize) -> S
+where
+    S: SimdScalarFloat,
+{
+    let base_scalar: S = cglinalg_numeric::cast(base);
+    let inverse_base = S::one() / base_scalar;
+    let mut remaining = index;
+    let mut fraction = inverse_base;
+    let mut result = S::zero();
+    while remaining > 0 {
+        let digit: S = cglinalg_numeric::cast(remaining % base);
+        result += digit * fraction;
+        fraction *= inverse_base;
+        remaining /= base;
+    }
+
+    result
+}
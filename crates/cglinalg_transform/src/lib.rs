@@ -1,5 +1,10 @@
+mod cascade;
+mod cubemap;
+mod frustum;
 mod isometry;
+mod jitter;
 mod projection;
+mod ray;
 mod reflection;
 mod rotation;
 mod scale;
@@ -7,12 +12,19 @@ mod shear;
 mod similarity;
 mod transform;
 mod translation;
+mod viewport;
 
 mod isometry_ops;
+mod lie;
 
 
+pub use cascade::*;
+pub use cubemap::*;
+pub use frustum::*;
 pub use isometry::*;
+pub use jitter::*;
 pub use projection::*;
+pub use ray::*;
 pub use reflection::*;
 pub use rotation::*;
 pub use scale::*;
@@ -20,5 +32,6 @@ pub use shear::*;
 pub use similarity::*;
 pub use transform::*;
 pub use translation::*;
+pub use viewport::*;
 
 // pub use isometry_ops::*;
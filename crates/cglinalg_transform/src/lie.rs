@@ -0,0 +1,396 @@
+use cglinalg_core::{
+    Matrix,
+    Matrix3x3,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The squared angle below which the closed-form coefficients of the exponential
+/// maps are replaced with their Taylor expansions.
+///
+/// The closed forms lose accuracy to cancellation as the angle goes to zero,
+/// but the loss is recovered by the powers of the rotation vector that multiply
+/// each coefficient. Above this threshold the cancellation error is negligible,
+/// and below it three terms of each Taylor series are exact to working precision.
+#[inline]
+fn small_angle_threshold<S>() -> S
+where
+    S: SimdScalarFloat,
+{
+    S::default_epsilon().sqrt().sqrt()
+}
+
+/// Evaluate the truncated series `c0 + c1 * x + c2 * x^2`.
+#[inline]
+fn taylor<S>(x: S, coefficients: [f64; 3]) -> S
+where
+    S: SimdScalarFloat,
+{
+    let c0: S = cglinalg_numeric::cast(coefficients[0]);
+    let c1: S = cglinalg_numeric::cast(coefficients[1]);
+    let c2: S = cglinalg_numeric::cast(coefficients[2]);
+
+    c0 + x * (c1 + x * c2)
+}
+
+/// Compute the coefficients `(1 - cos(theta)) / theta^2` and
+/// `(theta - sin(theta)) / theta^3` of the left Jacobian of **SO(3)**.
+#[inline]
+fn so3_coefficients<S>(theta_squared: S) -> (S, S)
+where
+    S: SimdScalarFloat,
+{
+    if theta_squared < small_angle_threshold() {
+        let beta = taylor(theta_squared, [1_f64 / 2_f64, -1_f64 / 24_f64, 1_f64 / 720_f64]);
+        let gamma = taylor(theta_squared, [1_f64 / 6_f64, -1_f64 / 120_f64, 1_f64 / 5040_f64]);
+
+        (beta, gamma)
+    } else {
+        let two = S::one() + S::one();
+        let theta = theta_squared.sqrt();
+        let sin_half_theta = (theta / two).sin();
+        let beta = two * sin_half_theta * sin_half_theta / theta_squared;
+        let gamma = (theta - theta.sin()) / (theta_squared * theta);
+
+        (beta, gamma)
+    }
+}
+
+/// Compute the coefficient `1 / theta^2 - (1 + cos(theta)) / (2 * theta * sin(theta))`
+/// of the inverse left Jacobian of **SO(3)**.
+#[inline]
+fn so3_inverse_coefficient<S>(theta_squared: S) -> S
+where
+    S: SimdScalarFloat,
+{
+    if theta_squared < small_angle_threshold() {
+        taylor(theta_squared, [1_f64 / 12_f64, 1_f64 / 720_f64, 1_f64 / 30240_f64])
+    } else {
+        let two = S::one() + S::one();
+        let half_theta = theta_squared.sqrt() / two;
+
+        (S::one() - half_theta * half_theta.cos() / half_theta.sin()) / theta_squared
+    }
+}
+
+/// Compute the left Jacobian of **SO(3)** at the rotation vector `phi`.
+#[inline]
+pub(crate) fn so3_left_jacobian<S>(phi: &Vector3<S>) -> Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    let (beta, gamma) = so3_coefficients(phi.norm_squared());
+    let phi_hat = Matrix3x3::cross_matrix(phi);
+
+    Matrix3x3::identity() + phi_hat * beta + (phi_hat * phi_hat) * gamma
+}
+
+/// Compute the inverse of the left Jacobian of **SO(3)** at the rotation vector `phi`.
+#[inline]
+pub(crate) fn so3_left_jacobian_inverse<S>(phi: &Vector3<S>) -> Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    let one_half = S::one() / (S::one() + S::one());
+    let delta = so3_inverse_coefficient(phi.norm_squared());
+    let phi_hat = Matrix3x3::cross_matrix(phi);
+
+    Matrix3x3::identity() - phi_hat * one_half + (phi_hat * phi_hat) * delta
+}
+
+/// Compute the upper right block `Q` of the left Jacobian of **SE(3)** at the
+/// twist with translational part `rho` and rotational part `phi`.
+#[inline]
+pub(crate) fn se3_left_jacobian_q<S>(rho: &Vector3<S>, phi: &Vector3<S>) -> Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    let theta_squared = phi.norm_squared();
+    let (_, c1) = so3_coefficients(theta_squared);
+    let (c2, c3) = if theta_squared < small_angle_threshold() {
+        let c2 = taylor(theta_squared, [1_f64 / 24_f64, -1_f64 / 720_f64, 1_f64 / 40320_f64]);
+        let c3 = taylor(theta_squared, [1_f64 / 120_f64, -1_f64 / 2520_f64, 1_f64 / 120960_f64]);
+
+        (c2, c3)
+    } else {
+        let one = S::one();
+        let two = one + one;
+        let three = two + one;
+        let theta = theta_squared.sqrt();
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let theta_fourth = theta_squared * theta_squared;
+        let c2 = (theta_squared + two * cos_theta - two) / (two * theta_fourth);
+        let c3 = (two * theta - three * sin_theta + theta * cos_theta) / (two * theta_fourth * theta);
+
+        (c2, c3)
+    };
+    let one_half = S::one() / (S::one() + S::one());
+    let three = S::one() + S::one() + S::one();
+    let rho_hat = Matrix3x3::cross_matrix(rho);
+    let phi_hat = Matrix3x3::cross_matrix(phi);
+    let phi_rho = phi_hat * rho_hat;
+    let rho_phi = rho_hat * phi_hat;
+    let phi_rho_phi = phi_rho * phi_hat;
+    let phi_phi_rho = phi_hat * phi_rho;
+    let rho_phi_phi = rho_phi * phi_hat;
+
+    rho_hat * one_half
+        + (phi_rho + rho_phi + phi_rho_phi) * c1
+        + (phi_phi_rho + rho_phi_phi - phi_rho_phi * three) * c2
+        + (phi_rho_phi * phi_hat + phi_hat * phi_rho_phi) * c3
+}
+
+/// Compute the integral of `u^k * exp(sigma * u)` over the unit interval.
+#[inline]
+fn exp_moment<S>(k: usize, sigma: S) -> S
+where
+    S: SimdScalarFloat,
+{
+    const MAX_TERMS: usize = 64;
+
+    let two = S::one() + S::one();
+    if sigma.abs() <= two {
+        // The power series converges quickly here, and unlike the recurrence
+        // below, it does not lose precision as `sigma` goes to zero.
+        let k_plus_one: S = cglinalg_numeric::cast(k + 1);
+        let mut power = S::one();
+        let mut sum = S::one() / k_plus_one;
+        for m in 1..MAX_TERMS {
+            let m_s: S = cglinalg_numeric::cast(m);
+            power = power * sigma / m_s;
+            let term = power / (k_plus_one + m_s);
+            sum += term;
+            if term.abs() <= S::default_epsilon() * sum.abs() {
+                break;
+            }
+        }
+
+        sum
+    } else {
+        let exp_sigma = sigma.exp();
+        let mut moment = sigma.exp_m1() / sigma;
+        for j in 1..=k {
+            let j_s: S = cglinalg_numeric::cast(j);
+            moment = (exp_sigma - j_s * moment) / sigma;
+        }
+
+        moment
+    }
+}
+
+/// Compute the coefficients `(a, b, c)` of the matrix
+/// `W == a * I + b * phi^ + c * phi^ * phi^` of **Sim(3)**, where `theta_squared`
+/// is the squared norm of the rotation vector `phi`.
+#[inline]
+fn sim3_coefficients<S>(theta_squared: S, sigma: S) -> (S, S, S)
+where
+    S: SimdScalarFloat,
+{
+    let a = exp_moment(0, sigma);
+    if theta_squared < small_angle_threshold() {
+        let one = S::one();
+        let two = one + one;
+        let six = two + two + two;
+        let twenty_four = six + six + six + six;
+        let one_hundred_twenty = twenty_four * (two + two + one);
+        let seven_hundred_twenty = one_hundred_twenty * six;
+        let theta_fourth = theta_squared * theta_squared;
+        let b =
+            exp_moment(1, sigma) - theta_squared * exp_moment(3, sigma) / six + theta_fourth * exp_moment(5, sigma) / one_hundred_twenty;
+        let c = exp_moment(2, sigma) / two - theta_squared * exp_moment(4, sigma) / twenty_four
+            + theta_fourth * exp_moment(6, sigma) / seven_hundred_twenty;
+
+        (a, b, c)
+    } else {
+        let theta = theta_squared.sqrt();
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let exp_sigma = sigma.exp();
+        let denominator = sigma * sigma + theta_squared;
+        let real = (sigma * (exp_sigma * cos_theta - S::one()) + theta * exp_sigma * sin_theta) / denominator;
+        let imaginary = (sigma * exp_sigma * sin_theta - theta * (exp_sigma * cos_theta - S::one())) / denominator;
+        let b = imaginary / theta;
+        let c = (a - real) / theta_squared;
+
+        (a, b, c)
+    }
+}
+
+/// Compute the matrix `W` relating the translational part of a **Sim(3)**
+/// tangent vector to the translation of its exponential.
+///
+/// This is the integral of `exp(u * (phi^ + sigma * I))` over the unit interval.
+#[inline]
+pub(crate) fn sim3_w<S>(phi: &Vector3<S>, sigma: S) -> Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    let (a, b, c) = sim3_coefficients(phi.norm_squared(), sigma);
+    let phi_hat = Matrix3x3::cross_matrix(phi);
+
+    Matrix3x3::identity() * a + phi_hat * b + (phi_hat * phi_hat) * c
+}
+
+/// Compute the inverse of the matrix `W` of **Sim(3)**.
+///
+/// The inverse has the same form `alpha * I + beta * phi^ + gamma * phi^ * phi^`
+/// as `W` itself. It exists unless `sigma == 0` and the rotation angle is a
+/// nonzero multiple of `2 * pi`.
+#[inline]
+pub(crate) fn sim3_w_inverse<S>(phi: &Vector3<S>, sigma: S) -> Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    let theta_squared = phi.norm_squared();
+    let (a, b, c) = sim3_coefficients(theta_squared, sigma);
+    // The eigenvalues of `W` in the plane orthogonal to `phi` are `d +/- i * theta * b`.
+    let d = a - theta_squared * c;
+    let determinant = d * d + theta_squared * b * b;
+    let beta = -b / determinant;
+    let gamma = (b * b - c * d) / (a * determinant);
+    let phi_hat = Matrix3x3::cross_matrix(phi);
+
+    Matrix3x3::identity() / a + phi_hat * beta + (phi_hat * phi_hat) * gamma
+}
+
+/// Compute the integrals of `exp(z * u)` and `u * exp(z * u)` over the unit
+/// interval, where `z == sigma + i * omega`, as pairs of real and imaginary parts.
+#[inline]
+fn complex_exp_moments<S>(sigma: S, omega: S) -> ((S, S), (S, S))
+where
+    S: SimdScalarFloat,
+{
+    let two = S::one() + S::one();
+    let (sin_omega, cos_omega) = omega.sin_cos();
+    let sin_half_omega = (omega / two).sin();
+    let exp_sigma = sigma.exp();
+    let denominator = sigma * sigma + omega * omega;
+    let divide = |real: S, imaginary: S| {
+        (
+            (real * sigma + imaginary * omega) / denominator,
+            (imaginary * sigma - real * omega) / denominator,
+        )
+    };
+    // Evaluate `exp(z) - 1` without cancellation for small `z`.
+    let exp_m1_real = sigma.exp_m1() * cos_omega - two * sin_half_omega * sin_half_omega;
+    let exp_imaginary = exp_sigma * sin_omega;
+    let (moment0_real, moment0_imaginary) = divide(exp_m1_real, exp_imaginary);
+    let moment1 = divide(exp_sigma * cos_omega - moment0_real, exp_imaginary - moment0_imaginary);
+
+    ((moment0_real, moment0_imaginary), moment1)
+}
+
+/// Evaluate the truncated series `c0 * m(k) + c1 * theta^2 * m(k + 2) + c2 * theta^4 * m(k + 4)`,
+/// where `m(k)` is the integral of `u^k * exp(sigma * u)` over the unit interval.
+#[inline]
+fn moment_series<S>(k: usize, sigma: S, theta_squared: S, coefficients: [f64; 3]) -> S
+where
+    S: SimdScalarFloat,
+{
+    let c0: S = cglinalg_numeric::cast(coefficients[0]);
+    let c1: S = cglinalg_numeric::cast(coefficients[1]);
+    let c2: S = cglinalg_numeric::cast(coefficients[2]);
+
+    c0 * exp_moment(k, sigma) + theta_squared * (c1 * exp_moment(k + 2, sigma) + theta_squared * c2 * exp_moment(k + 4, sigma))
+}
+
+/// Compute the blocks of the left Jacobian of **Sim(3)** coupling the
+/// translational part `rho` to the rotational part `phi` and to the log-scale
+/// `sigma` of a tangent vector.
+///
+/// The left Jacobian is the integral of `Ad(exp(u * xi))` over the unit
+/// interval, which is the block matrix `[[W, Q, v], [0, J, 0], [0, 0, 1]]`,
+/// where `W` is the matrix returned by [`sim3_w`] and `J` is the left Jacobian
+/// of **SO(3)**. This function returns the upper right blocks `(Q, v)`.
+/// Writing `R(u) == exp(u * phi^)` and `t(u)` for the translation of
+/// `exp(u * xi)`, they are
+/// ```text
+/// Q == integral(t(u)^ * R(u)) == (W * rho)^ * J - integral(exp(sigma * u) * R(u) * rho^ * transpose(G(u)))
+/// v == -integral(t(u)) == (integral(u * exp(sigma * u) * R(u)) - W) * rho
+/// ```
+/// where `G(u)` is the integral of `R` from `0` to `u`. The integrands expand into
+/// powers of `phi^` with coefficients that integrate in closed form.
+#[inline]
+pub(crate) fn sim3_left_jacobian_coupling<S>(rho: &Vector3<S>, phi: &Vector3<S>, sigma: S) -> (Matrix3x3<S>, Vector3<S>)
+where
+    S: SimdScalarFloat,
+{
+    // With `x == theta * u`, write `R(u) == I + a * phi^ + b * phi^ * phi^` and
+    // `G(u) == u * I + b * phi^ + g * phi^ * phi^`, where
+    // `a == sin(x) / theta`, `b == (1 - cos(x)) / theta^2`, and `g == (x - sin(x)) / theta^3`.
+    // Each coefficient below is the integral of `exp(sigma * u)` times the
+    // product in its name.
+    let theta_squared = phi.norm_squared();
+    let moment0 = exp_moment(0, sigma);
+    let moment1 = exp_moment(1, sigma);
+    let (a_u, b_u, b, g, a_b, a_g, b_b, b_g) = if theta_squared < small_angle_threshold() {
+        let series = |k, coefficients| moment_series(k, sigma, theta_squared, coefficients);
+        let a_u = series(2, [1_f64, -1_f64 / 6_f64, 1_f64 / 120_f64]);
+        let b_u = series(3, [1_f64 / 2_f64, -1_f64 / 24_f64, 1_f64 / 720_f64]);
+        let b = series(2, [1_f64 / 2_f64, -1_f64 / 24_f64, 1_f64 / 720_f64]);
+        let g = series(3, [1_f64 / 6_f64, -1_f64 / 120_f64, 1_f64 / 5040_f64]);
+        let a_b = series(3, [1_f64 / 2_f64, -1_f64 / 8_f64, 1_f64 / 80_f64]);
+        let a_g = series(4, [1_f64 / 6_f64, -13_f64 / 360_f64, 1_f64 / 336_f64]);
+        let b_b = series(4, [1_f64 / 4_f64, -1_f64 / 24_f64, 1_f64 / 320_f64]);
+        let b_g = series(5, [1_f64 / 12_f64, -1_f64 / 90_f64, 41_f64 / 60480_f64]);
+
+        (a_u, b_u, b, g, a_b, a_g, b_b, b_g)
+    } else {
+        let one_half = S::one() / (S::one() + S::one());
+        let three_halves = one_half + S::one();
+        let theta = theta_squared.sqrt();
+        let theta_fourth = theta_squared * theta_squared;
+        let ((cos0, sin0), (cos1, sin1)) = complex_exp_moments(sigma, theta);
+        let ((cos0_double, sin0_double), _) = complex_exp_moments(sigma, theta + theta);
+        let a_u = sin1 / theta;
+        let b_u = (moment1 - cos1) / theta_squared;
+        let b = (moment0 - cos0) / theta_squared;
+        let g = (theta * moment1 - sin0) / (theta_squared * theta);
+        let a_b = (sin0 - sin0_double * one_half) / (theta_squared * theta);
+        let a_g = (theta * sin1 - (moment0 - cos0_double) * one_half) / theta_fourth;
+        let b_b = (moment0 * three_halves - (cos0 + cos0) + cos0_double * one_half) / theta_fourth;
+        let b_g = (theta * (moment1 - cos1) - sin0 + sin0_double * one_half) / (theta_fourth * theta);
+
+        (a_u, b_u, b, g, a_b, a_g, b_b, b_g)
+    };
+    let w = sim3_w(phi, sigma);
+    let jacobian = so3_left_jacobian(phi);
+    let translation = w * rho;
+    let phi_hat = Matrix3x3::cross_matrix(phi);
+    let phi_hat_squared = phi_hat * phi_hat;
+    let rho_hat = Matrix3x3::cross_matrix(rho);
+    let phi_rho = phi_hat * rho_hat;
+    let phi_phi_rho = phi_hat_squared * rho_hat;
+    // Group the terms of the integrand by the power of `phi^` on each side of `rho^`.
+    let right_factor = |c0: S, c1: S, c2: S| Matrix3x3::identity() * c0 - phi_hat * c1 + phi_hat_squared * c2;
+    let integral =
+        rho_hat * right_factor(moment1, b, g) + phi_rho * right_factor(a_u, a_b, a_g) + phi_phi_rho * right_factor(b_u, b_b, b_g);
+    let q = Matrix3x3::cross_matrix(&translation) * jacobian - integral;
+    let v = (Matrix3x3::identity() * moment1 + phi_hat * a_u + phi_hat_squared * b_u) * rho - translation;
+
+    (q, v)
+}
+
+/// Split a tangent vector into three-dimensional parts starting at `offset`.
+#[inline]
+pub(crate) fn sub_vector3<S, const N: usize>(vector: &Vector<S, N>, offset: usize) -> Vector3<S>
+where
+    S: SimdScalarFloat,
+{
+    Vector3::new(vector[offset], vector[offset + 1], vector[offset + 2])
+}
+
+/// Write a three-by-three block into a matrix with its upper left corner
+/// at `(row, column)`.
+#[inline]
+pub(crate) fn set_block<S, const N: usize>(matrix: &mut Matrix<S, N, N>, row: usize, column: usize, block: &Matrix3x3<S>)
+where
+    S: SimdScalarFloat,
+{
+    for c in 0..3 {
+        for r in 0..3 {
+            matrix[column + c][row + r] = block[c][r];
+        }
+    }
+}
@@ -1,17 +1,207 @@
 use crate::transform::Transform3;
 use cglinalg_core::{
+    Matrix3x3,
     Matrix4x4,
+    NdcConvention,
     Point3,
     Unit,
+    Vector2,
     Vector3,
+    Vector4,
 };
 use cglinalg_numeric::SimdScalarFloat;
-use cglinalg_trigonometry::Radians;
+use cglinalg_trigonometry::{
+    Angle,
+    Radians,
+};
 
 use core::fmt;
 use core::ops;
 
 
+/// Express the matrix of a projection transformation for the normalized device
+/// coordinates convention `ndc` in OpenGL's normalized device coordinates convention.
+///
+/// Let `g := ndc.gaze_sign()`, `s := ndc.y_axis_sign()`, and let
+/// `(z_near, z_far) := depth_bounds` be the depths in normalized device coordinates
+/// that the **near plane** and the **far plane** map to. These are the depth bounds
+/// of `ndc` for a standard depth mapping, and the reverse of them for a reversed-z
+/// depth mapping. The matrix `m` of a projection for the
+/// convention `ndc` and the matrix `m_gl` of the same projection for OpenGL's
+/// convention satisfy
+///
+/// ```text
+/// m == C * m_gl * F
+/// ```
+///
+/// where `F := diag(1, 1, -g, 1)` flips the **z-axis** of a left-handed view space
+/// into a right-handed one, and
+///
+/// ```text
+///      [ 1  0  0  0 ]
+/// C := [ 0  s  0  0 ]
+///      [ 0  0  a  b ]
+///      [ 0  0  0  1 ]
+/// ```
+///
+/// with `a := (z_far - z_near) / 2` and `b := (z_far + z_near) / 2` maps OpenGL's
+/// canonical view volume onto the canonical view volume of `ndc`. The projection
+/// parameters are recovered from the entries of `m_gl == C^-1 * m * F^-1`.
+#[inline]
+fn matrix_to_opengl<S>(ndc: &NdcConvention, depth_bounds: (S, S), matrix: &Matrix4x4<S>) -> Matrix4x4<S>
+where
+    S: SimdScalarFloat,
+{
+    let one = S::one();
+    let two = one + one;
+    let gaze_sign = ndc.gaze_sign::<S>();
+    let y_axis_sign = ndc.y_axis_sign::<S>();
+    let (z_near, z_far) = depth_bounds;
+    let depth_scale = (z_far - z_near) / two;
+    let depth_offset = (z_far + z_near) / two;
+    let mut result = *matrix;
+    for c in 0..4 {
+        result[c][1] = y_axis_sign * matrix[c][1];
+        result[c][2] = (matrix[c][2] - depth_offset * matrix[c][3]) / depth_scale;
+    }
+
+    for r in 0..4 {
+        result[2][r] = -gaze_sign * result[2][r];
+    }
+
+    result
+}
+
+/// Map a point in the canonical view volume of the normalized device coordinates
+/// convention `ndc` to the corresponding point in OpenGL's canonical view volume.
+///
+/// The depths `depth_bounds` are the depths in normalized device coordinates
+/// that the **near plane** and the **far plane** map to, as in [`matrix_to_opengl`].
+#[inline]
+fn ndc_to_opengl<S>(ndc: &NdcConvention, depth_bounds: (S, S), x: S, y: S, z: S) -> Vector3<S>
+where
+    S: SimdScalarFloat,
+{
+    let one = S::one();
+    let two = one + one;
+    let (z_near, z_far) = depth_bounds;
+    let depth_scale = (z_far - z_near) / two;
+    let depth_offset = (z_far + z_near) / two;
+
+    Vector3::new(x, ndc.y_axis_sign::<S>() * y, (z - depth_offset) / depth_scale)
+}
+
+/// Offset the frustum parameters `left`, `right`, `bottom`, and `top` of a
+/// projection so that its image shifts by the sub-pixel offset `jitter` in a
+/// viewport of `viewport_width` by `viewport_height` pixels.
+///
+/// A viewport `viewport_width` pixels wide spans two units of normalized device
+/// coordinates, so shifting the image `jitter.x` pixels to the right shifts it
+/// `dx := 2 * jitter.x / viewport_width` units in normalized device coordinates.
+/// The horizontal coordinate in normalized device coordinates depends on the
+/// horizontal frustum parameters through
+///
+/// ```text
+/// x_ndc == (2 / (right + left)) * x_near - (right - left) / (right + left)
+/// ```
+///
+/// where `x_near` is the horizontal position on the near plane for a perspective
+/// projection, and on any plane for an orthographic projection. Holding the width
+/// `right + left` fixed, `x_ndc` increases by `dx` exactly when `right` decreases
+/// and `left` increases by `dx * (right + left) / 2`. The vertical parameters
+/// behave the same way.
+#[inline]
+fn jitter_extents<S>(left: S, right: S, bottom: S, top: S, jitter: &Vector2<S>, viewport_width: S, viewport_height: S) -> (S, S, S, S)
+where
+    S: SimdScalarFloat,
+{
+    let shift_x = (jitter.x / viewport_width) * (right + left);
+    let shift_y = (jitter.y / viewport_height) * (top + bottom);
+
+    (left + shift_x, right - shift_x, bottom + shift_y, top - shift_y)
+}
+
+
+/// The field of view of an asymmetric viewing frustum, described by the angles
+/// of its four side planes.
+///
+/// This is the description of the field of view of each eye that OpenXR and
+/// OpenVR use. Each angle is measured from the gaze direction of the camera
+/// to a side plane of the viewing frustum, with positive angles pointing right
+/// and up. For a viewing frustum containing the gaze direction, `left` and `down`
+/// are negative, and `right` and `up` are positive.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::FovAngles;
+/// # use cglinalg_trigonometry::Degrees;
+/// #
+/// let fov = FovAngles::new(Degrees(-45_f64), Degrees(40_f64), Degrees(42_f64), Degrees(-48_f64));
+///
+/// assert_eq!(fov.left, Degrees(-45_f64).into());
+/// assert_eq!(fov.right, Degrees(40_f64).into());
+/// assert_eq!(fov.up, Degrees(42_f64).into());
+/// assert_eq!(fov.down, Degrees(-48_f64).into());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FovAngles<S> {
+    /// The angle of the **left plane** from the gaze direction.
+    pub left: Radians<S>,
+    /// The angle of the **right plane** from the gaze direction.
+    pub right: Radians<S>,
+    /// The angle of the **top plane** from the gaze direction.
+    pub up: Radians<S>,
+    /// The angle of the **bottom plane** from the gaze direction.
+    pub down: Radians<S>,
+}
+
+impl<S> FovAngles<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new set of field of view angles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::FovAngles;
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let fov = FovAngles::new(Radians(-0.8_f64), Radians(0.7_f64), Radians(0.75_f64), Radians(-0.85_f64));
+    ///
+    /// assert_eq!(fov.left, Radians(-0.8_f64));
+    /// assert_eq!(fov.right, Radians(0.7_f64));
+    /// assert_eq!(fov.up, Radians(0.75_f64));
+    /// assert_eq!(fov.down, Radians(-0.85_f64));
+    /// ```
+    #[inline]
+    pub fn new<A>(left: A, right: A, up: A, down: A) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        Self {
+            left: left.into(),
+            right: right.into(),
+            up: up.into(),
+            down: down.into(),
+        }
+    }
+}
+
+impl<S> fmt::Display for FovAngles<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "FovAngles [left={}, right={}, up={}, down={}]",
+            self.left, self.right, self.up, self.down
+        )
+    }
+}
+
 /// A perspective projection transformation.
 ///
 /// The data type represents a perspective projection transformation that follows
@@ -140,9 +330,45 @@ use core::ops;
 /// ```
 ///
 /// where the matrix entries are indexed in column-major order.
+///
+/// # Normalized Device Coordinates Conventions
+///
+/// The description above is the default OpenGL convention. The constructors
+/// [`Perspective3::new_with_ndc`] and [`Perspective3::from_vfov_with_ndc`] construct
+/// a perspective projection for any [`NdcConvention`] instead, such as the ones that
+/// Vulkan, Direct3D, Metal, and WebGPU use. The convention selects the handedness of
+/// the view space, the depth range of the canonical view volume, and the direction of
+/// its **y-axis**. The frustum parameters are positive distances in every convention.
+///
+/// # Reversed-Z And Infinite Far Plane Projections
+///
+/// A reversed-z perspective projection maps the **near plane** to the far end of the
+/// depth range of its convention, and the **far plane** to the near end. Combined
+/// with a floating point depth buffer, this spreads the depth precision much more
+/// evenly over the viewing frustum. A perspective projection may also have an
+/// infinitely distant **far plane**, in which case [`Perspective3::far`] returns
+/// infinity. The constructors [`Perspective3::new_reversed_z`],
+/// [`Perspective3::new_infinite`], and [`Perspective3::new_infinite_reversed_z`],
+/// and their field of view counterparts, construct these variants.
+///
+/// # Skewed Projections
+///
+/// A perspective projection constructed from a camera intrinsic matrix with a
+/// nonzero `skew`, such as with [`Perspective3::from_intrinsics`], has a viewing
+/// frustum that is sheared along the **x-axis**, and the entry `m[1, 0]` of its
+/// matrix is nonzero. The frustum parameters returned by [`Perspective3::left`],
+/// [`Perspective3::right`], [`Perspective3::bottom`], and [`Perspective3::top`],
+/// and the field of view parameters returned by [`Perspective3::vfov`],
+/// [`Perspective3::aspect_ratio`], and [`Perspective3::fov_angles`], describe the
+/// viewing frustum before the shear,
+/// and [`Perspective3::shear`] returns the shear itself.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Perspective3<S> {
     matrix: Matrix4x4<S>,
+    ndc: NdcConvention,
+    reversed_z: bool,
+    infinite_far: bool,
+    standard_depth: Option<(S, S)>,
 }
 
 impl<S> Perspective3<S>
@@ -305,6 +531,10 @@ where
     pub fn new(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Self {
         Self {
             matrix: Matrix4x4::from_perspective(left, right, bottom, top, near, far),
+            ndc: NdcConvention::OPENGL,
+            reversed_z: false,
+            infinite_far: false,
+            standard_depth: None,
         }
     }
 
@@ -568,34 +798,1204 @@ where
     /// m[3, 2] == -(2 * far * near) / (far - near)
     /// ```
     ///
-    /// where the matrix entries are indexed in column-major order.
+    /// where the matrix entries are indexed in column-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    ///
+    /// assert_relative_eq!(perspective.vfov(),         vfov.into(),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.near(),         near,         abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.far(),          far,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_vfov<A>(vfov: A, aspect_ratio: S, near: S, far: S) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let spec_vfov = vfov.into();
+
+        Self {
+            matrix: Matrix4x4::from_perspective_vfov(spec_vfov, aspect_ratio, near, far),
+            ndc: NdcConvention::OPENGL,
+            reversed_z: false,
+            infinite_far: false,
+            standard_depth: None,
+        }
+    }
+
+    /// Construct a new possibly asymmetric perspective projection transformation based
+    /// on the location of the **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, **near plane**, and **far plane**, for the normalized device
+    /// coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in [`Perspective3::new`],
+    /// except that the **near plane** and the **far plane** lie along the viewing
+    /// direction of the view space of `ndc`. See [`Matrix4x4::from_perspective_with_ndc`]
+    /// for the underlying matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// let left = 3_f64;
+    /// let right = 3_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::VULKAN);
+    ///
+    /// assert_eq!(perspective.ndc_convention(), NdcConvention::VULKAN);
+    /// assert_relative_eq!(perspective.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.near(),   near,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.far(),    far,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The near plane maps to depth zero, and the top plane maps to `y == -1`.
+    /// let point = Point3::new(0_f64, top, -near);
+    /// let expected = Point3::new(0_f64, -1_f64, 0_f64);
+    /// let result = perspective.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new_with_ndc(left: S, right: S, bottom: S, top: S, near: S, far: S, ndc: NdcConvention) -> Self {
+        Self::new_sheared(left, right, bottom, top, near, far, S::zero(), ndc, false, false)
+    }
+
+    /// Construct a perspective projection transformation based on the `near`
+    /// plane, the `far` plane and the vertical field of view angle `vfov` and
+    /// the horizontal/vertical aspect ratio `aspect_ratio`, for the normalized
+    /// device coordinates convention `ndc`.
+    ///
+    /// The field of view parameters have the same meaning as in
+    /// [`Perspective3::from_vfov`], except that the **near plane** and the
+    /// **far plane** lie along the viewing direction of the view space of `ndc`.
+    /// See [`Matrix4x4::from_perspective_vfov_with_ndc`] for the underlying matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::DIRECT3D);
+    ///
+    /// assert_relative_eq!(perspective.vfov(),         vfov.into(),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.near(),         near,         abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.far(),          far,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The view space is left-handed, so the far plane lies along the positive z-axis.
+    /// let point = Point3::new(0_f64, 0_f64, far);
+    /// let expected = Point3::new(0_f64, 0_f64, 1_f64);
+    /// let result = perspective.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_vfov_with_ndc<A>(vfov: A, aspect_ratio: S, near: S, far: S, ndc: NdcConvention) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        Self {
+            matrix: Matrix4x4::from_perspective_vfov_with_ndc(vfov, aspect_ratio, near, far, ndc),
+            ndc,
+            reversed_z: false,
+            infinite_far: false,
+            standard_depth: None,
+        }
+    }
+
+    /// Construct a new possibly asymmetric reversed-z perspective projection
+    /// transformation based on the location of the **left plane**, **right plane**,
+    /// **bottom plane**, **top plane**, **near plane**, and **far plane**, for the
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in [`Perspective3::new_with_ndc`],
+    /// except that the **near plane** maps to the far end of the depth range of `ndc`,
+    /// and the **far plane** maps to the near end. See
+    /// [`Matrix4x4::from_perspective_reversed_z_with_ndc`] for the underlying matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// let left = 3_f64;
+    /// let right = 3_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new_reversed_z(left, right, bottom, top, near, far, NdcConvention::VULKAN);
+    ///
+    /// assert!(perspective.is_reversed_z());
+    /// assert!(!perspective.is_infinite());
+    /// assert_relative_eq!(perspective.near(), near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.far(),  far,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The near plane maps to depth one, and the far plane maps to depth zero.
+    /// let near_point = Point3::new(0_f64, 0_f64, -near);
+    /// let far_point = Point3::new(0_f64, 0_f64, -far);
+    ///
+    /// assert_relative_eq!(perspective.project_point(&near_point).z, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.project_point(&far_point).z,  0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new_reversed_z(left: S, right: S, bottom: S, top: S, near: S, far: S, ndc: NdcConvention) -> Self {
+        Self::new_sheared(left, right, bottom, top, near, far, S::zero(), ndc, true, false)
+    }
+
+    /// Construct a new possibly asymmetric perspective projection transformation with
+    /// an infinitely distant **far plane**, based on the location of the **left plane**,
+    /// **right plane**, **bottom plane**, **top plane**, and **near plane**, for the
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in [`Perspective3::new_with_ndc`].
+    /// Points at infinity map to the far end of the depth range of `ndc`. See
+    /// [`Matrix4x4::from_perspective_infinite_with_ndc`] for the underlying matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// let left = 3_f64;
+    /// let right = 3_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let perspective = Perspective3::new_infinite(left, right, bottom, top, near, NdcConvention::OPENGL);
+    ///
+    /// assert!(perspective.is_infinite());
+    /// assert_relative_eq!(perspective.near(), near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(perspective.far(), f64::INFINITY);
+    ///
+    /// // The near plane maps to depth negative one.
+    /// let point = Point3::new(0_f64, 0_f64, -near);
+    ///
+    /// assert_relative_eq!(perspective.project_point(&point).z, -1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new_infinite(left: S, right: S, bottom: S, top: S, near: S, ndc: NdcConvention) -> Self {
+        Self::new_sheared(left, right, bottom, top, near, S::infinity(), S::zero(), ndc, false, true)
+    }
+
+    /// Construct a new possibly asymmetric reversed-z perspective projection
+    /// transformation with an infinitely distant **far plane**, based on the location
+    /// of the **left plane**, **right plane**, **bottom plane**, **top plane**, and
+    /// **near plane**, for the normalized device coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in [`Perspective3::new_with_ndc`].
+    /// The **near plane** maps to the far end of the depth range of `ndc`, and points
+    /// at infinity map to the near end. See
+    /// [`Matrix4x4::from_perspective_infinite_reversed_z_with_ndc`] for the underlying
+    /// matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// let left = 3_f64;
+    /// let right = 3_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 0.1_f64;
+    /// let perspective = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, NdcConvention::DIRECT3D);
+    ///
+    /// assert!(perspective.is_reversed_z());
+    /// assert!(perspective.is_infinite());
+    /// assert_relative_eq!(perspective.near(), near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(perspective.far(), f64::INFINITY);
+    ///
+    /// // The near plane maps to depth one.
+    /// let point = Point3::new(0_f64, 0_f64, near);
+    ///
+    /// assert_relative_eq!(perspective.project_point(&point).z, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new_infinite_reversed_z(left: S, right: S, bottom: S, top: S, near: S, ndc: NdcConvention) -> Self {
+        Self::new_sheared(left, right, bottom, top, near, S::infinity(), S::zero(), ndc, true, true)
+    }
+
+    /// Construct a reversed-z perspective projection transformation based on the
+    /// `near` plane, the `far` plane and the vertical field of view angle `vfov` and
+    /// the horizontal/vertical aspect ratio `aspect_ratio`, for the normalized device
+    /// coordinates convention `ndc`.
+    ///
+    /// The field of view parameters have the same meaning as in
+    /// [`Perspective3::from_vfov_with_ndc`], and the depth mapping is the same as in
+    /// [`Perspective3::new_reversed_z`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov_reversed_z(vfov, aspect_ratio, near, far, NdcConvention::WEBGPU);
+    ///
+    /// assert!(perspective.is_reversed_z());
+    /// assert_relative_eq!(perspective.vfov(),         vfov.into(),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.near(),         near,         abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.far(),          far,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_vfov_reversed_z<A>(vfov: A, aspect_ratio: S, near: S, far: S, ndc: NdcConvention) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let (right, top) = Self::vfov_extents(vfov, aspect_ratio, near);
+
+        Self::new_reversed_z(right, right, top, top, near, far, ndc)
+    }
+
+    /// Construct a perspective projection transformation with an infinitely distant
+    /// **far plane** based on the `near` plane and the vertical field of view angle
+    /// `vfov` and the horizontal/vertical aspect ratio `aspect_ratio`, for the
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The field of view parameters have the same meaning as in
+    /// [`Perspective3::from_vfov_with_ndc`], and the depth mapping is the same as in
+    /// [`Perspective3::new_infinite`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let perspective = Perspective3::from_vfov_infinite(vfov, aspect_ratio, near, NdcConvention::OPENGL);
+    ///
+    /// assert!(perspective.is_infinite());
+    /// assert_relative_eq!(perspective.vfov(),         vfov.into(),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.near(),         near,         abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(perspective.far(), f64::INFINITY);
+    /// ```
+    pub fn from_vfov_infinite<A>(vfov: A, aspect_ratio: S, near: S, ndc: NdcConvention) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let (right, top) = Self::vfov_extents(vfov, aspect_ratio, near);
+
+        Self::new_infinite(right, right, top, top, near, ndc)
+    }
+
+    /// Construct a reversed-z perspective projection transformation with an infinitely
+    /// distant **far plane** based on the `near` plane and the vertical field of view
+    /// angle `vfov` and the horizontal/vertical aspect ratio `aspect_ratio`, for the
+    /// normalized device coordinates convention `ndc`.
+    ///
+    /// The field of view parameters have the same meaning as in
+    /// [`Perspective3::from_vfov_with_ndc`], and the depth mapping is the same as in
+    /// [`Perspective3::new_infinite_reversed_z`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let perspective = Perspective3::from_vfov_infinite_reversed_z(vfov, aspect_ratio, near, NdcConvention::VULKAN);
+    ///
+    /// assert!(perspective.is_reversed_z());
+    /// assert!(perspective.is_infinite());
+    /// assert_relative_eq!(perspective.vfov(),         vfov.into(),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.near(),         near,         abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(perspective.far(), f64::INFINITY);
+    /// ```
+    pub fn from_vfov_infinite_reversed_z<A>(vfov: A, aspect_ratio: S, near: S, ndc: NdcConvention) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let (right, top) = Self::vfov_extents(vfov, aspect_ratio, near);
+
+        Self::new_infinite_reversed_z(right, right, top, top, near, ndc)
+    }
+
+    /// Construct a perspective projection transformation from the intrinsic matrix
+    /// of a calibrated pinhole camera, for the normalized device coordinates
+    /// convention `ndc`.
+    ///
+    /// # Parameters
+    ///
+    /// * `intrinsics` is the camera intrinsic matrix `K`.
+    /// * `image_width` is the width of the image in pixels.
+    /// * `image_height` is the height of the image in pixels.
+    /// * `near` is the distance of the **near plane** from the camera.
+    /// * `far` is the distance of the **far plane** from the camera.
+    ///
+    /// # Camera Intrinsics
+    ///
+    /// The intrinsic matrix has the form
+    /// ```text
+    /// [ fx   skew   cx ]
+    /// [ 0    fy     cy ]
+    /// [ 0    0      1  ]
+    /// ```
+    /// where `fx` and `fy` are the focal lengths in pixels, and `(cx, cy)` is the
+    /// principal point in pixels. The intrinsic matrix maps a point `(X, Y, Z)` in
+    /// the camera coordinates of computer vision, with the **x-axis** pointing
+    /// right, the **y-axis** pointing down, and the **z-axis** pointing forward,
+    /// to the pixel `(u, v) == (fx * X / Z + cx, fy * Y / Z + cy)`. The image spans
+    /// the rectangle `[0, image_width] x [0, image_height]` with its origin at the
+    /// **top left corner**, so the center of the pixel `(i, j)` lies at
+    /// `(i + 1/2, j + 1/2)`, which is the same convention as the window coordinates of
+    /// a [`Viewport`](crate::Viewport). Calibrations such as OpenCV's place pixel
+    /// centers at integer coordinates instead; add `1/2` to `cx` and `cy` to convert
+    /// such intrinsics before calling this function.
+    ///
+    /// The camera coordinates of computer vision are the view space of the
+    /// perspective projection with the **y-axis** and the gaze direction flipped
+    /// into place, so that the resulting projection followed by the viewport
+    /// transformation of an `image_width` by `image_height` viewport maps every
+    /// point in view space to the same pixel as the intrinsic matrix does. The
+    /// **near plane** parameters of the viewing frustum are given by
+    /// ```text
+    /// left   == near * cx / fx
+    /// right  == near * (image_width - cx) / fx
+    /// bottom == near * (image_height - cy) / fy
+    /// top    == near * cy / fy
+    /// ```
+    /// A nonzero `skew` shears the viewing frustum along the **x-axis** by
+    /// ```text
+    /// shear == skew / fx
+    /// ```
+    /// as described in [`Perspective3::shear`]. The shear is the same in every
+    /// normalized device coordinates convention. This function returns `None` when
+    /// either focal length is not positive, or when the intrinsic matrix is not upper
+    /// triangular with a last row of `[0, 0, 1]`. The functions
+    /// [`Perspective3::from_intrinsics_reversed_z`],
+    /// [`Perspective3::from_intrinsics_infinite`], and
+    /// [`Perspective3::from_intrinsics_infinite_reversed_z`] construct the other
+    /// depth mappings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Perspective3,
+    /// #     Viewport,
+    /// # };
+    /// #
+    /// let fx = 800_f64;
+    /// let fy = 800_f64;
+    /// let cx = 400_f64;
+    /// let cy = 300_f64;
+    /// let image_width = 800_f64;
+    /// let image_height = 600_f64;
+    /// #[rustfmt::skip]
+    /// let intrinsics = Matrix3x3::new(
+    ///     fx,    0_f64, 0_f64,
+    ///     0_f64, fy,    0_f64,
+    ///     cx,    cy,    1_f64,
+    /// );
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let ndc = NdcConvention::OPENGL;
+    /// let perspective = Perspective3::from_intrinsics(&intrinsics, image_width, image_height, near, far, ndc).unwrap();
+    ///
+    /// assert_relative_eq!(perspective.left(),   0.05_f64,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.right(),  0.05_f64,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.bottom(), 0.0375_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.top(),    0.0375_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The point `(1, 2, -10)` in view space is the point `(1, -2, 10)` in the
+    /// // camera coordinates of computer vision, which lands on the pixel `(480, 140)`.
+    /// let viewport = Viewport::new_with_ndc(0_f64, 0_f64, image_width, image_height, 0_f64, 1_f64, ndc);
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let window_point = viewport.ndc_to_window(&perspective.project_point(&point));
+    ///
+    /// assert_relative_eq!(window_point.x, 480_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(window_point.y, 140_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_intrinsics(
+        intrinsics: &Matrix3x3<S>,
+        image_width: S,
+        image_height: S,
+        near: S,
+        far: S,
+        ndc: NdcConvention,
+    ) -> Option<Self> {
+        let (left, right, bottom, top, shear) = Self::intrinsics_frustum(intrinsics, image_width, image_height, near)?;
+
+        Some(Self::new_sheared(left, right, bottom, top, near, far, shear, ndc, false, false))
+    }
+
+    /// Construct a reversed-z perspective projection transformation from the
+    /// intrinsic matrix of a calibrated pinhole camera, for the normalized device
+    /// coordinates convention `ndc`.
+    ///
+    /// The intrinsic matrix has the same meaning as in
+    /// [`Perspective3::from_intrinsics`], and the depth mapping is the same as in
+    /// [`Perspective3::new_reversed_z`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     NdcConvention,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// #[rustfmt::skip]
+    /// let intrinsics = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     4_f64,   800_f64, 0_f64,
+    ///     400_f64, 300_f64, 1_f64,
+    /// );
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let ndc = NdcConvention::VULKAN;
+    /// let perspective = Perspective3::from_intrinsics_reversed_z(&intrinsics, 800_f64, 600_f64, near, far, ndc).unwrap();
+    ///
+    /// assert!(perspective.is_reversed_z());
+    /// assert_relative_eq!(perspective.far(), far, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(
+    ///     perspective.to_intrinsics(800_f64, 600_f64),
+    ///     intrinsics,
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON
+    /// );
+    /// ```
+    pub fn from_intrinsics_reversed_z(
+        intrinsics: &Matrix3x3<S>,
+        image_width: S,
+        image_height: S,
+        near: S,
+        far: S,
+        ndc: NdcConvention,
+    ) -> Option<Self> {
+        let (left, right, bottom, top, shear) = Self::intrinsics_frustum(intrinsics, image_width, image_height, near)?;
+
+        Some(Self::new_sheared(left, right, bottom, top, near, far, shear, ndc, true, false))
+    }
+
+    /// Construct a perspective projection transformation with an infinitely distant
+    /// **far plane** from the intrinsic matrix of a calibrated pinhole camera, for
+    /// the normalized device coordinates convention `ndc`.
+    ///
+    /// The intrinsic matrix has the same meaning as in
+    /// [`Perspective3::from_intrinsics`], and the depth mapping is the same as in
+    /// [`Perspective3::new_infinite`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     NdcConvention,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// #[rustfmt::skip]
+    /// let intrinsics = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     4_f64,   800_f64, 0_f64,
+    ///     400_f64, 300_f64, 1_f64,
+    /// );
+    /// let near = 0.1_f64;
+    /// let ndc = NdcConvention::OPENGL;
+    /// let perspective = Perspective3::from_intrinsics_infinite(&intrinsics, 800_f64, 600_f64, near, ndc).unwrap();
+    ///
+    /// assert!(perspective.is_infinite());
+    /// assert_eq!(perspective.far(), f64::INFINITY);
+    /// assert_relative_eq!(
+    ///     perspective.to_intrinsics(800_f64, 600_f64),
+    ///     intrinsics,
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON
+    /// );
+    /// ```
+    pub fn from_intrinsics_infinite(
+        intrinsics: &Matrix3x3<S>,
+        image_width: S,
+        image_height: S,
+        near: S,
+        ndc: NdcConvention,
+    ) -> Option<Self> {
+        let (left, right, bottom, top, shear) = Self::intrinsics_frustum(intrinsics, image_width, image_height, near)?;
+        let far = S::infinity();
+
+        Some(Self::new_sheared(left, right, bottom, top, near, far, shear, ndc, false, true))
+    }
+
+    /// Construct a reversed-z perspective projection transformation with an
+    /// infinitely distant **far plane** from the intrinsic matrix of a calibrated
+    /// pinhole camera, for the normalized device coordinates convention `ndc`.
+    ///
+    /// The intrinsic matrix has the same meaning as in
+    /// [`Perspective3::from_intrinsics`], and the depth mapping is the same as in
+    /// [`Perspective3::new_infinite_reversed_z`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     NdcConvention,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// #[rustfmt::skip]
+    /// let intrinsics = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     4_f64,   800_f64, 0_f64,
+    ///     400_f64, 300_f64, 1_f64,
+    /// );
+    /// let near = 0.1_f64;
+    /// let ndc = NdcConvention::DIRECT3D;
+    /// let perspective = Perspective3::from_intrinsics_infinite_reversed_z(&intrinsics, 800_f64, 600_f64, near, ndc).unwrap();
+    ///
+    /// assert!(perspective.is_reversed_z());
+    /// assert!(perspective.is_infinite());
+    /// assert_relative_eq!(
+    ///     perspective.to_intrinsics(800_f64, 600_f64),
+    ///     intrinsics,
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON
+    /// );
+    /// ```
+    pub fn from_intrinsics_infinite_reversed_z(
+        intrinsics: &Matrix3x3<S>,
+        image_width: S,
+        image_height: S,
+        near: S,
+        ndc: NdcConvention,
+    ) -> Option<Self> {
+        let (left, right, bottom, top, shear) = Self::intrinsics_frustum(intrinsics, image_width, image_height, near)?;
+        let far = S::infinity();
+
+        Some(Self::new_sheared(left, right, bottom, top, near, far, shear, ndc, true, true))
+    }
+
+    /// Construct a perspective projection transformation from the angles of the
+    /// four side planes of its viewing frustum, for the normalized device
+    /// coordinates convention `ndc`.
+    ///
+    /// This is the way OpenXR and OpenVR describe the viewing frustum of each eye.
+    /// The angles `angle_left`, `angle_right`, `angle_up`, and `angle_down` are
+    /// measured from the gaze direction, with positive angles pointing right and
+    /// up, as described in [`FovAngles`]. Typically `angle_left` and `angle_down`
+    /// are negative. The **near plane** parameters of the viewing frustum are given by
+    /// ```text
+    /// left   == -near * tan(angle_left)
+    /// right  ==  near * tan(angle_right)
+    /// bottom == -near * tan(angle_down)
+    /// top    ==  near * tan(angle_up)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let angle_left = Degrees(-45_f64);
+    /// let angle_right = Degrees(30_f64);
+    /// let angle_up = Degrees(45_f64);
+    /// let angle_down = Degrees(-60_f64);
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let ndc = NdcConvention::VULKAN;
+    /// let perspective = Perspective3::from_fov_angles(angle_left, angle_right, angle_up, angle_down, near, far, ndc);
+    ///
+    /// assert_relative_eq!(perspective.left(),   0.1_f64,                     abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.right(),  0.1_f64 / f64::sqrt(3_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.bottom(), 0.1_f64 * f64::sqrt(3_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.top(),    0.1_f64,                     abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_fov_angles<A>(angle_left: A, angle_right: A, angle_up: A, angle_down: A, near: S, far: S, ndc: NdcConvention) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let left = -Angle::tan(angle_left.into()) * near;
+        let right = Angle::tan(angle_right.into()) * near;
+        let bottom = -Angle::tan(angle_down.into()) * near;
+        let top = Angle::tan(angle_up.into()) * near;
+
+        Self::new_with_ndc(left, right, bottom, top, near, far, ndc)
+    }
+
+    /// Construct the perspective projection transformation that renders a face
+    /// of a cubemap, for the normalized device coordinates convention `ndc`.
+    ///
+    /// The projection has a vertical field of view of ninety degrees, and an aspect
+    /// ratio of one, so that the viewing frustums of the six faces of a cubemap
+    /// cover all directions around its center without overlapping. Use it together
+    /// with the view transformations [`Isometry3::cubemap_face_views_rh`] or
+    /// [`Isometry3::cubemap_face_views_lh`], which describe the normalized device
+    /// coordinates convention each graphics API needs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::{
+    /// #     Degrees,
+    /// #     Radians,
+    /// # };
+    /// #
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new_cubemap(near, far, NdcConvention::DIRECT3D);
+    ///
+    /// assert_relative_eq!(perspective.vfov(), Radians::from(Degrees(90_f64)), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(perspective.left(), near);
+    /// assert_eq!(perspective.top(), near);
+    /// ```
+    ///
+    /// [`Isometry3::cubemap_face_views_rh`]: crate::Isometry3::cubemap_face_views_rh
+    /// [`Isometry3::cubemap_face_views_lh`]: crate::Isometry3::cubemap_face_views_lh
+    pub fn new_cubemap(near: S, far: S, ndc: NdcConvention) -> Self {
+        Self::new_with_ndc(near, near, near, near, near, far, ndc)
+    }
+
+    /// Compute the half-width `right` and the half-height `top` of the **near plane**
+    /// of a symmetric viewing frustum from its field of view parameters.
+    #[inline]
+    fn vfov_extents<A>(vfov: A, aspect_ratio: S, near: S) -> (S, S)
+    where
+        A: Into<Radians<S>>,
+    {
+        let one = S::one();
+        let two = one + one;
+        let top = Angle::tan(vfov.into() / two) * near;
+        let right = aspect_ratio * top;
+
+        (right, top)
+    }
+
+    /// Construct a perspective projection transformation whose viewing frustum is
+    /// sheared along the **x-axis** by `shear`, with the depth mapping selected by
+    /// `reversed_z` and `infinite_far`.
+    ///
+    /// The projection maps a point `(x, y, z)` in view space to the same normalized
+    /// device coordinates as the unsheared projection with the same frustum
+    /// parameters maps the point `(x - shear * y, y, z)`. The **far plane**
+    /// parameter `far` is ignored when `infinite_far` is `true`.
+    fn new_sheared(
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        near: S,
+        far: S,
+        shear: S,
+        ndc: NdcConvention,
+        reversed_z: bool,
+        infinite_far: bool,
+    ) -> Self {
+        let matrix = match (reversed_z, infinite_far) {
+            (false, false) => Matrix4x4::from_perspective_with_ndc(left, right, bottom, top, near, far, ndc),
+            (true, false) => Matrix4x4::from_perspective_reversed_z_with_ndc(left, right, bottom, top, near, far, ndc),
+            (false, true) => Matrix4x4::from_perspective_infinite_with_ndc(left, right, bottom, top, near, ndc),
+            (true, true) => Matrix4x4::from_perspective_infinite_reversed_z_with_ndc(left, right, bottom, top, near, ndc),
+        };
+        let matrix = if shear == S::zero() {
+            matrix
+        } else {
+            matrix * Matrix4x4::from_affine_shear_xy(-shear)
+        };
+
+        Self {
+            matrix,
+            ndc,
+            reversed_z,
+            infinite_far,
+            standard_depth: None,
+        }
+    }
+
+    /// Compute the **left plane**, **right plane**, **bottom plane**, and **top
+    /// plane** parameters and the shear of the viewing frustum that the camera
+    /// intrinsic matrix `intrinsics` describes, as in [`Perspective3::from_intrinsics`].
+    fn intrinsics_frustum(intrinsics: &Matrix3x3<S>, image_width: S, image_height: S, near: S) -> Option<(S, S, S, S, S)> {
+        let zero = S::zero();
+        let one = S::one();
+        let fx = intrinsics.c0r0;
+        let fy = intrinsics.c1r1;
+        let skew = intrinsics.c1r0;
+        let cx = intrinsics.c2r0;
+        let cy = intrinsics.c2r1;
+        if intrinsics.c0r1 != zero || intrinsics.c0r2 != zero || intrinsics.c1r2 != zero || intrinsics.c2r2 != one {
+            return None;
+        }
+
+        if fx <= zero || fy <= zero {
+            return None;
+        }
+
+        let left = near * cx / fx;
+        let right = near * (image_width - cx) / fx;
+        let bottom = near * (image_height - cy) / fy;
+        let top = near * cy / fy;
+        let shear = skew / fx;
+
+        Some((left, right, bottom, top, shear))
+    }
+
+    /// Get the normalized device coordinates convention of the perspective projection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    ///
+    /// assert_eq!(perspective.ndc_convention(), NdcConvention::OPENGL);
+    /// ```
+    #[inline]
+    pub const fn ndc_convention(&self) -> NdcConvention {
+        self.ndc
+    }
+
+    /// Determine whether the perspective projection uses a reversed-z depth mapping.
+    ///
+    /// A reversed-z perspective projection maps the **near plane** to the far end of
+    /// the depth range of its normalized device coordinates convention, and the
+    /// **far plane** to the near end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let reversed_z = Perspective3::from_vfov_reversed_z(vfov, aspect_ratio, near, far, NdcConvention::OPENGL);
+    ///
+    /// assert!(!perspective.is_reversed_z());
+    /// assert!(reversed_z.is_reversed_z());
+    /// ```
+    #[inline]
+    pub const fn is_reversed_z(&self) -> bool {
+        self.reversed_z
+    }
+
+    /// Determine whether the **far plane** of the perspective projection is
+    /// infinitely distant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(72_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let infinite = Perspective3::from_vfov_infinite(vfov, aspect_ratio, near, NdcConvention::OPENGL);
+    ///
+    /// assert!(!perspective.is_infinite());
+    /// assert!(infinite.is_infinite());
+    /// ```
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.infinite_far
+    }
+
+    /// Replace the **near plane** of the perspective projection with an arbitrary
+    /// plane in view space, using Lengyel's oblique view frustum construction.
+    ///
+    /// The plane `plane == [a, b, c, d]` is given in view space, with its visible
+    /// side being the points `p` satisfying
+    ///
+    /// ```text
+    /// a * p.x + b * p.y + c * p.z + d >= 0
+    /// ```
+    ///
+    /// The resulting perspective projection maps the points on the plane to the
+    /// near end of the depth range, and clips away the points on its invisible side.
+    /// The **left plane**, **right plane**, **bottom plane**, and **top plane** of the
+    /// viewing frustum are unchanged, and the **far plane** is tilted as little as
+    /// possible so that it still bounds the original viewing frustum. This is the
+    /// standard way of clipping geometry against the mirror plane of a planar
+    /// reflection or the plane of a portal without a user clip plane.
+    ///
+    /// The accessors for the frustum parameters, such as [`Perspective3::near`] and
+    /// [`Perspective3::far`], continue to return the parameters of the perspective
+    /// projection before the modification. Replacing the **near plane** of an oblique
+    /// perspective projection replaces the previous oblique plane.
+    ///
+    /// The function returns `None` if the origin of view space does not lie strictly
+    /// on the invisible side of the plane, in which case there is no oblique view
+    /// frustum with the plane as its **near plane**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector4,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 1_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// // The visible side of the plane is the half-space `y + z <= -10`.
+    /// let plane = Vector4::new(0_f64, -1_f64, -1_f64, -10_f64);
+    /// let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+    ///
+    /// assert!(oblique.is_oblique());
+    /// assert_relative_eq!(oblique.near(), near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // Points on the plane map to the near end of the depth range.
+    /// let point = Point3::new(1_f64, -2_f64, -8_f64);
+    /// let projected_point = oblique.project_point(&point);
+    ///
+    /// assert_relative_eq!(projected_point.z, -1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // Unprojection inverts the oblique projection.
+    /// let point = Point3::new(1_f64, 2_f64, -20_f64);
+    /// let projected_point = oblique.project_point(&point);
+    /// let result = oblique.unproject_point(&projected_point);
+    ///
+    /// assert_relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The origin of view space must lie on the invisible side of the plane.
+    /// let plane = Vector4::new(0_f64, 0_f64, -1_f64, 10_f64);
+    ///
+    /// assert!(perspective.with_oblique_near_plane(&plane).is_none());
+    /// ```
+    pub fn with_oblique_near_plane(&self, plane: &Vector4<S>) -> Option<Self> {
+        // Let `m` be the perspective projection matrix with rows `r0`, `r1`, `r2`,
+        // and `r3`, and let `(z_near, z_far)` be the depths in normalized device
+        // coordinates that the near plane and the far plane map to. A point `p` in
+        // view space lies between the near plane and the far plane when the depth
+        // `(r2 * p) / (r3 * p)` lies between `z_near` and `z_far`, so the near plane
+        // is the plane `r2 - z_near * r3`. Replacing the third row with
+        // ```text
+        // r2' := z_near * r3 + k * plane
+        // ```
+        // for a scale factor `k` makes `plane` the new near plane, and the far plane
+        // becomes `(z_far - z_near) * r3 - k * plane`. We choose `k` so that the new far
+        // plane passes through the corner `q` of the original far plane that lies
+        // furthest on the visible side of `plane`, i.e.
+        // ```text
+        // k == (z_far - z_near) * (r3 * q) / (plane * q)
+        // ```
+        // which keeps the original viewing frustum inside the new one while using as
+        // much of the depth range as possible.
+        let zero = S::zero();
+        let one = S::one();
+        if plane[3] >= zero {
+            return None;
+        }
+
+        let matrix = self.standard_matrix();
+        let gaze_sign = self.ndc.gaze_sign::<S>();
+        let (z_near, z_far) = self.ndc_depth_bounds();
+        let sign = |value: S| -> S {
+            if value > zero {
+                one
+            } else if value < zero {
+                -one
+            } else {
+                zero
+            }
+        };
+        // The clip space plane `plane * m^-1` has components `plane.x / m[0, 0]` and
+        // `(plane.y - plane.x * m[0, 1] / m[0, 0]) / m[1, 1]` along the x-axis and the
+        // y-axis, where `m[0, 1]` is the skew term of the first row. The corner `q` is the
+        // homogeneous solution of `m * q == [sign_x, sign_y, z_far, 1]`, normalized
+        // so that `r3 * q == 1`. Its last component vanishes for an infinitely
+        // distant far plane.
+        let sign_x = sign(plane[0] / matrix.c0r0);
+        let sign_y = sign((plane[1] - plane[0] * matrix.c1r0 / matrix.c0r0) / matrix.c1r1);
+        let q_z = gaze_sign;
+        let q_y = (sign_y - matrix.c2r1 * q_z) / matrix.c1r1;
+        let q_x = (sign_x - matrix.c1r0 * q_y - matrix.c2r0 * q_z) / matrix.c0r0;
+        let q_w = (z_far - matrix.c2r2 * q_z) / matrix.c3r2;
+        let plane_dot_q = plane[0] * q_x + plane[1] * q_y + plane[2] * q_z + plane[3] * q_w;
+        if plane_dot_q <= zero {
+            return None;
+        }
+
+        let k = (z_far - z_near) / plane_dot_q;
+        let mut result = *self;
+        result.matrix.c0r2 = k * plane[0];
+        result.matrix.c1r2 = k * plane[1];
+        result.matrix.c2r2 = z_near * gaze_sign + k * plane[2];
+        result.matrix.c3r2 = k * plane[3];
+        result.standard_depth = Some((matrix.c2r2, matrix.c3r2));
+
+        Some(result)
+    }
+
+    /// Determine whether the **near plane** of the perspective projection has been
+    /// replaced by an oblique plane with [`Perspective3::with_oblique_near_plane`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector4;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 1_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let plane = Vector4::new(0_f64, 0_f64, -1_f64, -5_f64);
+    /// let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+    ///
+    /// assert!(!perspective.is_oblique());
+    /// assert!(oblique.is_oblique());
+    /// ```
+    #[inline]
+    pub const fn is_oblique(&self) -> bool {
+        self.standard_depth.is_some()
+    }
+
+    /// Offset the perspective projection by a sub-pixel jitter `jitter` in pixel
+    /// units, for a viewport of `viewport_width` by `viewport_height` pixels.
+    ///
+    /// The projected image shifts `jitter.x` pixels to the right and `jitter.y`
+    /// pixels up, as seen in view space, regardless of the direction of the
+    /// **y-axis** of the normalized device coordinates convention. The jitter is
+    /// applied to the **left plane**, **right plane**, **bottom plane**, and
+    /// **top plane** parameters, so the jittered projection is the possibly
+    /// asymmetric perspective projection with the shifted parameters. The
+    /// normalized device coordinates convention, the depth mapping, any skew, and
+    /// any oblique **near plane** are preserved. Use a
+    /// [`JitterSequence`](crate::JitterSequence) to generate the jitter offsets
+    /// for successive frames.
     ///
     /// # Example
     ///
     /// ```
     /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector2,
+    /// # };
     /// # use cglinalg_transform::Perspective3;
-    /// # use cglinalg_trigonometry::Degrees;
     /// #
-    /// let vfov = Degrees(72_f64);
-    /// let aspect_ratio = 800_f64 / 600_f64;
-    /// let near = 0.1_f64;
+    /// let left = 4_f64;
+    /// let right = 4_f64;
+    /// let bottom = 3_f64;
+    /// let top = 3_f64;
+    /// let near = 1_f64;
     /// let far = 100_f64;
-    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let viewport_width = 800_f64;
+    /// let viewport_height = 600_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    /// let jitter = Vector2::new(0.5_f64, -0.25_f64);
+    /// let jittered = perspective.with_jitter(&jitter, viewport_width, viewport_height);
     ///
-    /// assert_relative_eq!(perspective.vfov(),         vfov.into(),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
-    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
-    /// assert_relative_eq!(perspective.near(),         near,         abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
-    /// assert_relative_eq!(perspective.far(),          far,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
-    /// ```
-    pub fn from_vfov<A>(vfov: A, aspect_ratio: S, near: S, far: S) -> Self
-    where
-        A: Into<Radians<S>>,
-    {
-        let spec_vfov = vfov.into();
+    /// assert_relative_eq!(jittered.left(),   4.005_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered.right(),  3.995_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered.bottom(), 2.9975_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered.top(),    3.0025_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The image shifts by half a pixel to the right and a quarter pixel down.
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let projected_point = perspective.project_point(&point);
+    /// let jittered_point = jittered.project_point(&point);
+    ///
+    /// assert_relative_eq!(jittered_point.x - projected_point.x,  1_f64 / 800_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered_point.y - projected_point.y, -1_f64 / 1200_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn with_jitter(&self, jitter: &Vector2<S>, viewport_width: S, viewport_height: S) -> Self {
+        let (left, right, bottom, top) = jitter_extents(
+            self.left(),
+            self.right(),
+            self.bottom(),
+            self.top(),
+            jitter,
+            viewport_width,
+            viewport_height,
+        );
+        let result = Self::new_sheared(
+            left,
+            right,
+            bottom,
+            top,
+            self.near(),
+            self.far(),
+            self.shear(),
+            self.ndc,
+            self.reversed_z,
+            self.infinite_far,
+        );
+
+        match self.oblique_near_plane() {
+            Some(plane) => {
+                // The plane remains a valid oblique near plane since the origin of view
+                // space does not move. In the unlikely event that the shifted far plane
+                // corner no longer lies on its visible side, keep the original depth row,
+                // which still clips against the same plane.
+                result.with_oblique_near_plane(&plane).unwrap_or_else(|| {
+                    let mut result = result;
+                    result.matrix.c0r2 = self.matrix.c0r2;
+                    result.matrix.c1r2 = self.matrix.c1r2;
+                    result.matrix.c2r2 = self.matrix.c2r2;
+                    result.matrix.c3r2 = self.matrix.c3r2;
+                    result.standard_depth = self.standard_depth;
+
+                    result
+                })
+            }
+            None => result,
+        }
+    }
 
-        Self {
-            matrix: Matrix4x4::from_perspective_vfov(spec_vfov, aspect_ratio, near, far),
+    /// Get the perspective projection matrix before any oblique **near plane**
+    /// replaced its third row.
+    #[inline]
+    fn standard_matrix(&self) -> Matrix4x4<S> {
+        let mut matrix = self.matrix;
+        if let Some((c2r2, c3r2)) = self.standard_depth {
+            matrix.c0r2 = S::zero();
+            matrix.c1r2 = S::zero();
+            matrix.c2r2 = c2r2;
+            matrix.c3r2 = c3r2;
+        }
+
+        matrix
+    }
+
+    /// Recover the view space oblique **near plane** of the perspective projection,
+    /// up to a positive scale factor.
+    #[inline]
+    fn oblique_near_plane(&self) -> Option<Vector4<S>> {
+        // The third row of an oblique perspective projection matrix is
+        // `z_near * r3 + k * plane` where `r3 == [0, 0, gaze_sign, 0]`, and the
+        // sign of `k` is the sign of `z_far - z_near`.
+        self.standard_depth?;
+        let (z_near, z_far) = self.ndc_depth_bounds();
+        let sign = if z_far > z_near { S::one() } else { -S::one() };
+        let plane = Vector4::new(
+            self.matrix.c0r2,
+            self.matrix.c1r2,
+            self.matrix.c2r2 - z_near * self.ndc.gaze_sign::<S>(),
+            self.matrix.c3r2,
+        );
+
+        Some(plane * sign)
+    }
+
+    /// Unproject a point in normalized device coordinates through a perspective
+    /// projection matrix with an oblique **near plane** or a skew.
+    #[inline]
+    fn unproject_general(&self, x: S, y: S, z: S) -> Vector3<S> {
+        // A point `p == [p_x, p_y, p_z, 1]` in view space with clip space coordinates
+        // `m * p == w * [x, y, z, 1]` satisfies `w == gaze_sign * p_z` by the last row
+        // of the matrix. The second row and then the first row express `p_y` and `p_x`
+        // as multiples of `p_z`, and the third row is a linear equation for `p_z`.
+        let matrix = &self.matrix;
+        let gaze_sign = self.ndc.gaze_sign::<S>();
+        let y_ratio = (y * gaze_sign - matrix.c2r1) / matrix.c1r1;
+        let x_ratio = (x * gaze_sign - matrix.c1r0 * y_ratio - matrix.c2r0) / matrix.c0r0;
+        let denominator = matrix.c0r2 * x_ratio + matrix.c1r2 * y_ratio + matrix.c2r2 - z * gaze_sign;
+        let p_z = -matrix.c3r2 / denominator;
+
+        Vector3::new(x_ratio * p_z, y_ratio * p_z, p_z)
+    }
+
+    /// Get the depths in normalized device coordinates that the **near plane** and
+    /// the **far plane** of the perspective projection map to, in that order.
+    #[inline]
+    fn ndc_depth_bounds(&self) -> (S, S) {
+        let (z_near, z_far) = self.ndc.depth_bounds::<S>();
+        if self.reversed_z {
+            (z_far, z_near)
+        } else {
+            (z_near, z_far)
         }
     }
 
@@ -663,7 +2063,9 @@ where
         // ```
         // which is the desired formula.
         //
-        self.matrix[1][1] / self.matrix[0][0]
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
+
+        matrix[1][1] / matrix[0][0]
     }
 
     /// Get the vertical field of view angle.
@@ -822,16 +2224,122 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let numerator = two * self.matrix[1][1];
-        let denominator = self.matrix[2][1] * self.matrix[2][1] + self.matrix[1][1] * self.matrix[1][1] - one;
+        let numerator = two * matrix[1][1];
+        let denominator = matrix[2][1] * matrix[2][1] + matrix[1][1] * matrix[1][1] - one;
         let tan_vfov = numerator / denominator;
         let vfov = tan_vfov.atan();
 
         Radians(vfov)
     }
 
+    /// Get the angles of the four side planes of the viewing frustum.
+    ///
+    /// This is the inverse operation of [`Perspective3::from_fov_angles`]. The
+    /// angles are measured from the gaze direction, with positive angles pointing
+    /// right and up, as described in [`FovAngles`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::{
+    /// #     Degrees,
+    /// #     Radians,
+    /// # };
+    /// #
+    /// let angle_left = Degrees(-45_f64);
+    /// let angle_right = Degrees(30_f64);
+    /// let angle_up = Degrees(45_f64);
+    /// let angle_down = Degrees(-60_f64);
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let ndc = NdcConvention::OPENGL;
+    /// let perspective = Perspective3::from_fov_angles(angle_left, angle_right, angle_up, angle_down, near, far, ndc);
+    /// let result = perspective.fov_angles();
+    ///
+    /// assert_relative_eq!(result.left,  Radians::from(angle_left),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.right, Radians::from(angle_right), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.up,    Radians::from(angle_up),    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.down,  Radians::from(angle_down),  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn fov_angles(&self) -> FovAngles<S> {
+        let near = self.near();
+
+        FovAngles {
+            left: -Radians::atan(self.left() / near),
+            right: Radians::atan(self.right() / near),
+            up: Radians::atan(self.top() / near),
+            down: -Radians::atan(self.bottom() / near),
+        }
+    }
+
+    /// Get the intrinsic matrix of the pinhole camera that the perspective
+    /// projection models, for an image of `image_width` by `image_height` pixels.
+    ///
+    /// This is the inverse operation of [`Perspective3::from_intrinsics`], which
+    /// describes the conventions for the intrinsic matrix. The intrinsic matrix
+    /// only depends on the **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, and **near plane** parameters, and the shear of the viewing
+    /// frustum, so every perspective projection has one. The focal lengths, the
+    /// skew, and the principal point are given by
+    /// ```text
+    /// fx   == near * image_width / (left + right)
+    /// fy   == near * image_height / (bottom + top)
+    /// skew == shear * fx
+    /// cx   == left * image_width / (left + right)
+    /// cy   == top * image_height / (bottom + top)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Matrix3x3;
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// let left = 0.05_f64;
+    /// let right = 0.05_f64;
+    /// let bottom = 0.0375_f64;
+    /// let top = 0.0375_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    /// #[rustfmt::skip]
+    /// let expected = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     0_f64,   800_f64, 0_f64,
+    ///     400_f64, 300_f64, 1_f64,
+    /// );
+    /// let result = perspective.to_intrinsics(800_f64, 600_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    pub fn to_intrinsics(&self, image_width: S, image_height: S) -> Matrix3x3<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let near = self.near();
+        let width = self.left() + self.right();
+        let height = self.bottom() + self.top();
+        let fx = near * image_width / width;
+        let fy = near * image_height / height;
+        let cx = self.left() * image_width / width;
+        let cy = self.top() * image_height / height;
+        let skew = self.shear() * fx;
+
+        Matrix3x3::new(
+            fx,   zero, zero,
+            skew, fy,   zero,
+            cx,   cy,   one,
+        )
+    }
+
     /// Get the position of the **near plane** of the viewing
     /// frustum described by the perspective projection of the plane
     /// parallel to the **xy-plane** positioned along the **negative z-axis**.
@@ -943,17 +2451,33 @@ where
         // ```
         // which is the desired formula.
         //
+        // When the far plane is infinitely distant, the matrix elements are the
+        // limits of the ones above as `far` tends to infinity
+        // ```text
+        // m[2, 2] == -1
+        // m[3, 2] == -2 * near
+        // ```
+        // so that `ratio` is undefined, and we read `near` off of `m[3, 2]` instead.
+        //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let ratio = (-self.matrix[2][2] + one) / (-self.matrix[2][2] - one);
+        if self.infinite_far {
+            return -matrix[3][2] / two;
+        }
 
-        ((one - ratio) / (two * ratio)) * self.matrix[3][2]
+        let ratio = (-matrix[2][2] + one) / (-matrix[2][2] - one);
+
+        ((one - ratio) / (two * ratio)) * matrix[3][2]
     }
 
     /// Get the position of the **far plane** of the viewing
     /// frustum described by the perspective projection of the plane
     /// parallel to the **xy-plane** positioned along the **negative z-axis**.
     ///
+    /// The position of the **far plane** is infinite when the perspective
+    /// projection has an infinitely distant **far plane**.
+    ///
     /// # Example (Frustum Parametrization)
     ///
     /// ```
@@ -1061,13 +2585,19 @@ where
         // ```text
         // far == [(1 - ratio) / 2] * m[3, 2]
         // ```
-        // which is the desired formula.
+        // which is the desired formula. When the far plane is infinitely distant,
+        // there is nothing to read off of the matrix.
         //
+        if self.infinite_far {
+            return S::infinity();
+        }
+
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let ratio = (-self.matrix[2][2] + one) / (-self.matrix[2][2] - one);
+        let ratio = (-matrix[2][2] + one) / (-matrix[2][2] - one);
 
-        ((one - ratio) / two) * self.matrix[3][2]
+        ((one - ratio) / two) * matrix[3][2]
     }
 
     /// Get the position of the **right plane** of the viewing
@@ -1180,12 +2710,13 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let ratio = (self.matrix[2][0] + one) / (-self.matrix[2][0] + one);
+        let ratio = (matrix[2][0] + one) / (-matrix[2][0] + one);
         let near = self.near();
 
-        ((two * near) * (ratio / (ratio + one))) * (one / self.matrix[0][0])
+        ((two * near) * (ratio / (ratio + one))) * (one / matrix[0][0])
     }
 
     /// Get the position of the **left plane** of the viewing
@@ -1297,12 +2828,13 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let ratio = (self.matrix[2][0] + one) / (-self.matrix[2][0] + one);
+        let ratio = (matrix[2][0] + one) / (-matrix[2][0] + one);
         let near = self.near();
 
-        ((two * near) * (one / (ratio + one))) * (one / self.matrix[0][0])
+        ((two * near) * (one / (ratio + one))) * (one / matrix[0][0])
     }
 
     /// Get the position of the **top plane** of the viewing
@@ -1415,12 +2947,13 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let ratio = (self.matrix[2][1] + one) / (-self.matrix[2][1] + one);
+        let ratio = (matrix[2][1] + one) / (-matrix[2][1] + one);
         let near = self.near();
 
-        ((two * near) * (ratio / (ratio + one))) * (one / self.matrix[1][1])
+        ((two * near) * (ratio / (ratio + one))) * (one / matrix[1][1])
     }
 
     /// Get the position of the **bottom plane** of the viewing
@@ -1532,12 +3065,76 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc_depth_bounds(), &self.standard_matrix());
         let one = S::one();
         let two = one + one;
-        let ratio = (self.matrix[2][1] + one) / (-self.matrix[2][1] + one);
+        let ratio = (matrix[2][1] + one) / (-matrix[2][1] + one);
         let near = self.near();
 
-        ((two * near) * (one / (ratio + one))) * (one / self.matrix[1][1])
+        ((two * near) * (one / (ratio + one))) * (one / matrix[1][1])
+    }
+
+    /// Get the shear of the viewing frustum described by the perspective
+    /// projection along the **x-axis**.
+    ///
+    /// A sheared perspective projection maps a point `(x, y, z)` in view space to
+    /// the same normalized device coordinates as the unsheared perspective
+    /// projection with the same **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, **near plane**, and **far plane** parameters maps the point
+    /// `(x - shear * y, y, z)`. The shear is zero for every perspective projection
+    /// except the ones constructed from a camera intrinsic matrix with a nonzero
+    /// `skew`, such as with [`Perspective3::from_intrinsics`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// let left = 3_f64;
+    /// let right = 3_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    ///
+    /// assert_eq!(perspective.shear(), 0_f64);
+    ///
+    /// #[rustfmt::skip]
+    /// let intrinsics = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     40_f64,  800_f64, 0_f64,
+    ///     400_f64, 300_f64, 1_f64,
+    /// );
+    /// let ndc = NdcConvention::OPENGL;
+    /// let sheared = Perspective3::from_intrinsics(&intrinsics, 800_f64, 600_f64, 0.1_f64, 100_f64, ndc).unwrap();
+    /// let unsheared = Perspective3::new_with_ndc(
+    ///     sheared.left(),
+    ///     sheared.right(),
+    ///     sheared.bottom(),
+    ///     sheared.top(),
+    ///     sheared.near(),
+    ///     sheared.far(),
+    ///     ndc,
+    /// );
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let expected = unsheared.project_point(&Point3::new(point.x - sheared.shear() * point.y, point.y, point.z));
+    /// let result = sheared.project_point(&point);
+    ///
+    /// assert_relative_eq!(sheared.shear(), 0.05_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn shear(&self) -> S {
+        // Shearing view space by `x' == x - shear * y` before projecting puts the
+        // term `-shear * m[0, 0]` into the entry `m[1, 0]` of the projection matrix.
+        -self.matrix.c1r0 / self.matrix.c0r0
     }
 
     /// Get the matrix that implements the perspective projection transformation.
@@ -1718,7 +3315,7 @@ where
     /// ```
     #[inline]
     pub fn view_unit_gaze(&self) -> Unit<Vector3<S>> {
-        Unit::from_value_unchecked(-Vector3::unit_z())
+        Unit::from_value_unchecked(Vector3::unit_z() * self.ndc.gaze_sign::<S>())
     }
 
     /// Get the unit horizontal (right) direction for the clip space orthonormal frame of the
@@ -1776,7 +3373,7 @@ where
     /// ```
     #[inline]
     pub fn clip_unit_up(&self) -> Unit<Vector3<S>> {
-        Unit::from_value_unchecked(Vector3::unit_y())
+        Unit::from_value_unchecked(Vector3::unit_y() * self.ndc.y_axis_sign::<S>())
     }
 
     /// Get the unit depth direction for the clip space orthonormal frame of the
@@ -1812,12 +3409,14 @@ where
     /// perspective projection transformation.
     ///
     /// The gaze direction is the direction along the depth axis that faces into
-    /// the viewing volume.
+    /// the viewing volume. For a reversed-z perspective projection, depth decreases
+    /// into the viewing volume, so the gaze direction is the **negative z-axis**.
     ///
     /// # Example
     ///
     /// ```
     /// # use cglinalg_core::{
+    /// #     NdcConvention,
     /// #     Unit,
     /// #     Vector3,
     /// # };
@@ -1834,10 +3433,20 @@ where
     /// let expected = Unit::from_value(Vector3::unit_z());
     ///
     /// assert_eq!(result, expected);
+    ///
+    /// let perspective = Perspective3::from_vfov_reversed_z(vfov, aspect_ratio, near, far, NdcConvention::OPENGL);
+    /// let result = perspective.clip_unit_gaze();
+    /// let expected = Unit::from_value(-Vector3::unit_z());
+    ///
+    /// assert_eq!(result, expected);
     /// ```
     #[inline]
     pub fn clip_unit_gaze(&self) -> Unit<Vector3<S>> {
-        Unit::from_value_unchecked(Vector3::unit_z())
+        if self.reversed_z {
+            Unit::from_value_unchecked(-Vector3::unit_z())
+        } else {
+            Unit::from_value_unchecked(Vector3::unit_z())
+        }
     }
 
     /// Get the clip space normalized device coordinates bounds of the
@@ -1863,7 +3472,7 @@ where
     /// ```
     #[inline]
     pub fn clip_ndc_bounds(&self) -> ((S, S), (S, S), (S, S)) {
-        ((-S::one(), S::one()), (-S::one(), S::one()), (-S::one(), S::one()))
+        ((-S::one(), S::one()), (-S::one(), S::one()), self.ndc.depth_bounds())
     }
 
     /// Apply the projective projection transformation to a point.
@@ -1917,12 +3526,12 @@ where
     /// ```
     #[inline]
     pub fn project_point(&self, point: &Point3<S>) -> Point3<S> {
-        let inverse_w = -S::one() / point.z;
+        let inverse_w = S::one() / (self.matrix.c2r3 * point.z);
 
         Point3::new(
-            (self.matrix.c0r0 * point.x + self.matrix.c2r0 * point.z) * inverse_w,
-            (self.matrix.c1r1 * point.y + self.matrix.c3r1 * point.z) * inverse_w,
-            (self.matrix.c2r2 * point.z + self.matrix.c3r2) * inverse_w,
+            (self.matrix.c0r0 * point.x + self.matrix.c1r0 * point.y + self.matrix.c2r0 * point.z) * inverse_w,
+            (self.matrix.c1r1 * point.y + self.matrix.c2r1 * point.z) * inverse_w,
+            (self.matrix.c0r2 * point.x + self.matrix.c1r2 * point.y + self.matrix.c2r2 * point.z + self.matrix.c3r2) * inverse_w,
         )
     }
 
@@ -2059,22 +3668,39 @@ where
         // construction by only applying the nonzero elements
         // c0r0, c1r1, c2r3, c3r0, c3r1, c3r2, and c3r3 to the input point.
         //
+        // The inverse above is the inverse of OpenGL's perspective projection
+        // matrix, so we first map the input point into OpenGL's canonical view
+        // volume, and flip the depth axis of the result back for a left-handed
+        // view space. We write the elements c2r3 and c3r3 as
+        // ```text
+        // c2r3 == (1/f - 1/n) / 2
+        // c3r3 == (1/n + 1/f) / 2
+        // ```
+        // so that they remain finite for an infinitely distant far plane.
+        //
+        if self.is_oblique() || self.matrix.c1r0 != S::zero() {
+            return Point3::from_vector(&self.unproject_general(point.x, point.y, point.z));
+        }
+
         let one = S::one();
         let two = one + one;
+        let inverse_near = one / self.near();
+        let inverse_far = one / self.far();
+        let ndc_point = ndc_to_opengl(&self.ndc, self.ndc_depth_bounds(), point.x, point.y, point.z);
         let c0r0 = (self.right() + self.left()) / (two * self.near());
         let c1r1 = (self.top() + self.bottom()) / (two * self.near());
-        let c2r3 = (self.near() - self.far()) / (two * self.far() * self.near());
+        let c2r3 = (inverse_far - inverse_near) / two;
         let c3r0 = (self.right() - self.left()) / (two * self.near());
         let c3r1 = (self.top() - self.bottom()) / (two * self.near());
         let c3r2 = -one;
-        let c3r3 = (self.far() + self.near()) / (two * self.far() * self.near());
-        let w = c2r3 * point.z + c3r3;
+        let c3r3 = (inverse_near + inverse_far) / two;
+        let w = c2r3 * ndc_point.z + c3r3;
         let inverse_w = one / w;
 
         Point3::new(
-            (c0r0 * point.x + c3r0) * inverse_w,
-            (c1r1 * point.y + c3r1) * inverse_w,
-            c3r2 * inverse_w,
+            (c0r0 * ndc_point.x + c3r0) * inverse_w,
+            (c1r1 * ndc_point.y + c3r1) * inverse_w,
+            -self.ndc.gaze_sign::<S>() * c3r2 * inverse_w,
         )
     }
 
@@ -2154,22 +3780,39 @@ where
         // construction by only applying the nonzero elements
         // c0r0, c1r1, c2r3, c3r0, c3r1, c3r2, and c3r3 to the input vector.
         //
+        // The inverse above is the inverse of OpenGL's perspective projection
+        // matrix, so we first map the input vector into OpenGL's canonical view
+        // volume, and flip the depth axis of the result back for a left-handed
+        // view space. We write the elements c2r3 and c3r3 as
+        // ```text
+        // c2r3 == (1/f - 1/n) / 2
+        // c3r3 == (1/n + 1/f) / 2
+        // ```
+        // so that they remain finite for an infinitely distant far plane.
+        //
+        if self.is_oblique() || self.matrix.c1r0 != S::zero() {
+            return self.unproject_general(vector.x, vector.y, vector.z);
+        }
+
         let one = S::one();
         let two = one + one;
+        let inverse_near = one / self.near();
+        let inverse_far = one / self.far();
+        let ndc_vector = ndc_to_opengl(&self.ndc, self.ndc_depth_bounds(), vector.x, vector.y, vector.z);
         let c0r0 = (self.right() + self.left()) / (two * self.near());
         let c1r1 = (self.top() + self.bottom()) / (two * self.near());
-        let c2r3 = (self.near() - self.far()) / (two * self.far() * self.near());
+        let c2r3 = (inverse_far - inverse_near) / two;
         let c3r0 = (self.right() - self.left()) / (two * self.near());
         let c3r1 = (self.top() - self.bottom()) / (two * self.near());
         let c3r2 = -one;
-        let c3r3 = (self.far() + self.near()) / (two * self.far() * self.near());
-        let w = c2r3 * vector.z + c3r3;
+        let c3r3 = (inverse_near + inverse_far) / two;
+        let w = c2r3 * ndc_vector.z + c3r3;
         let inverse_w = one / w;
 
         Vector3::new(
-            (c0r0 * vector.x + c3r0) * inverse_w,
-            (c1r1 * vector.y + c3r1) * inverse_w,
-            c3r2 * inverse_w,
+            (c0r0 * ndc_vector.x + c3r0) * inverse_w,
+            (c1r1 * ndc_vector.y + c3r1) * inverse_w,
+            -self.ndc.gaze_sign::<S>() * c3r2 * inverse_w,
         )
     }
 
@@ -2814,9 +4457,19 @@ where
 /// m[3, 2] == -(far + near) / (far - near)
 /// ```
 /// where the matrix entries are indexed in column-major order.
+///
+/// # Normalized Device Coordinates Conventions
+///
+/// The description above is the default OpenGL convention. The constructor
+/// [`Orthographic3::new_with_ndc`] constructs an orthographic projection for any
+/// [`NdcConvention`] instead, such as the ones that Vulkan, Direct3D, Metal, and
+/// WebGPU use. The convention selects the handedness of the view space, the depth
+/// range of the canonical view volume, and the direction of its **y-axis**. The
+/// frustum parameters are positive distances in every convention.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Orthographic3<S> {
     matrix: Matrix4x4<S>,
+    ndc: NdcConvention,
 }
 
 impl<S> Orthographic3<S>
@@ -2978,9 +4631,143 @@ where
     pub fn new(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Self {
         Self {
             matrix: Matrix4x4::from_orthographic(left, right, bottom, top, near, far),
+            ndc: NdcConvention::OPENGL,
+        }
+    }
+
+    /// Construct a new possibly asymmetric orthographic projection transformation based
+    /// on the location of the **left plane**, **right plane**, **bottom plane**,
+    /// **top plane**, **near plane**, and **far plane**, for the normalized device
+    /// coordinates convention `ndc`.
+    ///
+    /// The frustum parameters have the same meaning as in [`Orthographic3::new`],
+    /// except that the **near plane** and the **far plane** lie along the viewing
+    /// direction of the view space of `ndc`. See [`Matrix4x4::from_orthographic_with_ndc`]
+    /// for the underlying matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Orthographic3;
+    /// #
+    /// let left = 6_f64;
+    /// let right = 6_f64;
+    /// let bottom = 4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::DIRECT3D);
+    ///
+    /// assert_eq!(orthographic.ndc_convention(), NdcConvention::DIRECT3D);
+    /// assert_relative_eq!(orthographic.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.near(),   near,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.far(),    far,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The view space is left-handed, so the near plane lies along the positive z-axis.
+    /// let point = Point3::new(right, top, near);
+    /// let expected = Point3::new(1_f64, 1_f64, 0_f64);
+    /// let result = orthographic.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn new_with_ndc(left: S, right: S, bottom: S, top: S, near: S, far: S, ndc: NdcConvention) -> Self {
+        Self {
+            matrix: Matrix4x4::from_orthographic_with_ndc(left, right, bottom, top, near, far, ndc),
+            ndc,
         }
     }
 
+    /// Get the normalized device coordinates convention of the orthographic projection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Orthographic3;
+    /// #
+    /// let left = 6_f64;
+    /// let right = 6_f64;
+    /// let bottom = 4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::METAL);
+    ///
+    /// assert_eq!(orthographic.ndc_convention(), NdcConvention::METAL);
+    /// ```
+    #[inline]
+    pub const fn ndc_convention(&self) -> NdcConvention {
+        self.ndc
+    }
+
+    /// Offset the orthographic projection by a sub-pixel jitter `jitter` in pixel
+    /// units, for a viewport of `viewport_width` by `viewport_height` pixels.
+    ///
+    /// The projected image shifts `jitter.x` pixels to the right and `jitter.y`
+    /// pixels up, as seen in view space, regardless of the direction of the
+    /// **y-axis** of the normalized device coordinates convention. The jitter is
+    /// applied to the **left plane**, **right plane**, **bottom plane**, and
+    /// **top plane** parameters, and the normalized device coordinates convention
+    /// is preserved. Use a [`JitterSequence`](crate::JitterSequence) to generate
+    /// the jitter offsets for successive frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_transform::Orthographic3;
+    /// #
+    /// let left = 4_f64;
+    /// let right = 4_f64;
+    /// let bottom = 3_f64;
+    /// let top = 3_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let viewport_width = 800_f64;
+    /// let viewport_height = 600_f64;
+    /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
+    /// let jitter = Vector2::new(0.5_f64, -0.25_f64);
+    /// let jittered = orthographic.with_jitter(&jitter, viewport_width, viewport_height);
+    ///
+    /// assert_relative_eq!(jittered.left(),   4.005_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered.right(),  3.995_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered.bottom(), 2.9975_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered.top(),    3.0025_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The image shifts by half a pixel to the right and a quarter pixel down.
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let projected_point = orthographic.project_point(&point);
+    /// let jittered_point = jittered.project_point(&point);
+    ///
+    /// assert_relative_eq!(jittered_point.x - projected_point.x,  1_f64 / 800_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(jittered_point.y - projected_point.y, -1_f64 / 1200_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn with_jitter(&self, jitter: &Vector2<S>, viewport_width: S, viewport_height: S) -> Self {
+        let (left, right, bottom, top) = jitter_extents(
+            self.left(),
+            self.right(),
+            self.bottom(),
+            self.top(),
+            jitter,
+            viewport_width,
+            viewport_height,
+        );
+
+        Self::new_with_ndc(left, right, bottom, top, self.near(), self.far(), self.ndc)
+    }
+
     /// Get the position of the **near plane** of the viewing
     /// volume described by the orthographic projection of the plane
     /// parallel to the **xy-plane** positioned along the **negative z-axis**.
@@ -3043,42 +4830,21 @@ where
         // ```text
         // (2 * far) / (far - near) == -m[3, 2] + 1
         // ```
-        // Define
-        // ```text
-        // ratio := far / near
-        // ```
-        // the ratio of `far` to `near` so that
-        // ```text
-        // ratio == far / near
-        //       == (2 * far) / (2 * near)
-        //       == [(2 * far) / (far - near)] / [(2 * near) / (far - near)]
-        //       == (-m[3, 2] + 1) / (-m[3, 2] - 1)
-        // ```
-        // so that
-        // ```text
-        // far == ratio * near
-        // ```
-        // Using the matrix element `m[2, 2]`
+        // Dividing the first identity by the matrix element `m[2, 2]`
         // ```text
         // m[2, 2] == -2 / (far - near)
         // ```
-        // or equivalently
-        // ```text
-        // -2 / m[2, 2] == far - near
-        //              == ratio * near - near
-        //              == (ratio - 1) * near
-        // ```
         // we obtain
         // ```text
-        // near == -2 * (1 / (ratio - 1)) * (1 / m[2, 2])
+        // near == (m[3, 2] + 1) / m[2, 2]
         // ```
-        // which is the desired formula.
+        // which is the desired formula. Unlike recovering `near` from the ratio
+        // `far / near`, this formula remains valid when `near == 0`.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc.depth_bounds(), &self.matrix);
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][2] + one) / (-self.matrix[3][2] - one);
 
-        (-two * (one / (ratio - one))) * (one / self.matrix[2][2])
+        (matrix[3][2] + one) / matrix[2][2]
     }
 
     /// Get the position of the **far plane** of the viewing
@@ -3143,43 +4909,21 @@ where
         // ```text
         // (2 * far) / (far - near) == -m[3, 2] + 1
         // ```
-        // Define
-        // ```text
-        // ratio := far / near
-        // ```
-        // the ratio of `far` to `near` so that
-        // ```text
-        // ratio == far / near
-        //       == (2 * far) / (2 * near)
-        //       == [(2 * far) / (far - near)] / [(2 * near) / (far - near)]
-        //       == (-m[3, 2] + 1) / (-m[3, 2] - 1)
-        // ```
-        // so that
-        // ```text
-        // near == far / ratio
-        // ```
-        // Using the matrix element `m[2, 2]`
+        // Dividing the second identity by the matrix element `m[2, 2]`
         // ```text
         // m[2, 2] == -2 / (far - near)
         // ```
-        // or equivalently
-        // ```text
-        // -2 / m[2, 2] == far - near
-        //              == far - far / ratio
-        //              == (1 - (1 / ratio)) * far
-        //              == ((ratio - 1) / ratio) * far
-        // ```
         // we obtain
         // ```text
-        // far == -2 * (ratio / (ratio - 1)) * (1 / m[2, 2])
+        // far == (m[3, 2] - 1) / m[2, 2]
         // ```
-        // which is the desired formula.
+        // which is the desired formula. Unlike recovering `far` from the ratio
+        // `far / near`, this formula remains valid when `near == 0`.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc.depth_bounds(), &self.matrix);
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][2] + one) / (-self.matrix[3][2] - one);
 
-        (-two * (ratio / (ratio - one))) * (one / self.matrix[2][2])
+        (matrix[3][2] - one) / matrix[2][2]
     }
 
     /// Get the position of the **right plane** of the viewing
@@ -3275,11 +5019,12 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc.depth_bounds(), &self.matrix);
         let one = S::one();
         let two = one + one;
-        let ratio = (-self.matrix[3][0] + one) / (self.matrix[3][0] + one);
+        let ratio = (-matrix[3][0] + one) / (matrix[3][0] + one);
 
-        (two * (ratio / (ratio + one))) * (one / self.matrix[0][0])
+        (two * (ratio / (ratio + one))) * (one / matrix[0][0])
     }
 
     /// Get the position of the **left plane** of the viewing
@@ -3374,11 +5119,12 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc.depth_bounds(), &self.matrix);
         let one = S::one();
         let two = one + one;
-        let ratio = (-self.matrix[3][0] + one) / (self.matrix[3][0] + one);
+        let ratio = (-matrix[3][0] + one) / (matrix[3][0] + one);
 
-        (two * (one / (ratio + one))) * (one / self.matrix[0][0])
+        (two * (one / (ratio + one))) * (one / matrix[0][0])
     }
 
     /// Get the position of the **top plane** of the viewing
@@ -3473,11 +5219,12 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc.depth_bounds(), &self.matrix);
         let one = S::one();
         let two = one + one;
-        let ratio = (-self.matrix[3][1] + one) / (self.matrix[3][1] + one);
+        let ratio = (-matrix[3][1] + one) / (matrix[3][1] + one);
 
-        (two * (ratio / (ratio + one))) * (one / self.matrix[1][1])
+        (two * (ratio / (ratio + one))) * (one / matrix[1][1])
     }
 
     /// Get the position of the **bottom plane** of the viewing
@@ -3572,11 +5319,12 @@ where
         // ```
         // which is the desired formula.
         //
+        let matrix = matrix_to_opengl(&self.ndc, self.ndc.depth_bounds(), &self.matrix);
         let one = S::one();
         let two = one + one;
-        let ratio = (-self.matrix[3][1] + one) / (self.matrix[3][1] + one);
+        let ratio = (-matrix[3][1] + one) / (matrix[3][1] + one);
 
-        (two * (one / (ratio + one))) * (one / self.matrix[1][1])
+        (two * (one / (ratio + one))) * (one / matrix[1][1])
     }
 
     /// Get the underlying matrix implementing the orthographic transformation.
@@ -3729,7 +5477,7 @@ where
     /// ```
     #[inline]
     pub fn view_unit_gaze(&self) -> Unit<Vector3<S>> {
-        Unit::from_value_unchecked(-Vector3::unit_z())
+        Unit::from_value_unchecked(Vector3::unit_z() * self.ndc.gaze_sign::<S>())
     }
 
     /// Get the unit horizontal (right) direction for the clip space orthonormal frame of the
@@ -3789,7 +5537,7 @@ where
     /// ```
     #[inline]
     pub fn clip_unit_up(&self) -> Unit<Vector3<S>> {
-        Unit::from_value_unchecked(Vector3::unit_y())
+        Unit::from_value_unchecked(Vector3::unit_y() * self.ndc.y_axis_sign::<S>())
     }
 
     /// Get the unit depth direction for the view space orthonormal frame of the
@@ -3878,7 +5626,7 @@ where
     /// ```
     #[inline]
     pub fn clip_ndc_bounds(&self) -> ((S, S), (S, S), (S, S)) {
-        ((-S::one(), S::one()), (-S::one(), S::one()), (-S::one(), S::one()))
+        ((-S::one(), S::one()), (-S::one(), S::one()), self.ndc.depth_bounds())
     }
 
     /// Apply the orthographic projection transformation to a point.
@@ -3987,8 +5735,14 @@ where
         // We can optimize the inverse calculation applying only
         // c0r0, c1r1, c2r2, c3r0, c3r1, and c3r2 to the input point.
         //
+        // The inverse above is the inverse of OpenGL's orthographic projection
+        // matrix, so we first map the input point into OpenGL's canonical view
+        // volume, and flip the depth axis of the result back for a left-handed
+        // view space.
+        //
         let one = S::one();
         let one_half = one / (one + one);
+        let ndc_point = ndc_to_opengl(&self.ndc, self.ndc.depth_bounds(), point.x, point.y, point.z);
         let c0r0 = one_half * (self.right() + self.left());
         let c1r1 = one_half * (self.top() + self.bottom());
         let c2r2 = -one_half * (self.far() - self.near());
@@ -3996,7 +5750,11 @@ where
         let c3r1 = one_half * (self.top() - self.bottom());
         let c3r2 = -one_half * (self.far() + self.near());
 
-        Point3::new(c0r0 * point.x + c3r0, c1r1 * point.y + c3r1, c2r2 * point.z + c3r2)
+        Point3::new(
+            c0r0 * ndc_point.x + c3r0,
+            c1r1 * ndc_point.y + c3r1,
+            -self.ndc.gaze_sign::<S>() * (c2r2 * ndc_point.z + c3r2),
+        )
     }
 
     /// Unproject a vector from normalized device coordinates back to
@@ -4045,13 +5803,24 @@ where
         // We can optimize the inverse calculation applying only
         // c0r0, c1r1, and c2r2 to the input vector.
         //
+        // The inverse above is the inverse of OpenGL's orthographic projection
+        // matrix, so we first map the input vector into OpenGL's canonical view
+        // volume, and flip the depth axis of the result back for a left-handed
+        // view space. Vectors are unaffected by the offset of the depth range.
+        //
         let one = S::one();
         let one_half = one / (one + one);
+        let (z_near, z_far) = self.ndc.depth_bounds::<S>();
+        let depth_scale = one_half * (z_far - z_near);
         let c0r0 = one_half * (self.right() + self.left());
         let c1r1 = one_half * (self.top() + self.bottom());
         let c2r2 = -one_half * (self.far() - self.near());
 
-        Vector3::new(c0r0 * vector.x, c1r1 * vector.y, c2r2 * vector.z)
+        Vector3::new(
+            c0r0 * vector.x,
+            c1r1 * (self.ndc.y_axis_sign::<S>() * vector.y),
+            -self.ndc.gaze_sign::<S>() * (c2r2 * (vector.z / depth_scale)),
+        )
     }
 
     /// Convert an orthographic projection to a projective matrix.
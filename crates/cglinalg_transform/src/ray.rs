@@ -0,0 +1,153 @@
+use cglinalg_core::{
+    Point3,
+    Unit,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A ray in three dimensions.
+///
+/// A ray is the half-line of points
+/// ```text
+/// r(t) := origin + t * direction
+/// ```
+/// for `t >= 0`, where `origin` is the starting point of the ray and `direction`
+/// is a unit vector.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Unit,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_transform::Ray3;
+/// #
+/// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+/// let direction = Unit::from_value(Vector3::new(0_f64, 0_f64, -2_f64));
+/// let ray = Ray3::new(origin, direction);
+/// let expected = Point3::new(1_f64, 2_f64, -2_f64);
+/// let result = ray.point_at(5_f64);
+///
+/// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray3<S> {
+    origin: Point3<S>,
+    direction: Unit<Vector3<S>>,
+}
+
+impl<S> Ray3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new ray from its starting point and its direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Ray3;
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Unit::from_value(Vector3::unit_x());
+    /// let ray = Ray3::new(origin, direction);
+    ///
+    /// assert_eq!(ray.origin(), &origin);
+    /// assert_eq!(ray.direction(), &direction);
+    /// ```
+    #[inline]
+    pub const fn new(origin: Point3<S>, direction: Unit<Vector3<S>>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Get the starting point of the ray.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Ray3;
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Unit::from_value(Vector3::unit_y());
+    /// let ray = Ray3::new(origin, direction);
+    ///
+    /// assert_eq!(ray.origin(), &origin);
+    /// ```
+    #[inline]
+    pub const fn origin(&self) -> &Point3<S> {
+        &self.origin
+    }
+
+    /// Get the unit direction of the ray.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Ray3;
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Unit::from_value(Vector3::unit_y());
+    /// let ray = Ray3::new(origin, direction);
+    ///
+    /// assert_eq!(ray.direction(), &direction);
+    /// ```
+    #[inline]
+    pub const fn direction(&self) -> &Unit<Vector3<S>> {
+        &self.direction
+    }
+
+    /// Get the point on the ray at a distance `t` from its starting point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Ray3;
+    /// #
+    /// let origin = Point3::new(1_f64, 1_f64, 1_f64);
+    /// let direction = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let ray = Ray3::new(origin, direction);
+    /// let expected = Point3::new(2_f64, 2_f64, 1_f64);
+    /// let result = ray.point_at(f64::sqrt(2_f64));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn point_at(&self, t: S) -> Point3<S> {
+        self.origin + self.direction.as_ref() * t
+    }
+}
+
+impl<S> fmt::Display for Ray3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Ray3 [origin={}, direction={}]", self.origin, self.direction)
+    }
+}
@@ -5,6 +5,7 @@ use cglinalg_core::{
     DimLt,
     DimMul,
     Euler,
+    EulerOrder,
     Matrix,
     Matrix2x2,
     Matrix3x3,
@@ -12,6 +13,7 @@ use cglinalg_core::{
     Point3,
     Quaternion,
     ShapeConstraint,
+    SwingTwistLimits,
     Unit,
     Vector,
     Vector2,
@@ -1065,6 +1067,164 @@ where
         self.axis().map(|axis| (axis, self.angle()))
     }
 
+    /// Compute the rotation vector of the rotation.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians, with the angle in the range `[0, pi]`.
+    /// Unlike [`Rotation3::axis_angle`], this is defined for every rotation: the
+    /// identity maps to the zero vector, and a rotation by an angle of `pi` maps
+    /// to either of its two opposite rotation vectors. The result is accurate
+    /// for small angles, and for angles near `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// # use core::f64;
+    /// #
+    /// let rotation = Rotation3::from_angle_x(Degrees(-60_f64));
+    /// let expected = Vector3::new(-f64::consts::FRAC_PI_3, 0_f64, 0_f64);
+    /// let result = rotation.scaled_axis();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// let identity = Rotation3::<f64>::identity();
+    ///
+    /// assert_eq!(identity.scaled_axis(), Vector3::zero());
+    /// ```
+    #[inline]
+    pub fn scaled_axis(&self) -> Vector3<S> {
+        Quaternion::from(self).scaled_axis()
+    }
+
+    /// Integrate a constant angular velocity expressed in the world frame over
+    /// a time step.
+    ///
+    /// The rotation `self` is the orientation at the start of the time step.
+    /// The function returns the orientation after rotating for a time `dt`
+    /// with the angular velocity `angular_velocity`, whose components are
+    /// measured in the world (fixed) frame. The update uses the exponential map
+    /// ```text
+    /// R(t + dt) := from_scaled_axis(angular_velocity * dt) * R(t)
+    /// ```
+    /// which is exact for a constant angular velocity over the time step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Rotation3::from_angle_x(Degrees(90_f64));
+    /// // One quarter turn per second about the world z-axis.
+    /// let angular_velocity = Vector3::new(0_f64, 0_f64, core::f64::consts::FRAC_PI_2);
+    /// let expected = Rotation3::from_angle_z(Degrees(45_f64)) * orientation;
+    /// let result = orientation.integrate_angular_velocity(&angular_velocity, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn integrate_angular_velocity(&self, angular_velocity: &Vector3<S>, dt: S) -> Self {
+        Self::from_scaled_axis(&(angular_velocity * dt)) * self
+    }
+
+    /// Integrate a constant angular velocity expressed in the body frame over
+    /// a time step.
+    ///
+    /// The rotation `self` is the orientation at the start of the time step.
+    /// The function returns the orientation after rotating for a time `dt`
+    /// with the angular velocity `angular_velocity`, whose components are
+    /// measured in the body (rotating) frame, as reported by a gyroscope.
+    /// The update uses the exponential map
+    /// ```text
+    /// R(t + dt) := R(t) * from_scaled_axis(angular_velocity * dt)
+    /// ```
+    /// which is exact for a constant angular velocity over the time step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Rotation3::from_angle_x(Degrees(90_f64));
+    /// // One quarter turn per second about the body z-axis.
+    /// let angular_velocity = Vector3::new(0_f64, 0_f64, core::f64::consts::FRAC_PI_2);
+    /// let expected = orientation * Rotation3::from_angle_z(Degrees(45_f64));
+    /// let result = orientation.integrate_body_angular_velocity(&angular_velocity, 0.5_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn integrate_body_angular_velocity(&self, angular_velocity: &Vector3<S>, dt: S) -> Self {
+        self * Self::from_scaled_axis(&(angular_velocity * dt))
+    }
+
+    /// Compute the constant angular velocity in the world frame that rotates
+    /// the orientation `self` into the orientation `next` over a time step `dt`.
+    ///
+    /// This is the inverse of [`Rotation3::integrate_angular_velocity`]. The time
+    /// step must be nonzero. The rotation between the two orientations is taken
+    /// along the shortest path, so the rotation angle over the time step is at
+    /// most `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Rotation3::from_angle_y(Degrees(30_f64));
+    /// let angular_velocity = Vector3::new(0.3_f64, -1.2_f64, 0.8_f64);
+    /// let dt = 0.1_f64;
+    /// let next = orientation.integrate_angular_velocity(&angular_velocity, dt);
+    /// let result = orientation.angular_velocity(&next, dt);
+    ///
+    /// assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn angular_velocity(&self, next: &Self, dt: S) -> Vector3<S> {
+        (next * self.inverse()).scaled_axis() / dt
+    }
+
+    /// Compute the constant angular velocity in the body frame that rotates
+    /// the orientation `self` into the orientation `next` over a time step `dt`.
+    ///
+    /// This is the inverse of [`Rotation3::integrate_body_angular_velocity`].
+    /// The time step must be nonzero. The rotation between the two orientations
+    /// is taken along the shortest path, so the rotation angle over the time
+    /// step is at most `pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let orientation = Rotation3::from_angle_z(Degrees(-75_f64));
+    /// let angular_velocity = Vector3::new(-2_f64, 0.5_f64, 1.5_f64);
+    /// let dt = 0.05_f64;
+    /// let next = orientation.integrate_body_angular_velocity(&angular_velocity, dt);
+    /// let result = orientation.body_angular_velocity(&next, dt);
+    ///
+    /// assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn body_angular_velocity(&self, next: &Self, dt: S) -> Vector3<S> {
+        (self.inverse() * next).scaled_axis() / dt
+    }
+
     /// Construct a three-dimensional rotation matrix from a quaternion.
     ///
     /// # Example
@@ -1136,6 +1296,37 @@ where
         }
     }
 
+    /// Construct a new three-dimensional rotation from a rotation vector.
+    ///
+    /// The rotation vector, or scaled axis, is the axis of rotation scaled by
+    /// the angle of rotation in radians. The zero vector maps to the identity
+    /// rotation. Unlike [`Rotation3::from_axis_angle`], this does not require
+    /// normalizing the axis, so it remains accurate for arbitrarily small angles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(0_f64, 3_f64, 4_f64));
+    /// let angle = 2.5_f64;
+    /// let scaled_axis = axis.into_inner() * angle;
+    /// let expected = Rotation3::from_axis_angle(&axis, Radians(angle));
+    /// let result = Rotation3::from_scaled_axis(&scaled_axis);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_scaled_axis(scaled_axis: &Vector3<S>) -> Self {
+        Self::from_quaternion(&Quaternion::from_scaled_axis(scaled_axis))
+    }
+
     /// Construct a new three-dimensional rotation about the **x-axis** in the
     /// **yz-plane** by an angle `angle`.
     ///
@@ -1594,6 +1785,302 @@ where
         Quaternion::rotation_between_axis(v1, v2).map(|q| q.into())
     }
 
+    /// Decompose a rotation into a swing and a twist about an axis.
+    ///
+    /// The twist is the rotation about `axis`, and the swing is the remaining
+    /// rotation, whose axis is perpendicular to `axis`. The function returns the
+    /// pair `(swing, twist)`, where the rotation is the twist followed by the
+    /// swing. See [`Quaternion::swing_twist`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let unit_z = Unit::from_value(Vector3::unit_z());
+    /// let expected_swing = Rotation3::from_angle_x(Degrees(-40_f64));
+    /// let expected_twist = Rotation3::from_angle_z(Degrees(75_f64));
+    /// let rotation = expected_swing * expected_twist;
+    /// let (swing, twist) = rotation.swing_twist(&unit_z);
+    ///
+    /// assert_relative_eq!(swing, expected_swing, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(twist, expected_twist, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn swing_twist(&self, axis: &Unit<Vector3<S>>) -> (Self, Self) {
+        let (swing, twist) = Quaternion::from(self).swing_twist(axis);
+
+        (Self::from_quaternion(&swing), Self::from_quaternion(&twist))
+    }
+
+    /// Construct a rotation from a swing and a twist.
+    ///
+    /// This is the inverse of [`Rotation3::swing_twist`]. The twist applies
+    /// first, followed by the swing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, 1_f64));
+    /// let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(2_f64, 1_f64, -1_f64)), Degrees(65_f64));
+    /// let (swing, twist) = rotation.swing_twist(&axis);
+    /// let result = Rotation3::from_swing_twist(&swing, &twist);
+    ///
+    /// assert_relative_eq!(result, rotation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_swing_twist(swing: &Self, twist: &Self) -> Self {
+        swing * twist
+    }
+
+    /// Clamp a rotation to a set of swing and twist joint limits.
+    ///
+    /// The function returns the clamped rotation, and whether any limit was
+    /// hit. When no limit is hit, the rotation is returned unchanged. See
+    /// [`Quaternion::clamp_swing_twist`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     SwingTwistLimits,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let twist_axis = Unit::from_value(Vector3::unit_y());
+    /// let limits = SwingTwistLimits::new_cone(&twist_axis, Degrees(20_f64), Degrees(-90_f64), Degrees(30_f64)).unwrap();
+    /// let rotation = Rotation3::from_angle_y(Degrees(75_f64));
+    /// let expected = Rotation3::from_angle_y(Degrees(30_f64));
+    /// let (result, clamped) = rotation.clamp_swing_twist(&limits);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(clamped);
+    /// ```
+    #[inline]
+    pub fn clamp_swing_twist(&self, limits: &SwingTwistLimits<S>) -> (Self, bool) {
+        let (result, clamped) = Quaternion::from(self).clamp_swing_twist(limits);
+        if clamped {
+            (Self::from_quaternion(&result), true)
+        } else {
+            (*self, false)
+        }
+    }
+
+    /// Construct a rotation from a rotation vector using the exponential map
+    /// of **SO(3)**.
+    ///
+    /// The rotation vector is the axis of rotation scaled by the angle of
+    /// rotation in radians. The zero vector maps to the identity rotation.
+    /// This is the same map as [`Rotation3::from_scaled_axis`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Radians;
+    /// # use core::f64;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64));
+    /// let angle = f64::consts::FRAC_PI_3;
+    /// let rotation_vector = axis.into_inner() * angle;
+    /// let expected = Rotation3::from_axis_angle(&axis, Radians(angle));
+    /// let result = Rotation3::exp(&rotation_vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn exp(rotation_vector: &Vector3<S>) -> Self {
+        Self::from_scaled_axis(rotation_vector)
+    }
+
+    /// Compute the rotation vector of a rotation using the logarithm map
+    /// of **SO(3)**.
+    ///
+    /// The rotation vector is the axis of rotation scaled by the angle of
+    /// rotation in radians, with the angle in the range `[0, pi]`. This is the
+    /// inverse of [`Rotation3::exp`]. For a rotation by an angle of `pi`, either
+    /// of the two opposite rotation vectors may be returned.
+    /// This is the same map as [`Rotation3::scaled_axis`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// #
+    /// let rotation_vector = Vector3::new(0.3_f64, -1.2_f64, 0.8_f64);
+    /// let rotation = Rotation3::exp(&rotation_vector);
+    /// let result = rotation.log();
+    ///
+    /// assert_relative_eq!(result, rotation_vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Vector3<S> {
+        self.scaled_axis()
+    }
+
+    /// Compute the left Jacobian of **SO(3)** at a rotation vector.
+    ///
+    /// The left Jacobian relates a perturbation of the rotation vector to
+    /// a perturbation of the rotation applied on the left, i.e. to first order
+    /// ```text
+    /// exp(phi + delta) == exp(left_jacobian(phi) * delta) * exp(phi)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// #
+    /// let rotation_vector = Vector3::new(0.4_f64, 0.1_f64, -0.9_f64);
+    /// let rotation = Rotation3::exp(&rotation_vector);
+    /// let expected = rotation.matrix() * Rotation3::right_jacobian(&rotation_vector);
+    /// let result = Rotation3::left_jacobian(&rotation_vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left_jacobian(rotation_vector: &Vector3<S>) -> Matrix3x3<S> {
+        crate::lie::so3_left_jacobian(rotation_vector)
+    }
+
+    /// Compute the right Jacobian of **SO(3)** at a rotation vector.
+    ///
+    /// The right Jacobian relates a perturbation of the rotation vector to
+    /// a perturbation of the rotation applied on the right, i.e. to first order
+    /// ```text
+    /// exp(phi + delta) == exp(phi) * exp(right_jacobian(phi) * delta)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// #
+    /// let rotation_vector = Vector3::new(-0.7_f64, 0.5_f64, 0.2_f64);
+    /// let expected = Rotation3::left_jacobian(&(-rotation_vector));
+    /// let result = Rotation3::right_jacobian(&rotation_vector);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right_jacobian(rotation_vector: &Vector3<S>) -> Matrix3x3<S> {
+        crate::lie::so3_left_jacobian(&(-rotation_vector))
+    }
+
+    /// Compute the inverse of the left Jacobian of **SO(3)** at a rotation vector.
+    ///
+    /// The inverse exists for rotation angles less than `2 * pi`, which covers
+    /// every rotation vector returned by [`Rotation3::log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// #
+    /// let rotation_vector = Vector3::new(1.1_f64, -0.3_f64, 0.6_f64);
+    /// let jacobian = Rotation3::left_jacobian(&rotation_vector);
+    /// let jacobian_inverse = Rotation3::left_jacobian_inverse(&rotation_vector);
+    /// let expected = Matrix3x3::identity();
+    /// let result = jacobian * jacobian_inverse;
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left_jacobian_inverse(rotation_vector: &Vector3<S>) -> Matrix3x3<S> {
+        crate::lie::so3_left_jacobian_inverse(rotation_vector)
+    }
+
+    /// Compute the inverse of the right Jacobian of **SO(3)** at a rotation vector.
+    ///
+    /// The inverse exists for rotation angles less than `2 * pi`, which covers
+    /// every rotation vector returned by [`Rotation3::log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// #
+    /// let rotation_vector = Vector3::new(0.2_f64, 2.1_f64, -0.4_f64);
+    /// let jacobian = Rotation3::right_jacobian(&rotation_vector);
+    /// let jacobian_inverse = Rotation3::right_jacobian_inverse(&rotation_vector);
+    /// let expected = Matrix3x3::identity();
+    /// let result = jacobian_inverse * jacobian;
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right_jacobian_inverse(rotation_vector: &Vector3<S>) -> Matrix3x3<S> {
+        crate::lie::so3_left_jacobian_inverse(&(-rotation_vector))
+    }
+
+    /// Compute the adjoint matrix of a rotation.
+    ///
+    /// The adjoint matrix maps rotation vectors expressed in the local frame
+    /// of the rotation to the parent frame, i.e.
+    /// ```text
+    /// rotation * exp(phi) * inverse(rotation) == exp(adjoint(rotation) * phi)
+    /// ```
+    /// For **SO(3)** the adjoint matrix is the rotation matrix itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let rotation = Rotation3::from_angle_y(Degrees(70_f64));
+    /// let rotation_vector = Vector3::new(0.5_f64, -0.2_f64, 0.1_f64);
+    /// let expected = rotation * Rotation3::exp(&rotation_vector) * rotation.inverse();
+    /// let result = Rotation3::exp(&(rotation.adjoint() * rotation_vector));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn adjoint(&self) -> Matrix3x3<S> {
+        self.matrix
+    }
+
     /// Construct a rotation matrix from a set of Euler angles.
     ///
     /// # Example
@@ -1852,6 +2339,79 @@ where
 
         Euler::new(roll, yaw, pitch)
     }
+
+    /// Construct a rotation matrix from a set of Euler angles in a given
+    /// rotation order.
+    ///
+    /// See [`EulerOrder`] for the supported rotation orders, and
+    /// [`Euler::to_matrix_with_order`] for the resulting rotation matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let euler_angles = Euler::new(Degrees(30_f64), Degrees(45_f64), Degrees(60_f64));
+    /// let order = EulerOrder::extrinsic(EulerAxes::ZXY);
+    /// let expected = {
+    ///     let rotation_x = Matrix3x3::from_angle_x(Degrees(30_f64));
+    ///     let rotation_y = Matrix3x3::from_angle_y(Degrees(45_f64));
+    ///     let rotation_z = Matrix3x3::from_angle_z(Degrees(60_f64));
+    ///
+    ///     rotation_y * rotation_x * rotation_z
+    /// };
+    /// let rotation = Rotation3::from_euler_angles_with_order(&euler_angles, order);
+    /// let result = rotation.to_matrix();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_euler_angles_with_order<A>(euler_angles: &Euler<A>, order: EulerOrder) -> Self
+    where
+        A: Angle<Dimensionless = S>,
+    {
+        Self {
+            matrix: euler_angles.to_matrix_with_order(order),
+        }
+    }
+
+    /// Extract Euler angles in a given rotation order from a rotation matrix, in
+    /// units of [`Radians`].
+    ///
+    /// See [`Euler::from_quaternion_with_order`] for the ranges of the angles and
+    /// the treatment of gimbal lock.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Euler,
+    /// #     EulerAxes,
+    /// #     EulerOrder,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let expected = Euler::new(Radians(0.4_f64), Radians(1.2_f64), Radians(-2.5_f64));
+    /// let order = EulerOrder::intrinsic(EulerAxes::ZYZ);
+    /// let rotation = Rotation3::from_euler_angles_with_order(&expected, order);
+    /// let result = rotation.euler_angles_with_order(order);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn euler_angles_with_order(&self, order: EulerOrder) -> Euler<Radians<S>> {
+        Euler::from_matrix_with_order(&self.matrix, order)
+    }
 }
 
 impl<S> From<Quaternion<S>> for Rotation3<S>
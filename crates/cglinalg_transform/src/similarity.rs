@@ -3,15 +3,22 @@ use crate::isometry::{
     Isometry2,
     Isometry3,
 };
-use crate::rotation::Rotation;
+use crate::rotation::{
+    Rotation,
+    Rotation3,
+};
 use crate::transform::Transform;
-use crate::translation::Translation;
+use crate::translation::{
+    Translation,
+    Translation3,
+};
 use cglinalg_core::{
     Const,
     DimAdd,
     DimLt,
     DimMul,
     Matrix,
+    Matrix3x3,
     Normed,
     Point,
     Point3,
@@ -1813,6 +1820,272 @@ where
 
         Self::from_isometry(&isometry)
     }
+
+    /// Construct a similarity transformation from a seven-dimensional tangent
+    /// vector using the exponential map of **Sim(3)**.
+    ///
+    /// The tangent vector is laid out as `[rho; phi; sigma]`, where the first
+    /// three components `rho` are the translational part, the next three
+    /// components `phi` are the rotation vector, and the last component `sigma`
+    /// is the logarithm of the scale factor. The rotation of the resulting
+    /// similarity transformation is `Rotation3::exp(phi)`, and its scale
+    /// factor is `exp(sigma)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Similarity3;
+    /// #
+    /// let tangent = Vector::from([0_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64, f64::ln(3_f64)]);
+    /// let expected = Similarity3::from_scale(3_f64);
+    /// let result = Similarity3::exp(&tangent);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn exp(tangent: &Vector<S, 7>) -> Self {
+        let rho = crate::lie::sub_vector3(tangent, 0);
+        let phi = crate::lie::sub_vector3(tangent, 3);
+        let sigma = tangent[6];
+        let rotation = Rotation3::exp(&phi);
+        let translation = crate::lie::sim3_w(&phi, sigma) * rho;
+
+        Self::from_parts(&Translation3::from_vector(&translation), &rotation, sigma.exp())
+    }
+
+    /// Compute the seven-dimensional tangent vector of a similarity
+    /// transformation using the logarithm map of **Sim(3)**.
+    ///
+    /// This is the inverse of [`Similarity3::exp`]. The tangent vector is laid
+    /// out as `[rho; phi; sigma]`, where `phi` is the rotation vector of the
+    /// rotation with an angle in the range `[0, pi]`, and `sigma` is the
+    /// logarithm of the scale factor. The scale factor must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Similarity3;
+    /// #
+    /// let tangent = Vector::from([1_f64, -0.5_f64, 2_f64, 0.3_f64, 0.6_f64, -0.2_f64, 0.4_f64]);
+    /// let similarity = Similarity3::exp(&tangent);
+    /// let result = similarity.log();
+    ///
+    /// assert_relative_eq!(result, tangent, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Vector<S, 7> {
+        let sigma = self.scale.ln();
+        let phi = self.isometry.rotation.log();
+        // The matrix `W` is invertible for every rotation angle in `[0, pi]`.
+        let w_inverse = crate::lie::sim3_w(&phi, sigma).try_inverse().unwrap();
+        let rho = w_inverse * self.isometry.translation.vector();
+
+        Vector::from([rho[0], rho[1], rho[2], phi[0], phi[1], phi[2], sigma])
+    }
+
+    /// Compute the left Jacobian of **Sim(3)** at a tangent vector.
+    ///
+    /// The tangent vector is laid out as `[rho; phi; sigma]`, as in
+    /// [`Similarity3::exp`]. The left Jacobian relates a perturbation of the
+    /// tangent vector to a perturbation of the similarity transformation applied
+    /// on the left, i.e. to first order
+    /// ```text
+    /// exp(xi + delta) == exp(left_jacobian(xi) * delta) * exp(xi)
+    /// ```
+    /// The left Jacobian is the block matrix `[[W, Q, v], [0, J, 0], [0, 0, 1]]`,
+    /// where `W` is the matrix mapping `rho` to the translation in
+    /// [`Similarity3::exp`], `J` is the left Jacobian of the rotation vector
+    /// `phi`, and `Q` and `v` couple the translational part to the rotation
+    /// and the scale. Every block is computed in closed form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Similarity3;
+    /// #
+    /// let tangent = Vector::from([0.2_f64, -0.4_f64, 1_f64, 0.7_f64, 0.1_f64, -0.3_f64, 0.5_f64]);
+    /// let similarity = Similarity3::exp(&tangent);
+    /// let delta = Vector::from([1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64]);
+    /// let expected = similarity.adjoint() * (Similarity3::right_jacobian(&tangent) * delta);
+    /// let result = Similarity3::left_jacobian(&tangent) * delta;
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left_jacobian(tangent: &Vector<S, 7>) -> Matrix<S, 7, 7> {
+        let rho = crate::lie::sub_vector3(tangent, 0);
+        let phi = crate::lie::sub_vector3(tangent, 3);
+        let sigma = tangent[6];
+        let w = crate::lie::sim3_w(&phi, sigma);
+        let jacobian = crate::lie::so3_left_jacobian(&phi);
+        let (q, v) = crate::lie::sim3_left_jacobian_coupling(&rho, &phi, sigma);
+        let mut result = Matrix::zero();
+        crate::lie::set_block(&mut result, 0, 0, &w);
+        crate::lie::set_block(&mut result, 0, 3, &q);
+        crate::lie::set_block(&mut result, 3, 3, &jacobian);
+        for i in 0..3 {
+            result[6][i] = v[i];
+        }
+        result[6][6] = S::one();
+
+        result
+    }
+
+    /// Compute the right Jacobian of **Sim(3)** at a tangent vector.
+    ///
+    /// The tangent vector is laid out as `[rho; phi; sigma]`, as in
+    /// [`Similarity3::exp`]. The right Jacobian relates a perturbation of the
+    /// tangent vector to a perturbation of the similarity transformation applied
+    /// on the right, i.e. to first order
+    /// ```text
+    /// exp(xi + delta) == exp(xi) * exp(right_jacobian(xi) * delta)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Similarity3;
+    /// #
+    /// let tangent = Vector::from([1_f64, 0_f64, -2_f64, 0.4_f64, 0.4_f64, 0.2_f64, -0.3_f64]);
+    /// let expected = Similarity3::left_jacobian(&(-tangent));
+    /// let result = Similarity3::right_jacobian(&tangent);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right_jacobian(tangent: &Vector<S, 7>) -> Matrix<S, 7, 7> {
+        Self::left_jacobian(&(-tangent))
+    }
+
+    /// Compute the inverse of the left Jacobian of **Sim(3)** at a tangent vector.
+    ///
+    /// The inverse exists for rotation angles less than `2 * pi`, which covers
+    /// every tangent vector returned by [`Similarity3::log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Similarity3;
+    /// #
+    /// let tangent = Vector::from([-0.3_f64, 0.8_f64, 0.1_f64, 1.2_f64, -0.5_f64, 0.4_f64, -0.7_f64]);
+    /// let jacobian = Similarity3::left_jacobian(&tangent);
+    /// let jacobian_inverse = Similarity3::left_jacobian_inverse(&tangent);
+    /// let vector = Vector::from([1_f64, -1_f64, 2_f64, 0_f64, 3_f64, -2_f64, 1_f64]);
+    /// let result = jacobian_inverse * (jacobian * vector);
+    ///
+    /// assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left_jacobian_inverse(tangent: &Vector<S, 7>) -> Matrix<S, 7, 7> {
+        let rho = crate::lie::sub_vector3(tangent, 0);
+        let phi = crate::lie::sub_vector3(tangent, 3);
+        let sigma = tangent[6];
+        let w_inverse = crate::lie::sim3_w_inverse(&phi, sigma);
+        let jacobian_inverse = crate::lie::so3_left_jacobian_inverse(&phi);
+        let (q, v) = crate::lie::sim3_left_jacobian_coupling(&rho, &phi, sigma);
+        let upper_middle = -(w_inverse * q * jacobian_inverse);
+        let upper_right = -(w_inverse * v);
+        let mut result = Matrix::zero();
+        crate::lie::set_block(&mut result, 0, 0, &w_inverse);
+        crate::lie::set_block(&mut result, 0, 3, &upper_middle);
+        crate::lie::set_block(&mut result, 3, 3, &jacobian_inverse);
+        for i in 0..3 {
+            result[6][i] = upper_right[i];
+        }
+        result[6][6] = S::one();
+
+        result
+    }
+
+    /// Compute the inverse of the right Jacobian of **Sim(3)** at a tangent vector.
+    ///
+    /// The inverse exists for rotation angles less than `2 * pi`, which covers
+    /// every tangent vector returned by [`Similarity3::log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector;
+    /// # use cglinalg_transform::Similarity3;
+    /// #
+    /// let tangent = Vector::from([2_f64, 0.5_f64, -1_f64, -0.9_f64, 0.3_f64, 1.1_f64, 0.4_f64]);
+    /// let jacobian = Similarity3::right_jacobian(&tangent);
+    /// let jacobian_inverse = Similarity3::right_jacobian_inverse(&tangent);
+    /// let vector = Vector::from([0_f64, 1_f64, 1_f64, -2_f64, 0.5_f64, 4_f64, -1_f64]);
+    /// let result = jacobian * (jacobian_inverse * vector);
+    ///
+    /// assert_relative_eq!(result, vector, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right_jacobian_inverse(tangent: &Vector<S, 7>) -> Matrix<S, 7, 7> {
+        Self::left_jacobian_inverse(&(-tangent))
+    }
+
+    /// Compute the adjoint matrix of a similarity transformation.
+    ///
+    /// The adjoint matrix maps tangent vectors expressed in the local frame of
+    /// the similarity transformation to the parent frame, i.e.
+    /// ```text
+    /// similarity * exp(xi) * inverse(similarity) == exp(adjoint(similarity) * xi)
+    /// ```
+    /// For a tangent vector laid out as `[rho; phi; sigma]`, the adjoint matrix
+    /// is the block matrix `[[s * R, t^ * R, -t], [0, R, 0], [0, 0, 1]]`, where
+    /// `s` is the scale factor, `R` is the rotation matrix, `t` is the translation,
+    /// and `t^` is the cross product matrix of the translation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Rotation3,
+    /// #     Similarity3,
+    /// #     Translation3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+    /// let rotation = Rotation3::from_axis_angle(&axis, Degrees(50_f64));
+    /// let translation = Translation3::new(3_f64, 1_f64, -2_f64);
+    /// let similarity = Similarity3::from_parts(&translation, &rotation, 2_f64);
+    /// let tangent = Vector::from([0.1_f64, 0.2_f64, 0.3_f64, -0.4_f64, 0.5_f64, 0.6_f64, 0.2_f64]);
+    /// let expected = similarity * Similarity3::exp(&tangent) * similarity.inverse();
+    /// let result = Similarity3::exp(&(similarity.adjoint() * tangent));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn adjoint(&self) -> Matrix<S, 7, 7> {
+        let rotation = self.isometry.rotation.matrix();
+        let translation = self.isometry.translation.vector();
+        let translation_cross_rotation = Matrix3x3::cross_matrix(translation) * rotation;
+        let mut result = Matrix::zero();
+        crate::lie::set_block(&mut result, 0, 0, &(rotation * self.scale));
+        crate::lie::set_block(&mut result, 0, 3, &translation_cross_rotation);
+        crate::lie::set_block(&mut result, 3, 3, rotation);
+        for i in 0..3 {
+            result[6][i] = -translation[i];
+        }
+        result[6][6] = S::one();
+
+        result
+    }
 }
 
 
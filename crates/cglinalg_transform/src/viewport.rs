@@ -0,0 +1,549 @@
+use crate::isometry::Isometry3;
+use crate::projection::{
+    Orthographic3,
+    Perspective3,
+};
+use crate::ray::Ray3;
+use cglinalg_core::{
+    NdcConvention,
+    Point2,
+    Point3,
+    Unit,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A viewport transformation in three dimensions.
+///
+/// The viewport transformation maps normalized device coordinates, the output of
+/// [`Perspective3::project_point`] and [`Orthographic3::project_point`], to window
+/// coordinates, and back. A viewport is a rectangle of the window with its **top
+/// left corner** at `(x, y)`, a width `width` and a height `height` in pixels,
+/// together with a depth range `[min_depth, max_depth]`.
+///
+/// # Window Coordinates
+///
+/// Window coordinates follow the convention of windowing systems and mouse
+/// input: the origin lies at the **top left corner** of the window, the
+/// **x-axis** points right, and the **y-axis** points down, regardless of the
+/// normalized device coordinates convention. The viewport uses its normalized
+/// device coordinates convention to decide which way the **y-axis** of normalized
+/// device coordinates points, and which depth range of normalized device
+/// coordinates maps onto `[min_depth, max_depth]`. The viewport transformation
+/// for a point `p_ndc` in normalized device coordinates is given by
+/// ```text
+/// x_window == x + width * (1 + x_ndc) / 2
+/// y_window == y + height * (1 - y_sign * y_ndc) / 2
+/// z_window == min_depth + (max_depth - min_depth) * (z_ndc - z_min) / (z_max - z_min)
+/// ```
+/// where `y_sign == 1` when the **y-axis** of normalized device coordinates points
+/// up and `y_sign == -1` when it points down, and `[z_min, z_max]` is the depth range
+/// of the normalized device coordinates convention. The viewport and the
+/// projections it is used with must share the same normalized device coordinates
+/// convention, so the picking rays are only defined for projections with the
+/// convention of the viewport.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::Point3;
+/// # use cglinalg_transform::Viewport;
+/// #
+/// let viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+///
+/// // The upper left corner of the canonical view volume.
+/// let ndc_point = Point3::new(-1_f64, 1_f64, -1_f64);
+/// let expected = Point3::new(0_f64, 0_f64, 0_f64);
+/// let result = viewport.ndc_to_window(&ndc_point);
+///
+/// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+///
+/// // The center of the canonical view volume.
+/// let ndc_point = Point3::new(0_f64, 0_f64, 0_f64);
+/// let expected = Point3::new(400_f64, 300_f64, 0.5_f64);
+/// let result = viewport.ndc_to_window(&ndc_point);
+///
+/// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Viewport<S> {
+    x: S,
+    y: S,
+    width: S,
+    height: S,
+    min_depth: S,
+    max_depth: S,
+    ndc: NdcConvention,
+}
+
+impl<S> Viewport<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new viewport for OpenGL's normalized device coordinates
+    /// convention.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` is the horizontal window coordinate of the **top left corner** of the viewport.
+    /// * `y` is the vertical window coordinate of the **top left corner** of the viewport.
+    /// * `width` is the width of the viewport in pixels.
+    /// * `height` is the height of the viewport in pixels.
+    /// * `min_depth` is the window depth that the minimum normalized device depth maps to.
+    /// * `max_depth` is the window depth that the maximum normalized device depth maps to.
+    ///
+    /// For a standard projection, `min_depth` is the depth of the **near plane**, and
+    /// `max_depth` is the depth of the **far plane**. A reversed-z projection swaps them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.x(), 10_f64);
+    /// assert_eq!(viewport.y(), 20_f64);
+    /// assert_eq!(viewport.width(), 800_f64);
+    /// assert_eq!(viewport.height(), 600_f64);
+    /// assert_eq!(viewport.min_depth(), 0_f64);
+    /// assert_eq!(viewport.max_depth(), 1_f64);
+    /// assert_eq!(viewport.ndc_convention(), NdcConvention::OPENGL);
+    /// ```
+    #[inline]
+    pub fn new(x: S, y: S, width: S, height: S, min_depth: S, max_depth: S) -> Self {
+        Self::new_with_ndc(x, y, width, height, min_depth, max_depth, NdcConvention::OPENGL)
+    }
+
+    /// Construct a new viewport for a given normalized device coordinates
+    /// convention.
+    ///
+    /// See [`Viewport::new`] for a description of the parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, NdcConvention::VULKAN);
+    ///
+    /// // In Vulkan's convention, the y-axis points down, and depth ranges over `[0, 1]`.
+    /// let ndc_point = Point3::new(-1_f64, -1_f64, 0_f64);
+    /// let expected = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let result = viewport.ndc_to_window(&ndc_point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn new_with_ndc(x: S, y: S, width: S, height: S, min_depth: S, max_depth: S, ndc: NdcConvention) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            min_depth,
+            max_depth,
+            ndc,
+        }
+    }
+
+    /// Get the horizontal window coordinate of the **top left corner** of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.x(), 10_f64);
+    /// ```
+    #[inline]
+    pub const fn x(&self) -> S {
+        self.x
+    }
+
+    /// Get the vertical window coordinate of the **top left corner** of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.y(), 20_f64);
+    /// ```
+    #[inline]
+    pub const fn y(&self) -> S {
+        self.y
+    }
+
+    /// Get the width of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.width(), 800_f64);
+    /// ```
+    #[inline]
+    pub const fn width(&self) -> S {
+        self.width
+    }
+
+    /// Get the height of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.height(), 600_f64);
+    /// ```
+    #[inline]
+    pub const fn height(&self) -> S {
+        self.height
+    }
+
+    /// Get the window depth that the minimum normalized device depth maps to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.min_depth(), 0_f64);
+    /// ```
+    #[inline]
+    pub const fn min_depth(&self) -> S {
+        self.min_depth
+    }
+
+    /// Get the window depth that the maximum normalized device depth maps to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.max_depth(), 1_f64);
+    /// ```
+    #[inline]
+    pub const fn max_depth(&self) -> S {
+        self.max_depth
+    }
+
+    /// Get the normalized device coordinates convention of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::NdcConvention;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, NdcConvention::METAL);
+    ///
+    /// assert_eq!(viewport.ndc_convention(), NdcConvention::METAL);
+    /// ```
+    #[inline]
+    pub const fn ndc_convention(&self) -> NdcConvention {
+        self.ndc
+    }
+
+    /// Get the aspect ratio of the viewport.
+    ///
+    /// The aspect ratio is the ratio of the width of the viewport to its height.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    /// let expected = 4_f64 / 3_f64;
+    /// let result = viewport.aspect_ratio();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn aspect_ratio(&self) -> S {
+        self.width / self.height
+    }
+
+    /// Determine whether a point in window coordinates lies inside the
+    /// rectangle of the viewport.
+    ///
+    /// The left and top edges of the viewport belong to it, and the right and
+    /// bottom edges do not, so that adjacent viewports do not overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(100_f64, 50_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert!(viewport.contains(&Point2::new(100_f64, 50_f64)));
+    /// assert!(viewport.contains(&Point2::new(500_f64, 300_f64)));
+    /// assert!(!viewport.contains(&Point2::new(900_f64, 300_f64)));
+    /// assert!(!viewport.contains(&Point2::new(99_f64, 300_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point2<S>) -> bool {
+        point.x >= self.x && point.x < self.x + self.width && point.y >= self.y && point.y < self.y + self.height
+    }
+
+    /// Map a point from normalized device coordinates to window coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new_with_ndc(100_f64, 50_f64, 800_f64, 600_f64, 0_f64, 1_f64, NdcConvention::DIRECT3D);
+    ///
+    /// // In Direct3D's convention, the y-axis points up, and depth ranges over `[0, 1]`.
+    /// let ndc_point = Point3::new(1_f64, -1_f64, 1_f64);
+    /// let expected = Point3::new(900_f64, 650_f64, 1_f64);
+    /// let result = viewport.ndc_to_window(&ndc_point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn ndc_to_window(&self, point: &Point3<S>) -> Point3<S> {
+        let one = S::one();
+        let one_half = one / (one + one);
+        let y_sign = self.ndc.y_axis_sign::<S>();
+        let (z_min, z_max) = self.ndc.depth_bounds::<S>();
+        let x = self.x + self.width * (one + point.x) * one_half;
+        let y = self.y + self.height * (one - y_sign * point.y) * one_half;
+        let z = self.min_depth + (self.max_depth - self.min_depth) * (point.z - z_min) / (z_max - z_min);
+
+        Point3::new(x, y, z)
+    }
+
+    /// Map a point from window coordinates to normalized device coordinates.
+    ///
+    /// This is the inverse operation of [`Viewport::ndc_to_window`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(100_f64, 50_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    /// let ndc_point = Point3::new(0.25_f64, -0.5_f64, 0.75_f64);
+    /// let window_point = viewport.ndc_to_window(&ndc_point);
+    /// let expected = ndc_point;
+    /// let result = viewport.window_to_ndc(&window_point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn window_to_ndc(&self, point: &Point3<S>) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let y_sign = self.ndc.y_axis_sign::<S>();
+        let (z_min, z_max) = self.ndc.depth_bounds::<S>();
+        let x = two * (point.x - self.x) / self.width - one;
+        let y = y_sign * (one - two * (point.y - self.y) / self.height);
+        let z = z_min + (z_max - z_min) * (point.z - self.min_depth) / (self.max_depth - self.min_depth);
+
+        Point3::new(x, y, z)
+    }
+
+    /// Construct the world space picking ray through a point in window
+    /// coordinates for a perspective camera.
+    ///
+    /// The camera is described by its perspective projection `projection`, and its
+    /// view transformation `view`, which maps world space to the camera's view
+    /// space, for instance the isometry returned by [`Isometry3::look_at_rh`].
+    /// The ray starts at the position of the camera, and passes through the point
+    /// `window_point` on the screen. Pass the window coordinates of a pixel center,
+    /// e.g. `(i + 1/2, j + 1/2)`, to pick through the center of the pixel `(i, j)`.
+    /// The function returns `None` when the normalized device coordinates convention
+    /// of `projection` differs from the convention of the viewport, since the
+    /// viewport would then map the projected points to different pixels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     NdcConvention,
+    /// #     Point2,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     Viewport,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    /// let projection = Perspective3::from_vfov(Degrees(90_f64), viewport.aspect_ratio(), 0.1_f64, 100_f64);
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    ///
+    /// // The center of the viewport looks straight at the target.
+    /// let ray = viewport.perspective_ray(&projection, &view, &Point2::new(400_f64, 300_f64)).unwrap();
+    /// let expected_direction = -Vector3::unit_z();
+    ///
+    /// assert_relative_eq!(ray.origin(), &eye, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(ray.direction().as_ref(), &expected_direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // A point in view projects back onto the pixel whose ray passes through it.
+    /// let point = Point3::new(1_f64, 2_f64, -3_f64);
+    /// let ndc_point = projection.project_point(&view.apply_point(&point));
+    /// let window_point = viewport.ndc_to_window(&ndc_point);
+    /// let ray = viewport.perspective_ray(&projection, &view, &Point2::new(window_point.x, window_point.y)).unwrap();
+    /// let expected = Unit::from_value(point - eye);
+    /// let result = ray.direction();
+    ///
+    /// assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    ///
+    /// // The normalized device coordinates conventions must match.
+    /// let ndc = NdcConvention::VULKAN;
+    /// let projection = Perspective3::from_vfov_with_ndc(Degrees(90_f64), viewport.aspect_ratio(), 0.1_f64, 100_f64, ndc);
+    ///
+    /// assert!(viewport.perspective_ray(&projection, &view, &Point2::new(400_f64, 300_f64)).is_none());
+    /// ```
+    pub fn perspective_ray(&self, projection: &Perspective3<S>, view: &Isometry3<S>, window_point: &Point2<S>) -> Option<Ray3<S>> {
+        if projection.ndc_convention() != self.ndc {
+            return None;
+        }
+
+        let ndc_point = self.window_to_ndc_near(window_point, projection.is_reversed_z());
+        let view_point = projection.unproject_point(&ndc_point);
+        let view_gaze = projection.view_unit_gaze().into_inner();
+        // Every point in front of a perspective camera lies on the side of the gaze
+        // direction. An oblique near plane can put the unprojected point behind the
+        // camera, so we flip the direction back towards the gaze in that case.
+        let view_direction = if view_point.to_vector().dot(&view_gaze) < S::zero() {
+            -view_point.to_vector()
+        } else {
+            view_point.to_vector()
+        };
+        let origin = view.inverse_apply_point(&Point3::origin());
+        let direction = Unit::from_value(view.inverse_apply_vector(&view_direction));
+
+        Some(Ray3::new(origin, direction))
+    }
+
+    /// Construct the world space picking ray through a point in window
+    /// coordinates for an orthographic camera.
+    ///
+    /// The camera is described by its orthographic projection `projection`, and
+    /// its view transformation `view`, which maps world space to the camera's view
+    /// space, for instance the isometry returned by [`Isometry3::look_at_rh`].
+    /// The ray starts on the **near plane** at the point that projects onto
+    /// `window_point`, and points along the gaze direction of the camera. The function
+    /// returns `None` when the normalized device coordinates convention of
+    /// `projection` differs from the convention of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// #     Viewport,
+    /// # };
+    /// #
+    /// let viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    /// let projection = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, 1_f64, 100_f64);
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    ///
+    /// // The top left corner of the viewport.
+    /// let ray = viewport.orthographic_ray(&projection, &view, &Point2::new(0_f64, 0_f64)).unwrap();
+    /// let expected_origin = Point3::new(-4_f64, 3_f64, 4_f64);
+    /// let expected_direction = -Vector3::unit_z();
+    ///
+    /// assert_relative_eq!(ray.origin(), &expected_origin, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(ray.direction().as_ref(), &expected_direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn orthographic_ray(&self, projection: &Orthographic3<S>, view: &Isometry3<S>, window_point: &Point2<S>) -> Option<Ray3<S>> {
+        if projection.ndc_convention() != self.ndc {
+            return None;
+        }
+
+        let ndc_point = self.window_to_ndc_near(window_point, false);
+        let view_point = projection.unproject_point(&ndc_point);
+        let view_gaze = projection.view_unit_gaze().into_inner();
+        let origin = view.inverse_apply_point(&view_point);
+        let direction = Unit::from_value(view.inverse_apply_vector(&view_gaze));
+
+        Some(Ray3::new(origin, direction))
+    }
+
+    /// Map a point in window coordinates to the point in normalized device
+    /// coordinates with the same horizontal and vertical coordinates lying on
+    /// the **near plane**.
+    #[inline]
+    fn window_to_ndc_near(&self, window_point: &Point2<S>, reversed_z: bool) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let y_sign = self.ndc.y_axis_sign::<S>();
+        let (z_min, z_max) = self.ndc.depth_bounds::<S>();
+        let x = two * (window_point.x - self.x) / self.width - one;
+        let y = y_sign * (one - two * (window_point.y - self.y) / self.height);
+        let z_near = if reversed_z { z_max } else { z_min };
+
+        Point3::new(x, y, z_near)
+    }
+}
+
+impl<S> fmt::Display for Viewport<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Viewport [x={}, y={}, width={}, height={}, min_depth={}, max_depth={}, ndc={}]",
+            self.x, self.y, self.width, self.height, self.min_depth, self.max_depth, self.ndc
+        )
+    }
+}
@@ -0,0 +1,259 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::{
+    DepthRange,
+    Handedness,
+    Matrix3x3,
+    NdcConvention,
+    NdcYAxis,
+    Point3,
+    Unit,
+    Vector3,
+};
+use cglinalg_transform::{
+    Isometry3,
+    Perspective3,
+    ShadowCascade,
+};
+use cglinalg_trigonometry::Degrees;
+
+
+const NDC_CONVENTIONS: [NdcConvention; 6] = [
+    NdcConvention::OPENGL,
+    NdcConvention::VULKAN,
+    NdcConvention::DIRECT3D,
+    NdcConvention::METAL,
+    NdcConvention::WEBGPU,
+    NdcConvention::new(Handedness::LeftHanded, DepthRange::NegativeOneToOne, NdcYAxis::Down),
+];
+
+fn view_isometry(ndc: &NdcConvention, eye: &Point3<f64>, target: &Point3<f64>) -> Isometry3<f64> {
+    let up = Vector3::unit_y();
+    match ndc.handedness {
+        Handedness::RightHanded => Isometry3::look_at_rh(eye, target, &up),
+        Handedness::LeftHanded => Isometry3::look_at_lh(eye, target, &up),
+    }
+}
+
+fn slice_corners(perspective: &Perspective3<f64>, view: &Isometry3<f64>, near: f64, far: f64) -> Vec<Point3<f64>> {
+    let gaze = perspective.view_unit_gaze().into_inner();
+    let shear = perspective.shear();
+    let mut corners = Vec::new();
+    for distance in [near, far] {
+        let scale = distance / perspective.near();
+        for x in [-perspective.left() * scale, perspective.right() * scale] {
+            for y in [-perspective.bottom() * scale, perspective.top() * scale] {
+                let view_corner = Point3::new(x + shear * y, y, 0_f64) + gaze * distance;
+                corners.push(view.inverse_apply_point(&view_corner));
+            }
+        }
+    }
+
+    corners
+}
+
+#[test]
+fn test_split_distances_blend() {
+    let near = 0.5_f64;
+    let far = 200_f64;
+    let lambda = 0.6_f64;
+    let result: [f64; 4] = ShadowCascade::split_distances(near, far, lambda);
+    for (i, split) in result.iter().enumerate() {
+        let fraction = ((i + 1) as f64) / 4_f64;
+        let logarithmic = near * (far / near).powf(fraction);
+        let uniform = near + (far - near) * fraction;
+        let expected = lambda * logarithmic + (1_f64 - lambda) * uniform;
+
+        assert_relative_eq!(*split, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_split_distances_increasing() {
+    let near = 0.1_f64;
+    let far = 1000_f64;
+    for lambda in [0_f64, 0.25_f64, 0.5_f64, 0.75_f64, 1_f64] {
+        let result: [f64; 8] = ShadowCascade::split_distances(near, far, lambda);
+
+        assert!(result[0] > near);
+        assert!(result.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(result[7], far);
+    }
+}
+
+#[test]
+fn test_shadow_cascades_cover_slices() {
+    let eye = Point3::new(3_f64, 4_f64, 10_f64);
+    let target = Point3::new(0_f64, 1_f64, 0_f64);
+    let light_direction = Unit::from_value(Vector3::new(-1_f64, -3_f64, 0.5_f64));
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_vfov_with_ndc(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 150_f64, ndc);
+        let view = view_isometry(&ndc, &eye, &target);
+        let cascades: [ShadowCascade<f64>; 4] =
+            ShadowCascade::from_perspective(&perspective, &view, &light_direction, 0.8_f64, 2048).unwrap();
+        let (z_min, z_max) = ndc.depth_bounds::<f64>();
+        for (i, cascade) in cascades.iter().enumerate() {
+            let view_projection = cascade.view_projection();
+            for corner in slice_corners(&perspective, &view, cascade.near(), cascade.far()) {
+                let clip = view_projection * corner.to_homogeneous();
+                let tolerance = 1e-8;
+
+                assert!(
+                    clip.x >= -1_f64 - tolerance && clip.x <= 1_f64 + tolerance,
+                    "i = {}; clip = {}",
+                    i,
+                    clip
+                );
+                assert!(
+                    clip.y >= -1_f64 - tolerance && clip.y <= 1_f64 + tolerance,
+                    "i = {}; clip = {}",
+                    i,
+                    clip
+                );
+                assert!(
+                    clip.z >= z_min - tolerance && clip.z <= z_max + tolerance,
+                    "i = {}; clip = {}",
+                    i,
+                    clip
+                );
+            }
+        }
+
+        assert_eq!(cascades[0].near(), perspective.near());
+        for i in 1..4 {
+            assert_eq!(cascades[i].near(), cascades[i - 1].far());
+        }
+        assert_relative_eq!(
+            cascades[3].far(),
+            perspective.far(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_shadow_cascades_cover_skewed_slices() {
+    let eye = Point3::new(3_f64, 4_f64, 10_f64);
+    let target = Point3::new(0_f64, 1_f64, 0_f64);
+    let light_direction = Unit::from_value(Vector3::new(-1_f64, -3_f64, 0.5_f64));
+    let intrinsics = Matrix3x3::new(
+        800_f64, 0_f64,   0_f64,
+        400_f64, 800_f64, 0_f64,
+        640_f64, 360_f64, 1_f64,
+    );
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_intrinsics(&intrinsics, 1280_f64, 720_f64, 0.1_f64, 150_f64, ndc).unwrap();
+        let view = view_isometry(&ndc, &eye, &target);
+        let cascades: [ShadowCascade<f64>; 4] =
+            ShadowCascade::from_perspective(&perspective, &view, &light_direction, 0.8_f64, 2048).unwrap();
+        for (i, cascade) in cascades.iter().enumerate() {
+            let view_projection = cascade.view_projection();
+            for corner in slice_corners(&perspective, &view, cascade.near(), cascade.far()) {
+                let clip = view_projection * corner.to_homogeneous();
+                let tolerance = 1e-8;
+
+                assert!(clip.x >= -1_f64 - tolerance && clip.x <= 1_f64 + tolerance, "i = {}; clip = {}", i, clip);
+                assert!(clip.y >= -1_f64 - tolerance && clip.y <= 1_f64 + tolerance, "i = {}; clip = {}", i, clip);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_shadow_cascade_light_view_looks_along_light_direction() {
+    let eye = Point3::new(3_f64, 4_f64, 10_f64);
+    let target = Point3::new(0_f64, 1_f64, 0_f64);
+    for light_direction in [
+        Unit::from_value(Vector3::new(-1_f64, -3_f64, 0.5_f64)),
+        Unit::from_value(-Vector3::unit_y()),
+        Unit::from_value(Vector3::unit_y()),
+    ] {
+        for ndc in NDC_CONVENTIONS {
+            let perspective = Perspective3::from_vfov_with_ndc(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 150_f64, ndc);
+            let view = view_isometry(&ndc, &eye, &target);
+            let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 1_f64, 10_f64, 1024).unwrap();
+            let expected = Vector3::unit_z() * ndc.gaze_sign::<f64>();
+            let result = cascade.light_view().apply_vector(light_direction.as_ref());
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_eq!(cascade.projection().ndc_convention(), ndc);
+        }
+    }
+}
+
+#[test]
+fn test_shadow_cascade_texel_snapping() {
+    let light_direction = Unit::from_value(Vector3::new(-1_f64, -3_f64, 0.5_f64));
+    let resolution = 1024;
+    let ndc = NdcConvention::OPENGL;
+    let perspective = Perspective3::from_vfov_with_ndc(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 150_f64, ndc);
+    for offset in [0_f64, 0.013_f64, 0.37_f64, 1.9_f64] {
+        let eye = Point3::new(3_f64 + offset, 4_f64, 10_f64 - offset);
+        let target = Point3::new(offset, 1_f64, -offset);
+        let view = view_isometry(&ndc, &eye, &target);
+        let cascade = ShadowCascade::fit(&perspective, &view, &light_direction, 2_f64, 20_f64, resolution).unwrap();
+        let light_view = cascade.light_view();
+        let light_right = light_view.inverse_apply_vector(&Vector3::unit_x());
+        let light_up = light_view.inverse_apply_vector(&Vector3::unit_y());
+        let texel_size = (cascade.projection().left() + cascade.projection().right()) / f64::from(resolution);
+        let light_eye = light_view.inverse_apply_point(&Point3::origin()).to_vector();
+        // The edges of the shadow map lie on the texel grid anchored at the world space origin.
+        let left_edge = (light_eye.dot(&light_right) - cascade.projection().left()) / texel_size;
+        let bottom_edge = (light_eye.dot(&light_up) - cascade.projection().bottom()) / texel_size;
+
+        assert_relative_eq!(left_edge, left_edge.round(), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+        assert_relative_eq!(bottom_edge, bottom_edge.round(), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_shadow_cascade_texel_size_is_stable_under_camera_translation() {
+    let light_direction = Unit::from_value(Vector3::new(-1_f64, -3_f64, 0.5_f64));
+    let resolution = 2048;
+    for ndc in NDC_CONVENTIONS.iter() {
+        let perspective = Perspective3::from_vfov_with_ndc(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 150_f64, *ndc);
+        let eye = Point3::new(3_f64, 4_f64, 10_f64);
+        let target = Point3::new(0_f64, 1_f64, 0_f64);
+        let view = view_isometry(ndc, &eye, &target);
+        let expected = ShadowCascade::fit(&perspective, &view, &light_direction, 2_f64, 20_f64, resolution).unwrap();
+        let texel_size = (expected.projection().left() + expected.projection().right()) / f64::from(resolution);
+        for fraction in [0.01_f64, 0.25_f64, 0.5_f64, 0.77_f64, 1.3_f64, 12.6_f64] {
+            let offset = Vector3::new(fraction, -0.5_f64 * fraction, 0.3_f64 * fraction) * texel_size;
+            let moved_view = view_isometry(ndc, &(eye + offset), &(target + offset));
+            let result = ShadowCascade::fit(&perspective, &moved_view, &light_direction, 2_f64, 20_f64, resolution).unwrap();
+            let projection = result.projection();
+
+            assert_eq!(projection.left(), expected.projection().left());
+            assert_eq!(projection.right(), expected.projection().right());
+            assert_eq!(projection.bottom(), expected.projection().bottom());
+            assert_eq!(projection.top(), expected.projection().top());
+        }
+    }
+}
+
+#[test]
+fn test_shadow_cascades_infinite_far_plane() {
+    let perspective = Perspective3::from_vfov_infinite(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, NdcConvention::VULKAN);
+    let view = Isometry3::identity();
+    let light_direction = Unit::from_value(-Vector3::unit_y());
+    let result: Option<[ShadowCascade<f64>; 3]> = ShadowCascade::from_perspective(&perspective, &view, &light_direction, 0.5_f64, 1024);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn test_shadow_cascades_degenerate_resolution() {
+    let perspective = Perspective3::from_vfov(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64);
+    let view = Isometry3::identity();
+    let light_direction = Unit::from_value(-Vector3::unit_y());
+    for resolution in [0, 1] {
+        let result: Option<[ShadowCascade<f64>; 3]> =
+            ShadowCascade::from_perspective(&perspective, &view, &light_direction, 0.5_f64, resolution);
+
+        assert!(result.is_none());
+        assert!(ShadowCascade::fit(&perspective, &view, &light_direction, 1_f64, 10_f64, resolution).is_none());
+    }
+
+    assert!(ShadowCascade::fit(&perspective, &view, &light_direction, 1_f64, 10_f64, 2).is_some());
+}
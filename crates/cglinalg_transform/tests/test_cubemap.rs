@@ -0,0 +1,134 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::{
+    DepthRange,
+    Handedness,
+    NdcConvention,
+    NdcYAxis,
+    Point3,
+    Vector3,
+};
+use cglinalg_transform::{
+    CubemapFace,
+    Isometry3,
+    Perspective3,
+};
+
+
+/// Sample a cubemap in the direction `direction`, returning the selected face
+/// and the texture coordinates on that face.
+fn sample_cubemap(direction: &Vector3<f64>) -> (CubemapFace, f64, f64) {
+    let (rx, ry, rz) = (direction.x, direction.y, direction.z);
+    let (face, sc, tc, ma) = if rx.abs() >= ry.abs() && rx.abs() >= rz.abs() {
+        if rx > 0_f64 {
+            (CubemapFace::PositiveX, -rz, -ry, rx.abs())
+        } else {
+            (CubemapFace::NegativeX, rz, -ry, rx.abs())
+        }
+    } else if ry.abs() >= rz.abs() {
+        if ry > 0_f64 {
+            (CubemapFace::PositiveY, rx, rz, ry.abs())
+        } else {
+            (CubemapFace::NegativeY, rx, -rz, ry.abs())
+        }
+    } else if rz > 0_f64 {
+        (CubemapFace::PositiveZ, rx, -ry, rz.abs())
+    } else {
+        (CubemapFace::NegativeZ, -rx, -ry, rz.abs())
+    };
+
+    (face, (sc / ma + 1_f64) / 2_f64, (tc / ma + 1_f64) / 2_f64)
+}
+
+/// Render a pixel of every face of a cubemap, and check that sampling the
+/// cubemap in the direction of the pixel returns the same pixel.
+fn check_cubemap_rendering(views: &[Isometry3<f64>; 6], ndc: NdcConvention, first_row_y: f64) {
+    let center = views[0].inverse_apply_point(&Point3::origin());
+    let perspective = Perspective3::new_cubemap(0.1_f64, 100_f64, ndc);
+    let (z_min, z_max) = ndc.depth_bounds::<f64>();
+    let samples = [(-0.8_f64, -0.6_f64), (0.7_f64, -0.2_f64), (-0.3_f64, 0.9_f64), (0.5_f64, 0.4_f64)];
+    for face in CubemapFace::ALL {
+        let view = views[face.index()];
+        for (x, y) in samples {
+            let ndc_point = Point3::new(x, y, (z_min + z_max) / 2_f64);
+            let view_point = perspective.unproject_point(&ndc_point);
+            let world_point = view.inverse_apply_point(&view_point);
+            let (result_face, s, t) = sample_cubemap(&(world_point - center));
+            let expected_s = (1_f64 + x) / 2_f64;
+            let expected_t = (1_f64 - first_row_y * y) / 2_f64;
+
+            assert_eq!(result_face, face, "ndc = {:?}; x = {}; y = {}", ndc, x, y);
+            assert_relative_eq!(s, expected_s, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(t, expected_t, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_cubemap_face_views_rh_opengl() {
+    let center = Point3::new(1_f64, -2_f64, 3_f64);
+    let views = Isometry3::cubemap_face_views_rh(&center);
+
+    check_cubemap_rendering(&views, NdcConvention::OPENGL, -1_f64);
+}
+
+#[test]
+fn test_cubemap_face_views_rh_vulkan() {
+    let center = Point3::new(1_f64, -2_f64, 3_f64);
+    let views = Isometry3::cubemap_face_views_rh(&center);
+    let ndc = NdcConvention::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Up);
+
+    check_cubemap_rendering(&views, ndc, -1_f64);
+}
+
+#[test]
+fn test_cubemap_face_views_rh_metal() {
+    let center = Point3::new(1_f64, -2_f64, 3_f64);
+    let views = Isometry3::cubemap_face_views_rh(&center);
+    let ndc = NdcConvention::new(Handedness::RightHanded, DepthRange::ZeroToOne, NdcYAxis::Down);
+
+    check_cubemap_rendering(&views, ndc, 1_f64);
+}
+
+#[test]
+fn test_cubemap_face_views_lh_direct3d() {
+    let center = Point3::new(1_f64, -2_f64, 3_f64);
+    let views = Isometry3::cubemap_face_views_lh(&center);
+
+    check_cubemap_rendering(&views, NdcConvention::DIRECT3D, 1_f64);
+}
+
+#[test]
+fn test_cubemap_face_views_cover_center() {
+    let center = Point3::new(-4_f64, 5_f64, 0.5_f64);
+    let views_rh = Isometry3::cubemap_face_views_rh(&center);
+    let views_lh = Isometry3::cubemap_face_views_lh(&center);
+    for face in CubemapFace::ALL {
+        let index = face.index();
+
+        assert_relative_eq!(
+            views_rh[index].apply_point(&center),
+            Point3::origin(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            views_lh[index].apply_point(&center),
+            Point3::origin(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn test_cubemap_face_order() {
+    for (i, face) in CubemapFace::ALL.iter().enumerate() {
+        assert_eq!(face.index(), i);
+    }
+}
+
+#[test]
+fn test_cubemap_face_display() {
+    assert_eq!(format!("{}", CubemapFace::PositiveX), "CubemapFace [+X]");
+    assert_eq!(format!("{}", CubemapFace::NegativeZ), "CubemapFace [-Z]");
+}
@@ -0,0 +1,294 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::{
+    Matrix4x4,
+    NdcConvention,
+    Point3,
+    Vector3,
+    Vector4,
+};
+use cglinalg_transform::{
+    Containment,
+    Frustum3,
+    Isometry3,
+    Orthographic3,
+    Perspective3,
+};
+use cglinalg_trigonometry::Degrees;
+
+
+const NDC_CONVENTIONS: [NdcConvention; 5] = [
+    NdcConvention::OPENGL,
+    NdcConvention::VULKAN,
+    NdcConvention::DIRECT3D,
+    NdcConvention::METAL,
+    NdcConvention::WEBGPU,
+];
+
+
+#[rustfmt::skip]
+#[test]
+fn test_frustum_from_matrix_planes() {
+    let view_projection = Matrix4x4::from_orthographic(1_f64, 2_f64, 3_f64, 4_f64, 1_f64, 10_f64);
+    let frustum = Frustum3::from_matrix(&view_projection, NdcConvention::OPENGL);
+    let expected = [
+        Vector4::new( 1_f64,  0_f64,  0_f64,  1_f64),
+        Vector4::new(-1_f64,  0_f64,  0_f64,  2_f64),
+        Vector4::new( 0_f64,  1_f64,  0_f64,  3_f64),
+        Vector4::new( 0_f64, -1_f64,  0_f64,  4_f64),
+        Vector4::new( 0_f64,  0_f64, -1_f64, -1_f64),
+        Vector4::new( 0_f64,  0_f64,  1_f64,  10_f64),
+    ];
+    let result = frustum.planes();
+
+    for i in 0..6 {
+        assert_relative_eq!(result[i], expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_frustum_perspective_planes_unit_normals() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let up = Vector3::unit_y();
+    let view = Isometry3::look_at_rh(&eye, &target, &up);
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, ndc);
+        let frustum = Frustum3::from_perspective(&projection, &view);
+
+        assert_eq!(frustum.ndc_convention(), ndc);
+        for plane in frustum.planes().iter() {
+            assert_relative_eq!(plane.contract().norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_frustum_perspective_classify_point() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let frustum = Frustum3::from_perspective(&projection, &Isometry3::identity());
+        let gaze = projection.view_unit_gaze().into_inner();
+        let inside = Point3::new(-1_f64, 1_f64, 0_f64) + gaze * 10_f64;
+        let behind = Point3::new(0_f64, 0_f64, 0_f64) - gaze * 10_f64;
+        let too_near = Point3::new(0_f64, 0_f64, 0_f64) + gaze * 0.5_f64;
+        let too_far = Point3::new(0_f64, 0_f64, 0_f64) + gaze * 101_f64;
+        let too_right = Point3::new(2_f64, 0_f64, 0_f64) + gaze * 1_f64;
+        let too_high = Point3::new(0_f64, 6_f64, 0_f64) + gaze * 1_f64;
+
+        assert_eq!(frustum.classify_point(&inside), Containment::Inside);
+        assert_eq!(frustum.classify_point(&behind), Containment::Outside);
+        assert_eq!(frustum.classify_point(&too_near), Containment::Outside);
+        assert_eq!(frustum.classify_point(&too_far), Containment::Outside);
+        assert_eq!(frustum.classify_point(&too_right), Containment::Outside);
+        assert_eq!(frustum.classify_point(&too_high), Containment::Outside);
+    }
+}
+
+#[test]
+fn test_frustum_perspective_classify_point_view() {
+    let vfov = Degrees(90_f64);
+    let aspect_ratio = 1_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let eye = Point3::new(0_f64, 10_f64, 0_f64);
+    let target = Point3::new(0_f64, 10_f64, 10_f64);
+    let up = Vector3::unit_y();
+    let view = Isometry3::look_at_rh(&eye, &target, &up);
+    let frustum = Frustum3::from_perspective(&projection, &view);
+
+    assert_eq!(frustum.classify_point(&Point3::new(0_f64, 10_f64, 50_f64)), Containment::Inside);
+    assert_eq!(frustum.classify_point(&Point3::new(0_f64, 10_f64, -50_f64)), Containment::Outside);
+    assert_eq!(frustum.classify_point(&Point3::new(0_f64, 40_f64, 20_f64)), Containment::Outside);
+}
+
+#[test]
+fn test_frustum_perspective_classify_sphere() {
+    let vfov = Degrees(90_f64);
+    let aspect_ratio = 1_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, ndc);
+        let frustum = Frustum3::from_perspective(&projection, &Isometry3::identity());
+        let gaze = projection.view_unit_gaze().into_inner();
+        let center = Point3::origin() + gaze * 50_f64;
+
+        assert_eq!(frustum.classify_sphere(&center, 1_f64), Containment::Inside);
+        assert_eq!(frustum.classify_sphere(&center, 60_f64), Containment::Intersecting);
+        assert_eq!(
+            frustum.classify_sphere(&(center - gaze * 49.5_f64), 1_f64),
+            Containment::Intersecting
+        );
+        assert_eq!(frustum.classify_sphere(&(center + gaze * 60_f64), 1_f64), Containment::Outside);
+        assert_eq!(
+            frustum.classify_sphere(&(center + Vector3::new(100_f64, 0_f64, 0_f64)), 1_f64),
+            Containment::Outside
+        );
+    }
+}
+
+#[test]
+fn test_frustum_perspective_classify_aabb() {
+    let vfov = Degrees(90_f64);
+    let aspect_ratio = 1_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, ndc);
+        let frustum = Frustum3::from_perspective(&projection, &Isometry3::identity());
+        let gaze = projection.view_unit_gaze().into_inner();
+        let center = Point3::origin() + gaze * 50_f64;
+        let extent = Vector3::new(1_f64, 1_f64, 1_f64);
+        let inside = (center - extent, center + extent);
+        let straddling = (center - extent * 60_f64, center + extent * 60_f64);
+        let outside = (center + gaze * 60_f64 - extent, center + gaze * 60_f64 + extent);
+
+        assert_eq!(frustum.classify_aabb(&inside.0, &inside.1), Containment::Inside);
+        assert_eq!(frustum.classify_aabb(&straddling.0, &straddling.1), Containment::Intersecting);
+        assert_eq!(frustum.classify_aabb(&outside.0, &outside.1), Containment::Outside);
+    }
+}
+
+#[test]
+fn test_frustum_perspective_corners() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let up = Vector3::unit_y();
+    let view = Isometry3::look_at_rh(&eye, &target, &up);
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let frustum = Frustum3::from_perspective(&projection, &view);
+        let ((_, _), (_, _), (z_min, z_max)) = projection.clip_ndc_bounds();
+        let ndc_corners = [
+            Point3::new(-1_f64, -1_f64, z_min),
+            Point3::new(1_f64, -1_f64, z_min),
+            Point3::new(1_f64, 1_f64, z_min),
+            Point3::new(-1_f64, 1_f64, z_min),
+            Point3::new(-1_f64, -1_f64, z_max),
+            Point3::new(1_f64, -1_f64, z_max),
+            Point3::new(1_f64, 1_f64, z_max),
+            Point3::new(-1_f64, 1_f64, z_max),
+        ];
+        let result = frustum.corners().unwrap();
+        for i in 0..8 {
+            let expected = view.inverse_apply_point(&projection.unproject_point(&ndc_corners[i]));
+
+            assert_relative_eq!(result[i], expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+            assert_eq!(frustum.classify_sphere(&result[i], 0.01_f64), Containment::Intersecting);
+        }
+    }
+}
+
+#[test]
+fn test_frustum_orthographic_classify() {
+    let left = 1_f64;
+    let right = 2_f64;
+    let bottom = 3_f64;
+    let top = 4_f64;
+    let near = 1_f64;
+    let far = 10_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let frustum = Frustum3::from_orthographic(&projection, &Isometry3::identity());
+        let gaze = projection.view_unit_gaze().into_inner();
+        let center = Point3::new(0_f64, 0_f64, 0_f64) + gaze * 5_f64;
+
+        assert_eq!(frustum.classify_point(&center), Containment::Inside);
+        assert_eq!(
+            frustum.classify_point(&(center + Vector3::new(-1.5_f64, 0_f64, 0_f64))),
+            Containment::Outside
+        );
+        assert_eq!(frustum.classify_sphere(&center, 0.5_f64), Containment::Inside);
+        assert_eq!(frustum.classify_sphere(&center, 1.5_f64), Containment::Intersecting);
+        assert_eq!(frustum.classify_sphere(&(center + gaze * 10_f64), 1_f64), Containment::Outside);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_frustum_orthographic_corners() {
+    let projection = Orthographic3::new(1_f64, 2_f64, 3_f64, 4_f64, 1_f64, 10_f64);
+    let frustum = Frustum3::from_orthographic(&projection, &Isometry3::identity());
+    let expected = [
+        Point3::new(-1_f64, -3_f64, -1_f64),
+        Point3::new( 2_f64, -3_f64, -1_f64),
+        Point3::new( 2_f64,  4_f64, -1_f64),
+        Point3::new(-1_f64,  4_f64, -1_f64),
+        Point3::new(-1_f64, -3_f64, -10_f64),
+        Point3::new( 2_f64, -3_f64, -10_f64),
+        Point3::new( 2_f64,  4_f64, -10_f64),
+        Point3::new(-1_f64,  4_f64, -10_f64),
+    ];
+    let result = frustum.corners().unwrap();
+
+    for i in 0..8 {
+        assert_relative_eq!(result[i], expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_frustum_reversed_z_matches_standard() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let standard = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, ndc);
+        let reversed_z = Perspective3::from_vfov_reversed_z(vfov, aspect_ratio, near, far, ndc);
+        let standard_frustum = Frustum3::from_perspective(&standard, &Isometry3::identity());
+        let reversed_z_frustum = Frustum3::from_perspective(&reversed_z, &Isometry3::identity());
+        let gaze = standard.view_unit_gaze().into_inner();
+        let points = [
+            Point3::origin() + gaze * 0.05_f64,
+            Point3::origin() + gaze * 50_f64,
+            Point3::origin() + gaze * 150_f64,
+            Point3::new(100_f64, 0_f64, 0_f64) + gaze * 50_f64,
+        ];
+        for point in points.iter() {
+            assert_eq!(reversed_z_frustum.classify_point(point), standard_frustum.classify_point(point));
+        }
+    }
+}
+
+#[test]
+fn test_frustum_infinite_far_plane() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    for ndc in NDC_CONVENTIONS {
+        let infinite = Perspective3::from_vfov_infinite(vfov, aspect_ratio, near, ndc);
+        let infinite_reversed_z = Perspective3::from_vfov_infinite_reversed_z(vfov, aspect_ratio, near, ndc);
+        for projection in [infinite, infinite_reversed_z] {
+            let eye = Point3::new(1_f64, 2_f64, 3_f64);
+            let target = Point3::new(-4_f64, 5_f64, -6_f64);
+            let up = Vector3::unit_y();
+            let view = Isometry3::look_at_rh(&eye, &target, &up);
+            let frustum = Frustum3::from_perspective(&projection, &view);
+            let gaze = view.inverse_apply_vector(&projection.view_unit_gaze().into_inner());
+            let distant = eye + gaze * 1e9_f64;
+            let too_near = eye + gaze * 0.05_f64;
+
+            assert_eq!(frustum.classify_point(&distant), Containment::Inside);
+            assert_eq!(frustum.classify_point(&too_near), Containment::Outside);
+            assert_eq!(frustum.classify_sphere(&distant, 1_f64), Containment::Inside);
+            assert!(frustum.corners().is_none());
+        }
+    }
+}
@@ -186,6 +186,7 @@ mod isometry2_tests {
 mod isometry3_tests {
     use approx_cmp::assert_relative_eq;
     use cglinalg_core::{
+        DualQuaternion,
         Matrix4x4,
         Point3,
         Unit,
@@ -199,6 +200,7 @@ mod isometry3_tests {
     use cglinalg_trigonometry::{
         Angle,
         Degrees,
+        Radians,
     };
 
 
@@ -407,4 +409,236 @@ mod isometry3_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_to_dual_quaternion() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+        let distance = Vector3::new(4_f64, -3_f64, 2_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(147_f64), &distance);
+        let dual_quaternion = isometry.to_dual_quaternion();
+        let expected = isometry.to_affine_matrix();
+        let result = dual_quaternion.to_affine_matrix();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dual_quaternion_round_trip() {
+        let axis = Unit::from_value(Vector3::new(-3_f64, 0_f64, 1_f64));
+        let distance = Vector3::new(1_f64, 1_f64, 5_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(-52_f64), &distance);
+        let result = Isometry3::from(DualQuaternion::from(isometry));
+
+        assert_relative_eq!(result, isometry, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dual_quaternion_product_matches_isometry_product() {
+        let isometry1 = Isometry3::from_axis_angle_translation(
+            &Unit::from_value(Vector3::unit_x()),
+            Degrees(30_f64),
+            &Vector3::new(1_f64, 2_f64, 3_f64),
+        );
+        let isometry2 = Isometry3::from_axis_angle_translation(
+            &Unit::from_value(Vector3::unit_y()),
+            Degrees(-80_f64),
+            &Vector3::new(-2_f64, 0_f64, 1_f64),
+        );
+        let expected = isometry1 * isometry2;
+        let result = Isometry3::from_dual_quaternion(&(isometry1.to_dual_quaternion() * isometry2.to_dual_quaternion()));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_scaled_axis_translation() {
+        let axis = Unit::from_value(Vector3::new(2_f64, 2_f64, -1_f64));
+        let distance = Vector3::new(-1_f64, 0_f64, 3_f64);
+        let expected = Isometry3::from_axis_angle_translation(&axis, Radians(0.8_f64), &distance);
+        let result = Isometry3::from_scaled_axis_translation(&(axis.into_inner() * 0.8_f64), &distance);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_ignores_translation() {
+        let scaled_axis = Vector3::new(0.1_f64, -2.2_f64, 0.7_f64);
+        let isometry = Isometry3::from_scaled_axis_translation(&scaled_axis, &Vector3::new(9_f64, 8_f64, 7_f64));
+        let expected = Isometry3::from_scaled_axis(&scaled_axis).scaled_axis();
+        let result = isometry.scaled_axis();
+
+        assert_eq!(result, expected);
+    }
+}
+
+
+#[cfg(test)]
+mod isometry3_lie_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Unit,
+        Vector,
+        Vector3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn finite_difference_left_jacobian(twist: &Vector<f64, 6>) -> Matrix<f64, 6, 6> {
+        let step = 1e-6;
+        let isometry_inverse = Isometry3::exp(twist).inverse();
+        let mut result = Matrix::zero();
+        for c in 0..6 {
+            let mut offset = Vector::zero();
+            offset[c] = step;
+            let forward = (Isometry3::exp(&(twist + offset)) * isometry_inverse).log();
+            let backward = (Isometry3::exp(&(twist - offset)) * isometry_inverse).log();
+            let column = (forward - backward) / (2_f64 * step);
+            for r in 0..6 {
+                result[c][r] = column[r];
+            }
+        }
+
+        result
+    }
+
+    fn finite_difference_right_jacobian(twist: &Vector<f64, 6>) -> Matrix<f64, 6, 6> {
+        let step = 1e-6;
+        let isometry_inverse = Isometry3::exp(twist).inverse();
+        let mut result = Matrix::zero();
+        for c in 0..6 {
+            let mut offset = Vector::zero();
+            offset[c] = step;
+            let forward = (isometry_inverse * Isometry3::exp(&(twist + offset))).log();
+            let backward = (isometry_inverse * Isometry3::exp(&(twist - offset))).log();
+            let column = (forward - backward) / (2_f64 * step);
+            for r in 0..6 {
+                result[c][r] = column[r];
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_exp_zero_is_identity() {
+        let expected = Isometry3::<f64>::identity();
+        let result = Isometry3::exp(&Vector::zero());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_pure_rotation() {
+        let twist = Vector::from([0_f64, 0_f64, 0_f64, 0.4_f64, -0.2_f64, 1.3_f64]);
+        let expected = Isometry3::from_rotation(&Rotation3::exp(&Vector3::new(0.4_f64, -0.2_f64, 1.3_f64)));
+        let result = Isometry3::exp(&twist);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_screw_motion() {
+        // A screw motion about the z-axis advances along the axis by the
+        // translational part and rotates about the axis by the angle.
+        let twist = Vector::from([0_f64, 0_f64, 2_f64, 0_f64, 0_f64, 1_f64]);
+        let expected = Isometry3::from_parts(
+            &Translation3::new(0_f64, 0_f64, 2_f64),
+            &Rotation3::exp(&Vector3::new(0_f64, 0_f64, 1_f64)),
+        );
+        let result = Isometry3::exp(&twist);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_log_round_trip() {
+        let axis = Unit::from_value(Vector3::new(2_f64, -3_f64, 1_f64));
+        let distance = Vector3::new(-4_f64, 2_f64, 7_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(172_f64), &distance);
+        let result = Isometry3::exp(&isometry.log());
+
+        assert_relative_eq!(result, isometry, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_pure_translation() {
+        let isometry = Isometry3::from_translation(&Translation3::new(1_f64, -2_f64, 3_f64));
+        let expected = Vector::from([1_f64, -2_f64, 3_f64, 0_f64, 0_f64, 0_f64]);
+        let result = isometry.log();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_left_jacobian_finite_difference() {
+        let twist = Vector::from([0.5_f64, -1_f64, 2_f64, 0.9_f64, 0.4_f64, -1.2_f64]);
+        let expected = finite_difference_left_jacobian(&twist);
+        let result = Isometry3::left_jacobian(&twist);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_finite_difference_small_angle() {
+        let twist = Vector::from([1_f64, 3_f64, -2_f64, 4e-3_f64, -2e-3_f64, 5e-3_f64]);
+        let expected = finite_difference_left_jacobian(&twist);
+        let result = Isometry3::left_jacobian(&twist);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_right_jacobian_finite_difference() {
+        let twist = Vector::from([-1.5_f64, 0.2_f64, 0.7_f64, -0.3_f64, 1.6_f64, 0.8_f64]);
+        let expected = finite_difference_right_jacobian(&twist);
+        let result = Isometry3::right_jacobian(&twist);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_inverse() {
+        let twist = Vector::from([2_f64, 1_f64, -1_f64, 1.7_f64, -0.6_f64, 1.1_f64]);
+        let jacobian = Isometry3::left_jacobian(&twist);
+        let jacobian_inverse = Isometry3::left_jacobian_inverse(&twist);
+        for c in 0..6 {
+            let mut expected = Vector::zero();
+            expected[c] = 1_f64;
+            let result = jacobian * (jacobian_inverse * expected);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_right_jacobian_inverse() {
+        let twist = Vector::from([0.3_f64, -0.9_f64, 0.4_f64, 2e-4_f64, 1e-4_f64, -3e-4_f64]);
+        let jacobian = Isometry3::right_jacobian(&twist);
+        let jacobian_inverse = Isometry3::right_jacobian_inverse(&twist);
+        for c in 0..6 {
+            let mut expected = Vector::zero();
+            expected[c] = 1_f64;
+            let result = jacobian_inverse * (jacobian * expected);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_adjoint() {
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64));
+        let distance = Vector3::new(1_f64, 5_f64, -3_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(-135_f64), &distance);
+        let twist = Vector::from([-0.6_f64, 0.2_f64, 1_f64, 0.5_f64, 0.3_f64, -0.2_f64]);
+        let expected = isometry * Isometry3::exp(&twist) * isometry.inverse();
+        let result = Isometry3::exp(&(isometry.adjoint() * twist));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
 }
@@ -0,0 +1,97 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::Vector2;
+use cglinalg_transform::JitterSequence;
+
+
+#[test]
+fn test_halton23_samples() {
+    let sequence = JitterSequence::Halton23;
+    let expected = [
+        Vector2::new(0_f64, -1_f64 / 6_f64),
+        Vector2::new(-1_f64 / 4_f64, 1_f64 / 6_f64),
+        Vector2::new(1_f64 / 4_f64, -7_f64 / 18_f64),
+        Vector2::new(-3_f64 / 8_f64, -1_f64 / 18_f64),
+        Vector2::new(1_f64 / 8_f64, 5_f64 / 18_f64),
+        Vector2::new(-1_f64 / 8_f64, -5_f64 / 18_f64),
+        Vector2::new(3_f64 / 8_f64, 1_f64 / 18_f64),
+        Vector2::new(-7_f64 / 16_f64, 7_f64 / 18_f64),
+    ];
+    for (i, expected_i) in expected.iter().enumerate() {
+        let result: Vector2<f64> = sequence.sample(i);
+
+        assert_relative_eq!(result, expected_i, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_r2_samples() {
+    let sequence = JitterSequence::R2;
+    let alpha_x = 0.7548776662466927_f64;
+    let alpha_y = 0.5698402909980532_f64;
+    for i in 0..16 {
+        let n = i as f64;
+        let expected = Vector2::new((0.5_f64 + n * alpha_x).fract() - 0.5_f64, (0.5_f64 + n * alpha_y).fract() - 0.5_f64);
+        let result: Vector2<f64> = sequence.sample(i);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_r2_first_sample_is_pixel_center() {
+    let sequence = JitterSequence::R2;
+    let result: Vector2<f64> = sequence.sample(0);
+
+    assert_eq!(result, Vector2::zero());
+}
+
+#[test]
+fn test_jitter_samples_lie_within_pixel() {
+    for sequence in [JitterSequence::Halton23, JitterSequence::R2] {
+        for i in 0..1024 {
+            let sample: Vector2<f64> = sequence.sample(i);
+
+            assert!(
+                sample.x >= -0.5_f64 && sample.x < 0.5_f64,
+                "sequence = {}; i = {}; sample = {}",
+                sequence,
+                i,
+                sample
+            );
+            assert!(
+                sample.y >= -0.5_f64 && sample.y < 0.5_f64,
+                "sequence = {}; i = {}; sample = {}",
+                sequence,
+                i,
+                sample
+            );
+        }
+    }
+}
+
+#[test]
+fn test_jitter_samples_are_distinct() {
+    for sequence in [JitterSequence::Halton23, JitterSequence::R2] {
+        let samples: Vec<Vector2<f64>> = (0..16).map(|i| sequence.sample(i)).collect();
+        for i in 0..samples.len() {
+            for j in (i + 1)..samples.len() {
+                assert_ne!(samples[i], samples[j], "sequence = {}; i = {}; j = {}", sequence, i, j);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_jitter_sequence_samples_f32() {
+    let sequence = JitterSequence::Halton23;
+    let expected = Vector2::new(1_f32 / 4_f32, -7_f32 / 18_f32);
+    let result: Vector2<f32> = sequence.sample(2);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-6, relative_all <= f32::EPSILON);
+}
+
+#[test]
+fn test_jitter_sequence_display() {
+    assert_eq!(format!("{}", JitterSequence::Halton23), "Halton(2, 3)");
+    assert_eq!(format!("{}", JitterSequence::R2), "R2");
+}
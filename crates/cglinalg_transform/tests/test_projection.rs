@@ -1,14 +1,27 @@
 use approx_cmp::assert_relative_eq;
 use cglinalg_core::{
+    DepthRange,
+    Handedness,
+    Matrix3x3,
     Matrix4x4,
+    NdcConvention,
+    NdcYAxis,
     Point3,
+    Unit,
+    Vector2,
     Vector3,
+    Vector4,
 };
 use cglinalg_transform::{
+    FovAngles,
     Orthographic3,
     Perspective3,
+    Viewport,
+};
+use cglinalg_trigonometry::{
+    Degrees,
+    Radians,
 };
-use cglinalg_trigonometry::Degrees;
 
 
 #[rustfmt::skip]
@@ -308,3 +321,1289 @@ fn test_orthographic_projection_unproject_vector() {
 
     assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
 }
+
+
+const NDC_CONVENTIONS: [NdcConvention; 6] = [
+    NdcConvention::OPENGL,
+    NdcConvention::VULKAN,
+    NdcConvention::DIRECT3D,
+    NdcConvention::METAL,
+    NdcConvention::WEBGPU,
+    NdcConvention::new(Handedness::LeftHanded, DepthRange::NegativeOneToOne, NdcYAxis::Down),
+];
+
+#[test]
+fn test_perspective_projection_asymmetric_project_point() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let point = Point3::new(-2_f64, 2_f64, -50_f64);
+    let expected = Point3::from_homogeneous(&(projection.matrix() * point.to_homogeneous())).unwrap();
+    let result = projection.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_ndc_opengl_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let expected = Matrix4x4::from_perspective(left, right, bottom, top, near, far);
+    let result = Matrix4x4::from_perspective_with_ndc(left, right, bottom, top, near, far, NdcConvention::OPENGL);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_ndc_vulkan_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let expected = Matrix4x4::new(
+        1_f64 / 4_f64,  0_f64,          0_f64,             0_f64,
+        0_f64,         -2_f64 / 5_f64,  0_f64,             0_f64,
+        0_f64,         -1_f64 / 5_f64, -100_f64 / 99_f64, -1_f64,
+        0_f64,          0_f64,         -100_f64 / 99_f64,  0_f64,
+    );
+    let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::VULKAN);
+    let result = perspective.matrix();
+
+    assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_ndc_direct3d_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let expected = Matrix4x4::new(
+        1_f64 / 4_f64,  0_f64,          0_f64,            0_f64,
+        0_f64,          2_f64 / 5_f64,  0_f64,            0_f64,
+        0_f64,         -1_f64 / 5_f64,  100_f64 / 99_f64, 1_f64,
+        0_f64,          0_f64,         -100_f64 / 99_f64, 0_f64,
+    );
+    let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::DIRECT3D);
+    let result = perspective.matrix();
+
+    assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_ndc_rectangular_parameters() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+
+        assert_eq!(perspective.ndc_convention(), ndc);
+        assert_relative_eq!(perspective.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.near(),   near,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.far(),    far,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_ndc_fov_parameters() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let expected_vfov = vfov.into();
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, ndc);
+
+        assert_relative_eq!(perspective.vfov(),         expected_vfov, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.near(),         near,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.far(),          far,           abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_ndc_frustum_corners() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let up = perspective.clip_unit_up().into_inner();
+        let ((_, _), (_, _), (z_near, z_far)) = perspective.clip_ndc_bounds();
+        let near_top_right = Point3::new(right, top, 0_f64) + gaze * near;
+        let far_bottom_left = Point3::new(-left * (far / near), -bottom * (far / near), 0_f64) + gaze * far;
+        let expected_near_top_right = Point3::new(1_f64, up.y, z_near);
+        let expected_far_bottom_left = Point3::new(-1_f64, -up.y, z_far);
+
+        assert_relative_eq!(
+            perspective.project_point(&near_top_right),
+            expected_near_top_right,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+        assert_relative_eq!(
+            perspective.project_point(&far_bottom_left),
+            expected_far_bottom_left,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+    }
+}
+
+#[test]
+fn test_perspective_projection_ndc_unproject_point() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let expected = Point3::new(-2_f64, 2_f64, 0_f64) + projection.view_unit_gaze().into_inner() * 50_f64;
+        let projected_point = projection.project_point(&expected);
+        let result = projection.unproject_point(&projected_point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_ndc_unproject_vector() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let expected = Vector3::new(-2_f64, 2_f64, 0_f64) + projection.view_unit_gaze().into_inner() * 50_f64;
+        let projected_vector = projection.project_vector(&expected);
+        let result = projection.unproject_vector(&projected_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_ndc_clip_ndc_bounds() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let opengl = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::OPENGL);
+    let vulkan = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::VULKAN);
+
+    assert_eq!(opengl.clip_ndc_bounds(), ((-1_f64, 1_f64), (-1_f64, 1_f64), (-1_f64, 1_f64)));
+    assert_eq!(vulkan.clip_ndc_bounds(), ((-1_f64, 1_f64), (-1_f64, 1_f64), (0_f64, 1_f64)));
+}
+
+#[test]
+fn test_perspective_projection_ndc_unit_directions() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let vulkan = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::VULKAN);
+    let direct3d = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::DIRECT3D);
+
+    assert_eq!(vulkan.clip_unit_up(), Unit::from_value(-Vector3::unit_y()));
+    assert_eq!(vulkan.view_unit_gaze(), Unit::from_value(-Vector3::unit_z()));
+    assert_eq!(direct3d.clip_unit_up(), Unit::from_value(Vector3::unit_y()));
+    assert_eq!(direct3d.view_unit_gaze(), Unit::from_value(Vector3::unit_z()));
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_reversed_z_vulkan_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let expected = Matrix4x4::new(
+        1_f64 / 4_f64,  0_f64,          0_f64,            0_f64,
+        0_f64,         -2_f64 / 5_f64,  0_f64,            0_f64,
+        0_f64,         -1_f64 / 5_f64,  1_f64 / 99_f64,  -1_f64,
+        0_f64,          0_f64,          100_f64 / 99_f64, 0_f64,
+    );
+    let perspective = Perspective3::new_reversed_z(left, right, bottom, top, near, far, NdcConvention::VULKAN);
+    let result = perspective.matrix();
+
+    assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_infinite_opengl_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let expected = Matrix4x4::new(
+        1_f64 / 4_f64, 0_f64,          0_f64,  0_f64,
+        0_f64,         2_f64 / 5_f64,  0_f64,  0_f64,
+        0_f64,         1_f64 / 5_f64, -1_f64, -1_f64,
+        0_f64,         0_f64,         -2_f64,  0_f64,
+    );
+    let perspective = Perspective3::new_infinite(left, right, bottom, top, near, NdcConvention::OPENGL);
+    let result = perspective.matrix();
+
+    assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_infinite_reversed_z_direct3d_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let expected = Matrix4x4::new(
+        1_f64 / 4_f64,  0_f64,          0_f64, 0_f64,
+        0_f64,          2_f64 / 5_f64,  0_f64, 0_f64,
+        0_f64,         -1_f64 / 5_f64,  0_f64, 1_f64,
+        0_f64,          0_f64,          1_f64, 0_f64,
+    );
+    let perspective = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, NdcConvention::DIRECT3D);
+    let result = perspective.matrix();
+
+    assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_reversed_z_depth_mapping() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let (z_min, z_max) = ndc.depth_bounds::<f64>();
+        let near_point = Point3::origin() + gaze * near;
+        let far_point = Point3::origin() + gaze * far;
+
+        assert_relative_eq!(perspective.project_point(&near_point).z, z_max, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.project_point(&far_point).z,  z_min, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_infinite_depth_mapping() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let distant = 1e12_f64;
+    for ndc in NDC_CONVENTIONS {
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        let infinite_reversed_z = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        let gaze = infinite.view_unit_gaze().into_inner();
+        let (z_min, z_max) = ndc.depth_bounds::<f64>();
+        let near_point = Point3::origin() + gaze * near;
+        let distant_point = Point3::origin() + gaze * distant;
+
+        assert_relative_eq!(infinite.project_point(&near_point).z,    z_min, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(infinite.project_point(&distant_point).z, z_max, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(infinite_reversed_z.project_point(&near_point).z,    z_max, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(infinite_reversed_z.project_point(&distant_point).z, z_min, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_reversed_z_infinite_rectangular_parameters() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        let infinite_reversed_z = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        for perspective in [reversed_z, infinite, infinite_reversed_z] {
+            assert_eq!(perspective.ndc_convention(), ndc);
+            assert_relative_eq!(perspective.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.near(),   near,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+
+        assert_relative_eq!(reversed_z.far(), far, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_eq!(infinite.far(), f64::INFINITY);
+        assert_eq!(infinite_reversed_z.far(), f64::INFINITY);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_reversed_z_infinite_fov_parameters() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let expected_vfov = vfov.into();
+    for ndc in NDC_CONVENTIONS {
+        let reversed_z = Perspective3::from_vfov_reversed_z(vfov, aspect_ratio, near, far, ndc);
+        let infinite = Perspective3::from_vfov_infinite(vfov, aspect_ratio, near, ndc);
+        let infinite_reversed_z = Perspective3::from_vfov_infinite_reversed_z(vfov, aspect_ratio, near, ndc);
+        for perspective in [reversed_z, infinite, infinite_reversed_z] {
+            assert_relative_eq!(perspective.vfov(),         expected_vfov, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.near(),         near,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+
+        assert_relative_eq!(reversed_z.far(), far, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_eq!(infinite.far(), f64::INFINITY);
+        assert_eq!(infinite_reversed_z.far(), f64::INFINITY);
+    }
+}
+
+#[test]
+fn test_perspective_projection_reversed_z_infinite_unproject_point() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        let infinite_reversed_z = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        for projection in [reversed_z, infinite, infinite_reversed_z] {
+            let expected = Point3::new(-2_f64, 2_f64, 0_f64) + projection.view_unit_gaze().into_inner() * 50_f64;
+            let projected_point = projection.project_point(&expected);
+            let result = projection.unproject_point(&projected_point);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_reversed_z_infinite_unproject_vector() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        let infinite_reversed_z = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        for projection in [reversed_z, infinite, infinite_reversed_z] {
+            let expected = Vector3::new(-2_f64, 2_f64, 0_f64) + projection.view_unit_gaze().into_inner() * 50_f64;
+            let projected_vector = projection.project_vector(&expected);
+            let result = projection.unproject_vector(&projected_vector);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_reversed_z_clip_unit_gaze() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let perspective = Perspective3::from_vfov_with_ndc(vfov, aspect_ratio, near, far, NdcConvention::VULKAN);
+    let reversed_z = Perspective3::from_vfov_reversed_z(vfov, aspect_ratio, near, far, NdcConvention::VULKAN);
+    let infinite = Perspective3::from_vfov_infinite(vfov, aspect_ratio, near, NdcConvention::VULKAN);
+    let infinite_reversed_z = Perspective3::from_vfov_infinite_reversed_z(vfov, aspect_ratio, near, NdcConvention::VULKAN);
+
+    assert!(!perspective.is_reversed_z() && !perspective.is_infinite());
+    assert!(reversed_z.is_reversed_z() && !reversed_z.is_infinite());
+    assert!(!infinite.is_reversed_z() && infinite.is_infinite());
+    assert!(infinite_reversed_z.is_reversed_z() && infinite_reversed_z.is_infinite());
+    assert_eq!(perspective.clip_unit_gaze(), Unit::from_value(Vector3::unit_z()));
+    assert_eq!(reversed_z.clip_unit_gaze(), Unit::from_value(-Vector3::unit_z()));
+    assert_eq!(infinite.clip_unit_gaze(), Unit::from_value(Vector3::unit_z()));
+    assert_eq!(infinite_reversed_z.clip_unit_gaze(), Unit::from_value(-Vector3::unit_z()));
+    assert_eq!(reversed_z.clip_ndc_bounds(), perspective.clip_ndc_bounds());
+}
+
+#[test]
+fn test_perspective_projection_oblique_parallel_near_plane() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        for perspective in [standard, reversed_z, infinite] {
+            let gaze = perspective.view_unit_gaze().into_inner();
+            let plane = Vector4::new(0_f64, 0_f64, gaze.z, -5_f64);
+            let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+            let ((_, _), (_, _), (z_min, z_max)) = oblique.clip_ndc_bounds();
+            let expected = if perspective.is_reversed_z() { z_max } else { z_min };
+            let point = Point3::new(1_f64, -1_f64, 0_f64) + gaze * 5_f64;
+            let result = oblique.project_point(&point).z;
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_oblique_near_plane_clipping() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        for perspective in [standard, reversed_z] {
+            let gaze = perspective.view_unit_gaze().into_inner();
+            // The visible side of the plane is `y + depth >= 10`.
+            let plane = Vector4::new(0_f64, 1_f64, gaze.z, -10_f64);
+            let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+            let ((_, _), (_, _), (z_min, z_max)) = oblique.clip_ndc_bounds();
+            let (z_near, z_far) = if perspective.is_reversed_z() {
+                (z_max, z_min)
+            } else {
+                (z_min, z_max)
+            };
+            let on_plane = Point3::new(0_f64, 2_f64, 0_f64) + gaze * 8_f64;
+            let visible = Point3::new(0_f64, 2_f64, 0_f64) + gaze * 20_f64;
+            let invisible = Point3::new(0_f64, 2_f64, 0_f64) + gaze * 6_f64;
+            let depth_on_plane = oblique.project_point(&on_plane).z;
+            let depth_visible = oblique.project_point(&visible).z;
+            let depth_invisible = oblique.project_point(&invisible).z;
+
+            assert_relative_eq!(depth_on_plane, z_near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert!((depth_visible - z_near) * (z_far - z_near) > 0_f64);
+            assert!((depth_visible - z_far) * (z_far - z_near) < 0_f64);
+            assert!((depth_invisible - z_near) * (z_far - z_near) < 0_f64);
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_oblique_unproject_point() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        for perspective in [standard, reversed_z, infinite] {
+            let gaze = perspective.view_unit_gaze().into_inner();
+            let plane = Vector4::new(0.3_f64, 1_f64, gaze.z, -10_f64);
+            let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+            let expected = Point3::new(-2_f64, 2_f64, 0_f64) + gaze * 50_f64;
+            let projected_point = oblique.project_point(&expected);
+            let result = oblique.unproject_point(&projected_point);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_oblique_unproject_vector() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let plane = Vector4::new(0.3_f64, 1_f64, gaze.z, -10_f64);
+        let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+        let expected = Vector3::new(-2_f64, 2_f64, 0_f64) + gaze * 50_f64;
+        let projected_vector = oblique.project_vector(&expected);
+        let result = oblique.unproject_vector(&projected_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_oblique_parameters() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let plane = Vector4::new(0.3_f64, 1_f64, gaze.z, -10_f64);
+        let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+
+        assert!(!perspective.is_oblique());
+        assert!(oblique.is_oblique());
+        assert_relative_eq!(oblique.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(oblique.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(oblique.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(oblique.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(oblique.near(),   near,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(oblique.far(),    far,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_oblique_replaces_previous_plane() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let plane1 = Vector4::new(0_f64, 1_f64, -1_f64, -10_f64);
+    let plane2 = Vector4::new(1_f64, 0_f64, -1_f64, -5_f64);
+    let expected = perspective.with_oblique_near_plane(&plane2).unwrap();
+    let result = perspective
+        .with_oblique_near_plane(&plane1)
+        .unwrap()
+        .with_oblique_near_plane(&plane2)
+        .unwrap();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_oblique_origin_on_visible_side() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let visible = Vector4::new(0_f64, 1_f64, -1_f64, 10_f64);
+    let through_origin = Vector4::new(0_f64, 1_f64, -1_f64, 0_f64);
+
+    assert!(perspective.with_oblique_near_plane(&visible).is_none());
+    assert!(perspective.with_oblique_near_plane(&through_origin).is_none());
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_orthographic_projection_ndc_opengl_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let expected = Matrix4x4::from_orthographic(left, right, bottom, top, near, far);
+    let result = Matrix4x4::from_orthographic_with_ndc(left, right, bottom, top, near, far, NdcConvention::OPENGL);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_orthographic_projection_ndc_direct3d_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let expected = Matrix4x4::new(
+        1_f64 / 4_f64, 0_f64,          0_f64,           0_f64,
+        0_f64,         1_f64 / 2_f64,  0_f64,           0_f64,
+        0_f64,         0_f64,          1_f64 / 99_f64,  0_f64,
+        0_f64,         0_f64,         -1_f64 / 99_f64,  1_f64,
+    );
+    let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::DIRECT3D);
+    let result = orthographic.matrix();
+
+    assert_relative_eq!(result, &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_orthographic_projection_ndc_rectangular_parameters() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+
+        assert_eq!(orthographic.ndc_convention(), ndc);
+        assert_relative_eq!(orthographic.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(orthographic.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(orthographic.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(orthographic.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(orthographic.near(),   near,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(orthographic.far(),    far,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_orthographic_projection_ndc_frustum_corners() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let gaze = orthographic.view_unit_gaze().into_inner();
+        let up = orthographic.clip_unit_up().into_inner();
+        let ((_, _), (_, _), (z_near, z_far)) = orthographic.clip_ndc_bounds();
+        let near_top_right = Point3::new(right, top, 0_f64) + gaze * near;
+        let far_bottom_left = Point3::new(-left, -bottom, 0_f64) + gaze * far;
+        let expected_near_top_right = Point3::new(1_f64, up.y, z_near);
+        let expected_far_bottom_left = Point3::new(-1_f64, -up.y, z_far);
+
+        assert_relative_eq!(
+            orthographic.project_point(&near_top_right),
+            expected_near_top_right,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+        assert_relative_eq!(
+            orthographic.project_point(&far_bottom_left),
+            expected_far_bottom_left,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+    }
+}
+
+#[test]
+fn test_orthographic_projection_ndc_unproject_point() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let expected = Point3::new(1_f64, 1_f64, 0_f64) + projection.view_unit_gaze().into_inner() * 50_f64;
+        let projected_point = projection.project_point(&expected);
+        let result = projection.unproject_point(&projected_point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_orthographic_projection_ndc_unproject_vector() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let projection = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let expected = Vector3::new(1_f64, 1_f64, 0_f64) + projection.view_unit_gaze().into_inner() * 50_f64;
+        let projected_vector = projection.project_vector(&expected);
+        let result = projection.unproject_vector(&projected_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_orthographic_projection_ndc_clip_ndc_bounds() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let opengl = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::OPENGL);
+    let webgpu = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, NdcConvention::WEBGPU);
+
+    assert_eq!(opengl.clip_ndc_bounds(), ((-1_f64, 1_f64), (-1_f64, 1_f64), (-1_f64, 1_f64)));
+    assert_eq!(webgpu.clip_ndc_bounds(), ((-1_f64, 1_f64), (-1_f64, 1_f64), (0_f64, 1_f64)));
+}
+
+#[test]
+fn test_perspective_projection_jitter_shifts_image() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let viewport_width = 1920_f64;
+    let viewport_height = 1080_f64;
+    let jitter = Vector2::new(0.375_f64, -0.125_f64);
+    for ndc in NDC_CONVENTIONS {
+        let point = Point3::new(1_f64, -2_f64, 10_f64 * ndc.gaze_sign::<f64>());
+        let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        let infinite_reversed_z = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        for perspective in [standard, reversed_z, infinite, infinite_reversed_z] {
+            let jittered = perspective.with_jitter(&jitter, viewport_width, viewport_height);
+            let projected_point = perspective.project_point(&point);
+            let jittered_point = jittered.project_point(&point);
+            let expected_x = 2_f64 * jitter.x / viewport_width;
+            let expected_y = 2_f64 * jitter.y / viewport_height * ndc.y_axis_sign::<f64>();
+
+            assert_relative_eq!(
+                jittered_point.x - projected_point.x,
+                expected_x,
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(
+                jittered_point.y - projected_point.y,
+                expected_y,
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(
+                jittered_point.z,
+                projected_point.z,
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_jitter_preserves_parameters() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let jitter = Vector2::new(0.25_f64, 0.5_f64);
+    for ndc in NDC_CONVENTIONS {
+        let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        let infinite = Perspective3::new_infinite(left, right, bottom, top, near, ndc);
+        let infinite_reversed_z = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+        for perspective in [standard, reversed_z, infinite, infinite_reversed_z] {
+            let jittered = perspective.with_jitter(&jitter, 800_f64, 600_f64);
+
+            assert_eq!(jittered.ndc_convention(), perspective.ndc_convention());
+            assert_eq!(jittered.is_reversed_z(), perspective.is_reversed_z());
+            assert_eq!(jittered.is_infinite(), perspective.is_infinite());
+            assert!(!jittered.is_oblique());
+            assert_relative_eq!(
+                jittered.near(),
+                perspective.near(),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_eq!(jittered.far().is_infinite(), perspective.far().is_infinite());
+            assert_relative_eq!(
+                jittered.left() + jittered.right(),
+                perspective.left() + perspective.right(),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(
+                jittered.bottom() + jittered.top(),
+                perspective.bottom() + perspective.top(),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+        }
+    }
+}
+
+#[test]
+fn test_perspective_projection_jitter_zero() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let jittered = perspective.with_jitter(&Vector2::zero(), 800_f64, 600_f64);
+
+        assert_relative_eq!(
+            jittered.matrix(),
+            perspective.matrix(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn test_perspective_projection_jitter_preserves_oblique_near_plane() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let jitter = Vector2::new(-0.5_f64, 0.25_f64);
+    for ndc in NDC_CONVENTIONS {
+        let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+        for perspective in [standard, reversed_z] {
+            let gaze = perspective.view_unit_gaze().into_inner();
+            let plane = Vector4::new(0.1_f64, 0.2_f64, gaze.z, -5_f64);
+            let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+            let jittered = oblique.with_jitter(&jitter, 800_f64, 600_f64);
+            let direct = perspective
+                .with_jitter(&jitter, 800_f64, 600_f64)
+                .with_oblique_near_plane(&plane)
+                .unwrap();
+            let ((_, _), (_, _), (z_min, z_max)) = jittered.clip_ndc_bounds();
+            let expected_z = if perspective.is_reversed_z() { z_max } else { z_min };
+            // A point on the oblique near plane.
+            let point = Point3::origin() + gaze * 5_f64;
+            let result = jittered.project_point(&point).z;
+
+            assert!(jittered.is_oblique());
+            assert_relative_eq!(
+                jittered.matrix(),
+                direct.matrix(),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(result, expected_z, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(jittered.left(), direct.left(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(jittered.top(), direct.top(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_orthographic_projection_jitter_shifts_image() {
+    let left = 4_f64;
+    let right = 1_f64;
+    let bottom = 2_f64;
+    let top = 5_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let viewport_width = 1920_f64;
+    let viewport_height = 1080_f64;
+    let jitter = Vector2::new(-0.25_f64, 0.375_f64);
+    for ndc in NDC_CONVENTIONS {
+        let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+        let jittered = orthographic.with_jitter(&jitter, viewport_width, viewport_height);
+        let point = Point3::new(1_f64, -2_f64, 10_f64 * ndc.gaze_sign::<f64>());
+        let projected_point = orthographic.project_point(&point);
+        let jittered_point = jittered.project_point(&point);
+        let expected_x = 2_f64 * jitter.x / viewport_width;
+        let expected_y = 2_f64 * jitter.y / viewport_height * ndc.y_axis_sign::<f64>();
+
+        assert_eq!(jittered.ndc_convention(), orthographic.ndc_convention());
+        assert_relative_eq!(
+            jittered_point.x - projected_point.x,
+            expected_x,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            jittered_point.y - projected_point.y,
+            expected_y,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            jittered_point.z,
+            projected_point.z,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_from_intrinsics_pixel_mapping() {
+    let fx = 1200_f64;
+    let fy = 1150_f64;
+    let cx = 655.5_f64;
+    let cy = 348.25_f64;
+    let image_width = 1280_f64;
+    let image_height = 720_f64;
+    let intrinsics = Matrix3x3::new(
+        fx,    0_f64, 0_f64,
+        0_f64, fy,    0_f64,
+        cx,    cy,    1_f64,
+    );
+    let near = 0.1_f64;
+    let far = 100_f64;
+    // A point in the camera coordinates of computer vision.
+    let camera_point = Point3::new(0.7_f64, -0.4_f64, 3_f64);
+    let expected_u = fx * camera_point.x / camera_point.z + cx;
+    let expected_v = fy * camera_point.y / camera_point.z + cy;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_intrinsics(&intrinsics, image_width, image_height, near, far, ndc).unwrap();
+        let viewport = Viewport::new_with_ndc(0_f64, 0_f64, image_width, image_height, 0_f64, 1_f64, ndc);
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let view_point = Point3::new(camera_point.x, -camera_point.y, 0_f64) + gaze * camera_point.z;
+        let window_point = viewport.ndc_to_window(&perspective.project_point(&view_point));
+
+        assert_relative_eq!(window_point.x, expected_u, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_relative_eq!(window_point.y, expected_v, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_eq!(perspective.ndc_convention(), ndc);
+        assert_relative_eq!(perspective.near(), near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(perspective.far(), far, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_intrinsics_round_trip() {
+    let intrinsics = Matrix3x3::new(
+        1200_f64,  0_f64,      0_f64,
+        0_f64,     1150_f64,   0_f64,
+        655.5_f64, 348.25_f64, 1_f64,
+    );
+    let image_width = 1280_f64;
+    let image_height = 720_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_intrinsics(&intrinsics, image_width, image_height, 0.1_f64, 100_f64, ndc).unwrap();
+        let result = perspective.to_intrinsics(image_width, image_height);
+
+        assert_relative_eq!(result, intrinsics, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_from_skewed_intrinsics_pixel_mapping() {
+    let fx = 1200_f64;
+    let fy = 1150_f64;
+    let skew = 35.5_f64;
+    let cx = 655.5_f64;
+    let cy = 348.25_f64;
+    let image_width = 1280_f64;
+    let image_height = 720_f64;
+    let intrinsics = Matrix3x3::new(
+        fx,    0_f64, 0_f64,
+        skew,  fy,    0_f64,
+        cx,    cy,    1_f64,
+    );
+    let near = 0.1_f64;
+    let far = 100_f64;
+    // A point in the camera coordinates of computer vision.
+    let camera_point = Point3::new(0.7_f64, -0.4_f64, 3_f64);
+    let expected_u = (fx * camera_point.x + skew * camera_point.y) / camera_point.z + cx;
+    let expected_v = fy * camera_point.y / camera_point.z + cy;
+    for ndc in NDC_CONVENTIONS {
+        let viewport = Viewport::new_with_ndc(0_f64, 0_f64, image_width, image_height, 0_f64, 1_f64, ndc);
+        let perspectives = [
+            Perspective3::from_intrinsics(&intrinsics, image_width, image_height, near, far, ndc).unwrap(),
+            Perspective3::from_intrinsics_reversed_z(&intrinsics, image_width, image_height, near, far, ndc).unwrap(),
+            Perspective3::from_intrinsics_infinite(&intrinsics, image_width, image_height, near, ndc).unwrap(),
+            Perspective3::from_intrinsics_infinite_reversed_z(&intrinsics, image_width, image_height, near, ndc).unwrap(),
+        ];
+        for perspective in perspectives {
+            let gaze = perspective.view_unit_gaze().into_inner();
+            let view_point = Point3::new(camera_point.x, -camera_point.y, 0_f64) + gaze * camera_point.z;
+            let projected_point = perspective.project_point(&view_point);
+            let window_point = viewport.ndc_to_window(&projected_point);
+
+            assert_relative_eq!(window_point.x, expected_u, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+            assert_relative_eq!(window_point.y, expected_v, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+            assert_relative_eq!(perspective.shear(), skew / fx, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+            assert_relative_eq!(
+                perspective.matrix() * view_point.to_homogeneous(),
+                projected_point.to_homogeneous() * (perspective.matrix().c2r3 * view_point.z),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(
+                perspective.unproject_point(&projected_point),
+                view_point,
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(
+                perspective.to_intrinsics(image_width, image_height),
+                intrinsics,
+                abs_diff_all <= 1e-8,
+                relative_all <= f64::EPSILON
+            );
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_skewed_intrinsics_round_trip() {
+    let intrinsics = Matrix3x3::new(
+        1200_f64,  0_f64,      0_f64,
+        -22.75_f64, 1150_f64,  0_f64,
+        655.5_f64, 348.25_f64, 1_f64,
+    );
+    let image_width = 1280_f64;
+    let image_height = 720_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_intrinsics(&intrinsics, image_width, image_height, 0.1_f64, 100_f64, ndc).unwrap();
+        let result = perspective.to_intrinsics(image_width, image_height);
+
+        assert_relative_eq!(result, intrinsics, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_skewed_intrinsics_jitter_and_oblique_near_plane() {
+    let intrinsics = Matrix3x3::new(
+        1200_f64,  0_f64,      0_f64,
+        35.5_f64,  1150_f64,   0_f64,
+        655.5_f64, 348.25_f64, 1_f64,
+    );
+    let image_width = 1280_f64;
+    let image_height = 720_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_intrinsics(&intrinsics, image_width, image_height, 0.1_f64, 100_f64, ndc).unwrap();
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let plane = Vector4::new(0.1_f64, -0.2_f64, gaze.z, -5_f64);
+        let point = Point3::new(1_f64, 2_f64, 0_f64) + gaze * 20_f64;
+        let jitter = Vector2::new(0.5_f64, -0.25_f64);
+        let jittered = perspective.with_jitter(&jitter, image_width, image_height);
+        let mut expected = perspective.to_intrinsics(image_width, image_height);
+        expected.c2r0 += jitter.x;
+        expected.c2r1 -= jitter.y;
+
+        assert_relative_eq!(
+            jittered.to_intrinsics(image_width, image_height),
+            expected,
+            abs_diff_all <= 1e-8,
+            relative_all <= f64::EPSILON
+        );
+
+        let infinite = Perspective3::from_intrinsics_infinite_reversed_z(&intrinsics, image_width, image_height, 0.1_f64, ndc).unwrap();
+        let jittered_infinite = infinite.with_jitter(&jitter, image_width, image_height);
+
+        assert!(jittered_infinite.is_reversed_z());
+        assert!(jittered_infinite.is_infinite());
+        assert_relative_eq!(jittered_infinite.shear(), infinite.shear(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            jittered_infinite.to_intrinsics(image_width, image_height),
+            expected,
+            abs_diff_all <= 1e-8,
+            relative_all <= f64::EPSILON
+        );
+
+        let oblique = perspective.with_oblique_near_plane(&plane).unwrap();
+        let projected_point = oblique.project_point(&point);
+        let expected_point = perspective.project_point(&point);
+
+        assert_relative_eq!(projected_point.x, expected_point.x, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(projected_point.y, expected_point.y, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(oblique.unproject_point(&projected_point), point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_to_intrinsics_reversed_z_infinite() {
+    let left = 0.06_f64;
+    let right = 0.04_f64;
+    let bottom = 0.03_f64;
+    let top = 0.045_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let ndc = NdcConvention::VULKAN;
+    let standard = Perspective3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+    let reversed_z = Perspective3::new_reversed_z(left, right, bottom, top, near, far, ndc);
+    let infinite = Perspective3::new_infinite_reversed_z(left, right, bottom, top, near, ndc);
+    let expected = standard.to_intrinsics(1920_f64, 1080_f64);
+
+    assert_relative_eq!(
+        reversed_z.to_intrinsics(1920_f64, 1080_f64),
+        expected,
+        abs_diff_all <= 1e-8,
+        relative_all <= f64::EPSILON
+    );
+    assert_relative_eq!(
+        infinite.to_intrinsics(1920_f64, 1080_f64),
+        expected,
+        abs_diff_all <= 1e-8,
+        relative_all <= f64::EPSILON
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_from_intrinsics_rejects_invalid_intrinsics() {
+    let not_upper_triangular = Matrix3x3::new(
+        800_f64, 0.5_f64, 0_f64,
+        0_f64,   800_f64, 0_f64,
+        400_f64, 300_f64, 1_f64,
+    );
+    let negative_focal_length = Matrix3x3::new(
+        -800_f64, 0_f64,   0_f64,
+        0_f64,    800_f64, 0_f64,
+        400_f64,  300_f64, 1_f64,
+    );
+    let not_affine = Matrix3x3::new(
+        800_f64, 0_f64,   0_f64,
+        0_f64,   800_f64, 0_f64,
+        400_f64, 300_f64, 2_f64,
+    );
+    let ndc = NdcConvention::OPENGL;
+
+    assert!(Perspective3::from_intrinsics(&not_upper_triangular, 800_f64, 600_f64, 0.1_f64, 100_f64, ndc).is_none());
+    assert!(Perspective3::from_intrinsics(&negative_focal_length, 800_f64, 600_f64, 0.1_f64, 100_f64, ndc).is_none());
+    assert!(Perspective3::from_intrinsics(&not_affine, 800_f64, 600_f64, 0.1_f64, 100_f64, ndc).is_none());
+}
+
+#[test]
+fn test_perspective_projection_from_fov_angles() {
+    let angle_left = Radians(-0.8_f64);
+    let angle_right = Radians(0.7_f64);
+    let angle_up = Radians(0.75_f64);
+    let angle_down = Radians(-0.85_f64);
+    let near = 0.05_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_fov_angles(angle_left, angle_right, angle_up, angle_down, near, far, ndc);
+        let expected = Perspective3::new_with_ndc(
+            near * f64::tan(0.8_f64),
+            near * f64::tan(0.7_f64),
+            near * f64::tan(0.85_f64),
+            near * f64::tan(0.75_f64),
+            near,
+            far,
+            ndc,
+        );
+
+        assert_relative_eq!(
+            perspective.matrix(),
+            expected.matrix(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn test_perspective_projection_fov_angles_round_trip() {
+    let expected = FovAngles::new(Degrees(-52_f64), Degrees(44_f64), Degrees(49_f64), Degrees(-55_f64));
+    let near = 0.05_f64;
+    let far = 100_f64;
+    for ndc in NDC_CONVENTIONS {
+        let perspective = Perspective3::from_fov_angles(expected.left, expected.right, expected.up, expected.down, near, far, ndc);
+        let result = perspective.fov_angles();
+
+        assert_relative_eq!(result.left, expected.left, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.right, expected.right, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.up, expected.up, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.down, expected.down, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_fov_angles_symmetric_vfov() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 16_f64 / 9_f64;
+    let perspective = Perspective3::from_vfov(vfov, aspect_ratio, 0.1_f64, 100_f64);
+    let result = perspective.fov_angles();
+    let half_vfov = Radians::from(vfov) / 2_f64;
+
+    assert_relative_eq!(result.up, half_vfov, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result.down, -half_vfov, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result.right, -result.left, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_fov_angles_off_axis() {
+    // A frustum that lies entirely to the right of the gaze direction.
+    let perspective = Perspective3::from_fov_angles(
+        Degrees(10_f64),
+        Degrees(50_f64),
+        Degrees(30_f64),
+        Degrees(-30_f64),
+        0.1_f64,
+        100_f64,
+        NdcConvention::OPENGL,
+    );
+    let result = perspective.fov_angles();
+
+    assert!(perspective.left() < 0_f64);
+    assert_relative_eq!(
+        result.left,
+        Radians::from(Degrees(10_f64)),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON
+    );
+    assert_relative_eq!(
+        result.right,
+        Radians::from(Degrees(50_f64)),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON
+    );
+}
+
+#[test]
+fn test_orthographic_projection_parameters_zero_near_plane() {
+    let left = 6_f64;
+    let right = 6_f64;
+    let bottom = 4_f64;
+    let top = 4_f64;
+    let near = 0_f64;
+    let far = 50_f64;
+    for ndc in NDC_CONVENTIONS {
+        let orthographic = Orthographic3::new_with_ndc(left, right, bottom, top, near, far, ndc);
+
+        assert_relative_eq!(orthographic.near(), near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(orthographic.far(), far, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
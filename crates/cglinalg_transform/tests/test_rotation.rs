@@ -741,7 +741,11 @@ mod rotation3_tests {
 #[cfg(test)]
 mod rotation3_euler_angle_tests {
     use approx_cmp::assert_relative_eq;
-    use cglinalg_core::Euler;
+    use cglinalg_core::{
+        Euler,
+        EulerAxes,
+        EulerOrder,
+    };
     use cglinalg_transform::Rotation3;
     use cglinalg_trigonometry::{
         Angle,
@@ -814,4 +818,497 @@ mod rotation3_euler_angle_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_euler_angles_with_default_order() {
+        let roll_yz: Radians<f64> = Radians::full_turn_div_2();
+        let yaw_zx: Radians<f64> = Radians::full_turn_div_8();
+        let pitch_xy: Radians<f64> = Radians::full_turn_div_6();
+        let rotation = Rotation3::from_euler_angles(&Euler::new(roll_yz, yaw_zx, pitch_xy));
+        let expected = rotation.euler_angles();
+        let result = rotation.euler_angles_with_order(EulerOrder::default());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_euler_angles_with_order_unity() {
+        let roll_yz: Radians<f64> = Radians(0.3_f64);
+        let yaw_zx: Radians<f64> = Radians(-2.1_f64);
+        let pitch_xy: Radians<f64> = Radians(1.2_f64);
+        let rotation_yz = Rotation3::from_angle_x(roll_yz);
+        let rotation_zx = Rotation3::from_angle_y(yaw_zx);
+        let rotation_xy = Rotation3::from_angle_z(pitch_xy);
+        let rotation = rotation_zx * rotation_yz * rotation_xy;
+        let order = EulerOrder::extrinsic(EulerAxes::ZXY);
+        let expected = Euler::new(roll_yz, yaw_zx, pitch_xy);
+        let result = rotation.euler_angles_with_order(order);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_euler_angles_with_order_proper_euler() {
+        let alpha: Radians<f64> = Radians(0.3_f64);
+        let beta: Radians<f64> = Radians(2.1_f64);
+        let gamma: Radians<f64> = Radians(-1.2_f64);
+        let rotation_alpha = Rotation3::from_angle_z(alpha);
+        let rotation_beta = Rotation3::from_angle_x(beta);
+        let rotation_gamma = Rotation3::from_angle_z(gamma);
+        let expected = rotation_alpha * rotation_beta * rotation_gamma;
+        let order = EulerOrder::intrinsic(EulerAxes::ZXZ);
+        let result = Rotation3::from_euler_angles_with_order(&Euler::new(alpha, beta, gamma), order);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod rotation3_swing_twist_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[test]
+    fn test_swing_twist_matches_quaternion() {
+        let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 3_f64));
+        let quaternion = Quaternion::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 4_f64, -2_f64)), Degrees(115_f64));
+        let rotation = Rotation3::from_quaternion(&quaternion);
+        let (expected_swing, expected_twist) = quaternion.swing_twist(&axis);
+        let (swing, twist) = rotation.swing_twist(&axis);
+
+        assert_relative_eq!(
+            swing,
+            Rotation3::from_quaternion(&expected_swing),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            twist,
+            Rotation3::from_quaternion(&expected_twist),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_swing_twist_recomposes() {
+        let axis = Unit::from_value(Vector3::unit_y());
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(-1_f64, 1_f64, 1_f64)), Degrees(-150_f64));
+        let (swing, twist) = rotation.swing_twist(&axis);
+        let result = Rotation3::from_swing_twist(&swing, &twist);
+
+        assert_relative_eq!(result, rotation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_twist_fixes_axis() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 0_f64, 1_f64));
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(3_f64, 2_f64, 1_f64)), Degrees(80_f64));
+        let (_, twist) = rotation.swing_twist(&axis);
+        let expected = axis.into_inner();
+        let result = twist.apply_vector(&expected);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod rotation3_lie_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix3x3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::{
+        Degrees,
+        Radians,
+    };
+    use core::f64;
+
+
+    fn finite_difference_left_jacobian(rotation_vector: &Vector3<f64>) -> Matrix3x3<f64> {
+        let step = 1e-6;
+        let rotation_inverse = Rotation3::exp(rotation_vector).inverse();
+        let mut result = Matrix3x3::zero();
+        for c in 0..3 {
+            let mut offset = Vector3::zero();
+            offset[c] = step;
+            let forward = (Rotation3::exp(&(rotation_vector + offset)) * rotation_inverse).log();
+            let backward = (Rotation3::exp(&(rotation_vector - offset)) * rotation_inverse).log();
+            let column = (forward - backward) / (2_f64 * step);
+            result[c][0] = column[0];
+            result[c][1] = column[1];
+            result[c][2] = column[2];
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_exp_zero_is_identity() {
+        let expected = Rotation3::<f64>::identity();
+        let result = Rotation3::exp(&Vector3::zero());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_log_identity_is_zero() {
+        let expected = Vector3::zero();
+        let result = Rotation3::<f64>::identity().log();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_log_round_trip() {
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(-2_f64, 1_f64, 4_f64)), Degrees(165_f64));
+        let result = Rotation3::exp(&rotation.log());
+
+        assert_relative_eq!(result, rotation, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_log_half_turn() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Radians(f64::consts::PI));
+        let rotation_vector = rotation.log();
+
+        assert_relative_eq!(rotation_vector.norm(), f64::consts::PI, abs_diff <= 1e-10, relative <= f64::EPSILON);
+        assert_relative_eq!(
+            rotation_vector.cross(&axis).norm(),
+            0_f64,
+            abs_diff <= 1e-10,
+            relative <= f64::EPSILON
+        );
+        assert_relative_eq!(
+            Rotation3::exp(&rotation_vector),
+            rotation,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_log_small_angle() {
+        let expected = Vector3::new(3e-9_f64, -1e-9_f64, 2e-9_f64);
+        let result = Rotation3::exp(&expected).log();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-20, relative_all <= 1e-8);
+    }
+
+    #[test]
+    fn test_left_jacobian_finite_difference() {
+        let rotation_vector = Vector3::new(0.8_f64, -1.1_f64, 0.5_f64);
+        let expected = finite_difference_left_jacobian(&rotation_vector);
+        let result = Rotation3::left_jacobian(&rotation_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_right_jacobian_finite_difference() {
+        let rotation_vector = Vector3::new(-0.2_f64, 1.4_f64, 0.9_f64);
+        let step = 1e-6;
+        let rotation_inverse = Rotation3::exp(&rotation_vector).inverse();
+        let jacobian = Rotation3::right_jacobian(&rotation_vector);
+        for c in 0..3 {
+            let mut offset = Vector3::zero();
+            offset[c] = step;
+            let forward = (rotation_inverse * Rotation3::exp(&(rotation_vector + offset))).log();
+            let backward = (rotation_inverse * Rotation3::exp(&(rotation_vector - offset))).log();
+            let expected = (forward - backward) / (2_f64 * step);
+            let result = jacobian.column(c);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_left_jacobian_small_angle() {
+        let rotation_vector = Vector3::new(6e-3_f64, -4e-3_f64, 5e-3_f64);
+        let theta = rotation_vector.norm();
+        let phi_hat = Matrix3x3::cross_matrix(&rotation_vector);
+        let beta = (1_f64 - theta.cos()) / (theta * theta);
+        let gamma = (theta - theta.sin()) / (theta * theta * theta);
+        let expected = Matrix3x3::identity() + phi_hat * beta + phi_hat * phi_hat * gamma;
+        let result = Rotation3::left_jacobian(&rotation_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_inverse() {
+        let rotation_vector = Vector3::new(1.5_f64, 2_f64, -1.8_f64);
+        let expected = Matrix3x3::identity();
+        let result = Rotation3::left_jacobian_inverse(&rotation_vector) * Rotation3::left_jacobian(&rotation_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_right_jacobian_inverse_small_angle() {
+        let rotation_vector = Vector3::new(1e-5_f64, 2e-5_f64, -3e-5_f64);
+        let expected = Matrix3x3::identity();
+        let result = Rotation3::right_jacobian(&rotation_vector) * Rotation3::right_jacobian_inverse(&rotation_vector);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-14, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_adjoint() {
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 1_f64, -1_f64)), Degrees(-110_f64));
+        let rotation_vector = Vector3::new(0.3_f64, 0.2_f64, -0.7_f64);
+        let expected = rotation * Rotation3::exp(&rotation_vector) * rotation.inverse();
+        let result = Rotation3::exp(&(rotation.adjoint() * rotation_vector));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+
+#[cfg(test)]
+mod rotation3_scaled_axis_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::{
+        Degrees,
+        Radians,
+    };
+    use core::f64;
+
+
+    #[test]
+    fn test_scaled_axis_matches_axis_angle() {
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(3_f64, -1_f64, 2_f64)), Degrees(100_f64));
+        let (axis, angle) = rotation.axis_angle().unwrap();
+        let expected = axis.into_inner() * angle.0;
+        let result = rotation.scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_round_trip_small_angle() {
+        let expected = Vector3::new(-2e-10_f64, 1e-10_f64, 4e-10_f64);
+        let result = Rotation3::from_scaled_axis(&expected).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-24, relative_all <= 1e-6);
+    }
+
+    #[test]
+    fn test_scaled_axis_round_trip_near_half_turn() {
+        let axis = Unit::from_value(Vector3::new(-1_f64, 4_f64, 1_f64));
+        let expected = axis.into_inner() * (f64::consts::PI - 1e-7_f64);
+        let result = Rotation3::from_scaled_axis(&expected).scaled_axis();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scaled_axis_half_turn() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Radians(f64::consts::PI));
+        let scaled_axis = rotation.scaled_axis();
+
+        assert_relative_eq!(scaled_axis.norm(), f64::consts::PI, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        assert_relative_eq!(
+            Rotation3::from_scaled_axis(&scaled_axis),
+            rotation,
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod rotation3_angular_velocity_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn orientation() -> Rotation3<f64> {
+        Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(-2_f64, 1_f64, 1_f64)), Degrees(142_f64))
+    }
+
+    #[test]
+    fn test_integrate_angular_velocity_matches_quaternion() {
+        let angular_velocity = Vector3::new(0.4_f64, 2.5_f64, -1_f64);
+        let quaternion = Quaternion::from(orientation());
+        let expected = Rotation3::from_quaternion(&quaternion.integrate_angular_velocity(&angular_velocity, 0.3_f64));
+        let result = orientation().integrate_angular_velocity(&angular_velocity, 0.3_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_integrate_body_angular_velocity_matches_quaternion() {
+        let angular_velocity = Vector3::new(-3_f64, 0.1_f64, 0.6_f64);
+        let quaternion = Quaternion::from(orientation());
+        let expected = Rotation3::from_quaternion(&quaternion.integrate_body_angular_velocity(&angular_velocity, 0.3_f64));
+        let result = orientation().integrate_body_angular_velocity(&angular_velocity, 0.3_f64);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_body_and_world_angular_velocity_agree() {
+        let orientation = orientation();
+        let world_angular_velocity = Vector3::new(1.2_f64, -0.3_f64, 0.8_f64);
+        let body_angular_velocity = orientation.inverse_apply_vector(&world_angular_velocity);
+        let next = orientation.integrate_angular_velocity(&world_angular_velocity, 0.5_f64);
+        let result = orientation.body_angular_velocity(&next, 0.5_f64);
+
+        assert_relative_eq!(result, body_angular_velocity, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_round_trip() {
+        let angular_velocity = Vector3::new(5_f64, 2_f64, -7_f64);
+        let dt = 0.01_f64;
+        let next = orientation().integrate_angular_velocity(&angular_velocity, dt);
+        let result = orientation().angular_velocity(&next, dt);
+
+        assert_relative_eq!(result, angular_velocity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod rotation3_swing_twist_limits_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Quaternion,
+        SwingTwistLimits,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn limits() -> SwingTwistLimits<f64> {
+        SwingTwistLimits::new_cone(
+            &Unit::from_value(Vector3::new(1_f64, 0_f64, 1_f64)),
+            Degrees(35_f64),
+            Degrees(-15_f64),
+            Degrees(25_f64),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_clamp_swing_twist_within_limits() {
+        let rotation = Rotation3::from_axis_angle(limits().twist_axis(), Degrees(20_f64));
+        let (result, clamped) = rotation.clamp_swing_twist(&limits());
+
+        assert_eq!(result, rotation);
+        assert!(!clamped);
+    }
+
+    #[test]
+    fn test_clamp_swing_twist_matches_quaternion() {
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(-1_f64, 3_f64, 0.5_f64)), Degrees(110_f64));
+        let (quaternion, expected_clamped) = Quaternion::from(rotation).clamp_swing_twist(&limits());
+        let expected = Rotation3::from_quaternion(&quaternion);
+        let (result, clamped) = rotation.clamp_swing_twist(&limits());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_eq!(clamped, expected_clamped);
+        assert!(clamped);
+    }
+}
+
+#[cfg(test)]
+mod rotation3_symmetric_eigen_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix3x3,
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[test]
+    fn test_principal_axes_rotation_diagonalizes_inertia_tensor() {
+        let orientation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, -2_f64, 2_f64)), Degrees(67_f64));
+        let principal_moments = Matrix3x3::from_diagonal(&Vector3::new(1_f64, 2.5_f64, 4_f64));
+        let inertia = orientation.matrix() * principal_moments * orientation.matrix().transpose();
+        let eigen = inertia.try_symmetric_eigen().unwrap();
+        let rotation = Rotation3::from_quaternion(&Quaternion::from_matrix(eigen.eigenvectors()));
+        let result = rotation.matrix().transpose() * inertia * rotation.matrix();
+
+        assert_relative_eq!(result, principal_moments, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            rotation.matrix(),
+            eigen.eigenvectors(),
+            abs_diff_all <= 1e-12,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[cfg(test)]
+mod rotation3_polar_decomposition_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix3x3,
+        Quaternion,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::Rotation3;
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rotation_from_deformed_linear_map() {
+        let expected = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(2_f64, 1_f64, -1_f64)), Degrees(135_f64));
+        let stretch = Matrix3x3::new(
+            1.5_f64, 0.2_f64, 0_f64,
+            0.2_f64, 0.8_f64, 0.1_f64,
+            0_f64,   0.1_f64, 1.2_f64,
+        );
+        let deformation = expected.matrix() * stretch;
+        let polar = deformation.polar();
+        let result = Rotation3::from_quaternion(&Quaternion::from_matrix(polar.rotation()));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotation_from_inverted_linear_map() {
+        let expected = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(0_f64, 1_f64, 3_f64)), Degrees(-40_f64));
+        let stretch = Matrix3x3::from_diagonal(&Vector3::new(1_f64, 2_f64, -0.25_f64));
+        let deformation = expected.matrix() * stretch;
+        let polar = deformation.polar();
+        let result = Rotation3::from_quaternion(&Quaternion::from_matrix(polar.rotation()));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        assert_relative_eq!(polar.stretch(), &stretch, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
 }
@@ -480,3 +480,297 @@ mod similarity3_tests {
         assert_eq!(result, expected);
     }
 }
+
+
+#[cfg(test)]
+mod similarity3_lie_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix,
+        Matrix3x3,
+        Unit,
+        Vector,
+        Vector3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Similarity3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn finite_difference_left_jacobian(tangent: &Vector<f64, 7>) -> Matrix<f64, 7, 7> {
+        let step = 1e-6;
+        let similarity_inverse = Similarity3::exp(tangent).inverse();
+        let mut result = Matrix::zero();
+        for c in 0..7 {
+            let mut offset = Vector::zero();
+            offset[c] = step;
+            let forward = (Similarity3::exp(&(tangent + offset)) * similarity_inverse).log();
+            let backward = (Similarity3::exp(&(tangent - offset)) * similarity_inverse).log();
+            let column = (forward - backward) / (2_f64 * step);
+            for r in 0..7 {
+                result[c][r] = column[r];
+            }
+        }
+
+        result
+    }
+
+    fn finite_difference_right_jacobian(tangent: &Vector<f64, 7>) -> Matrix<f64, 7, 7> {
+        let step = 1e-6;
+        let similarity_inverse = Similarity3::exp(tangent).inverse();
+        let mut result = Matrix::zero();
+        for c in 0..7 {
+            let mut offset = Vector::zero();
+            offset[c] = step;
+            let forward = (similarity_inverse * Similarity3::exp(&(tangent + offset))).log();
+            let backward = (similarity_inverse * Similarity3::exp(&(tangent - offset))).log();
+            let column = (forward - backward) / (2_f64 * step);
+            for r in 0..7 {
+                result[c][r] = column[r];
+            }
+        }
+
+        result
+    }
+
+    /// Integrate `adjoint(exp(u * tangent))` over the unit interval with the
+    /// composite Simpson rule, which gives the left Jacobian independently of
+    /// its closed form.
+    fn quadrature_left_jacobian(tangent: &Vector<f64, 7>) -> Matrix<f64, 7, 7> {
+        let intervals = 4096;
+        let step = 1_f64 / (intervals as f64);
+        let mut result = Matrix::zero();
+        for i in 0..=intervals {
+            let weight = if i == 0 || i == intervals {
+                1_f64
+            } else if i % 2 == 1 {
+                4_f64
+            } else {
+                2_f64
+            };
+            let adjoint = Similarity3::exp(&(tangent * (i as f64 * step))).adjoint();
+            result += adjoint * (weight * step / 3_f64);
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_exp_zero_is_identity() {
+        let expected = Similarity3::<f64>::identity();
+        let result = Similarity3::exp(&Vector::zero());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_exp_without_scaling_matches_isometry() {
+        let twist = Vector::from([1_f64, -2_f64, 0.5_f64, 0.6_f64, 1.2_f64, -0.4_f64]);
+        let tangent = Vector::from([1_f64, -2_f64, 0.5_f64, 0.6_f64, 1.2_f64, -0.4_f64, 0_f64]);
+        let expected = Similarity3::from_isometry(&Isometry3::exp(&twist));
+        let result = Similarity3::exp(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_without_rotation() {
+        let sigma = 0.7_f64;
+        let tangent = Vector::from([2_f64, 0_f64, -1_f64, 0_f64, 0_f64, 0_f64, sigma]);
+        let factor = sigma.exp_m1() / sigma;
+        let expected = Similarity3::from_parts(
+            &Translation3::new(2_f64 * factor, 0_f64, -factor),
+            &Rotation3::identity(),
+            sigma.exp(),
+        );
+        let result = Similarity3::exp(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_log_round_trip() {
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 3_f64, -2_f64)), Degrees(155_f64));
+        let translation = Translation3::new(-3_f64, 4_f64, 1_f64);
+        let similarity = Similarity3::from_parts(&translation, &rotation, 0.25_f64);
+        let result = Similarity3::exp(&similarity.log());
+
+        assert_relative_eq!(result, similarity, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_log_round_trip_small_angle_and_scale() {
+        let tangent = Vector::from([1_f64, 2_f64, 3_f64, 2e-3_f64, -1e-3_f64, 3e-3_f64, 1e-3_f64]);
+        let result = Similarity3::exp(&tangent).log();
+
+        assert_relative_eq!(result, tangent, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_exp_translation_near_small_angle_threshold() {
+        // The translation is `W * rho`, where `W = sum((phi^ + sigma * I)^n / (n + 1)!)`.
+        // Check both sides of the switch from the Taylor expansion to the closed form.
+        let sigma = -0.8_f64;
+        let rho = Vector3::new(1_f64, -2_f64, 3_f64);
+        for angle in [0.0105_f64, 0.0115_f64] {
+            let phi = Vector3::new(angle * 0.6_f64, 0_f64, angle * 0.8_f64);
+            let generator = Matrix3x3::cross_matrix(&phi) + Matrix3x3::identity() * sigma;
+            let mut term = Matrix3x3::identity();
+            let mut w = Matrix3x3::identity();
+            for n in 1..30 {
+                term = term * generator / ((n + 1) as f64);
+                w += term;
+            }
+            let expected = w * rho;
+            let tangent = Vector::from([rho[0], rho[1], rho[2], phi[0], phi[1], phi[2], sigma]);
+            let result = *Similarity3::exp(&tangent).translation().vector();
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-14, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_left_jacobian_finite_difference() {
+        let tangent = Vector::from([0.5_f64, -1_f64, 2_f64, 0.9_f64, 0.4_f64, -1.2_f64, 0.3_f64]);
+        let expected = finite_difference_left_jacobian(&tangent);
+        let result = Similarity3::left_jacobian(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_right_jacobian_finite_difference() {
+        let tangent = Vector::from([-1.5_f64, 0.2_f64, 0.7_f64, -0.3_f64, 1.6_f64, 0.8_f64, -0.6_f64]);
+        let expected = finite_difference_right_jacobian(&tangent);
+        let result = Similarity3::right_jacobian(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_finite_difference_small_angle() {
+        let tangent = Vector::from([1_f64, 3_f64, -2_f64, 4e-3_f64, -2e-3_f64, 5e-3_f64, -0.2_f64]);
+        let expected = finite_difference_left_jacobian(&tangent);
+        let result = Similarity3::left_jacobian(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-7, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_quadrature() {
+        let tangent = Vector::from([0.5_f64, -1_f64, 2_f64, 0.9_f64, 0.4_f64, -1.2_f64, 0.3_f64]);
+        let expected = quadrature_left_jacobian(&tangent);
+        let result = Similarity3::left_jacobian(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_near_small_angle_threshold() {
+        // Check both sides of the switch from the Taylor expansions to the closed forms.
+        let rho = Vector3::new(1_f64, -2_f64, 3_f64);
+        for angle in [0.0105_f64, 0.0115_f64] {
+            let phi = Vector3::new(angle * 0.6_f64, 0_f64, angle * 0.8_f64);
+            let tangent = Vector::from([rho[0], rho[1], rho[2], phi[0], phi[1], phi[2], -0.8_f64]);
+            let expected = quadrature_left_jacobian(&tangent);
+            let result = Similarity3::left_jacobian(&tangent);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_left_jacobian_large_rotation_angle() {
+        let axis = Vector3::new(2_f64, -1_f64, 2_f64) / 3_f64;
+        let phi = axis * (10_f64 * core::f64::consts::PI + 0.3_f64);
+        let tangent = Vector::from([1_f64, 0.5_f64, -2_f64, phi[0], phi[1], phi[2], 0.2_f64]);
+        let expected = quadrature_left_jacobian(&tangent);
+        let result = Similarity3::left_jacobian(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-9, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_large_negative_log_scale() {
+        let tangent = Vector::from([-2_f64, 1_f64, 3_f64, 0.7_f64, -1.1_f64, 0.4_f64, -30_f64]);
+        let expected = quadrature_left_jacobian(&tangent);
+        let result = Similarity3::left_jacobian(&tangent);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_left_jacobian_without_scaling_matches_isometry() {
+        let twist = Vector::from([1_f64, -2_f64, 0.5_f64, 0.6_f64, 1.2_f64, -0.4_f64]);
+        let tangent = Vector::from([1_f64, -2_f64, 0.5_f64, 0.6_f64, 1.2_f64, -0.4_f64, 0_f64]);
+        let expected = Isometry3::left_jacobian(&twist);
+        let result = Similarity3::left_jacobian(&tangent);
+        for c in 0..6 {
+            for r in 0..6 {
+                assert_relative_eq!(result[c][r], expected[c][r], abs_diff <= 1e-12, relative <= f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_left_jacobian_inverse() {
+        let tangent = Vector::from([2_f64, 1_f64, -1_f64, 1.7_f64, -0.6_f64, 1.1_f64, -0.5_f64]);
+        let jacobian = Similarity3::left_jacobian(&tangent);
+        let jacobian_inverse = Similarity3::left_jacobian_inverse(&tangent);
+        for c in 0..7 {
+            let mut expected = Vector::zero();
+            expected[c] = 1_f64;
+            let result = jacobian * (jacobian_inverse * expected);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_left_jacobian_inverse_large_rotation_angle() {
+        // Unlike for **SE(3)**, a nonzero log-scale keeps the Jacobian invertible
+        // past a rotation angle of `2 * pi`.
+        let axis = Vector3::new(0_f64, 0.6_f64, 0.8_f64);
+        let phi = axis * (10_f64 * core::f64::consts::PI + 0.5_f64);
+        let tangent = Vector::from([0.5_f64, -1_f64, 1_f64, phi[0], phi[1], phi[2], 1.5_f64]);
+        let jacobian = Similarity3::left_jacobian(&tangent);
+        let jacobian_inverse = Similarity3::left_jacobian_inverse(&tangent);
+        for c in [0, 1, 2, 6] {
+            let mut expected = Vector::zero();
+            expected[c] = 1_f64;
+            let result = jacobian_inverse * (jacobian * expected);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_right_jacobian_inverse() {
+        let tangent = Vector::from([0.3_f64, -0.9_f64, 0.4_f64, 2e-4_f64, 1e-4_f64, -3e-4_f64, 2e-4_f64]);
+        let jacobian = Similarity3::right_jacobian(&tangent);
+        let jacobian_inverse = Similarity3::right_jacobian_inverse(&tangent);
+        for c in 0..7 {
+            let mut expected = Vector::zero();
+            expected[c] = 1_f64;
+            let result = jacobian_inverse * (jacobian * expected);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_adjoint() {
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(2_f64, 0_f64, 1_f64)), Degrees(75_f64));
+        let translation = Translation3::new(0_f64, -2_f64, 6_f64);
+        let similarity = Similarity3::from_parts(&translation, &rotation, 3_f64);
+        let tangent = Vector::from([0.4_f64, -0.1_f64, 0.3_f64, 0.2_f64, -0.5_f64, 0.1_f64, -0.4_f64]);
+        let expected = similarity * Similarity3::exp(&tangent) * similarity.inverse();
+        let result = Similarity3::exp(&(similarity.adjoint() * tangent));
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
@@ -0,0 +1,238 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::{
+    DepthRange,
+    Handedness,
+    NdcConvention,
+    NdcYAxis,
+    Point2,
+    Point3,
+    Unit,
+    Vector3,
+    Vector4,
+};
+use cglinalg_transform::{
+    Isometry3,
+    Orthographic3,
+    Perspective3,
+    Ray3,
+    Viewport,
+};
+use cglinalg_trigonometry::Degrees;
+
+
+const NDC_CONVENTIONS: [NdcConvention; 6] = [
+    NdcConvention::OPENGL,
+    NdcConvention::VULKAN,
+    NdcConvention::DIRECT3D,
+    NdcConvention::METAL,
+    NdcConvention::WEBGPU,
+    NdcConvention::new(Handedness::LeftHanded, DepthRange::NegativeOneToOne, NdcYAxis::Down),
+];
+
+fn view_isometry(ndc: &NdcConvention, eye: &Point3<f64>, target: &Point3<f64>, up: &Vector3<f64>) -> Isometry3<f64> {
+    match ndc.handedness {
+        Handedness::RightHanded => Isometry3::look_at_rh(eye, target, up),
+        Handedness::LeftHanded => Isometry3::look_at_lh(eye, target, up),
+    }
+}
+
+#[test]
+fn test_viewport_ndc_to_window_corners() {
+    let viewport = Viewport::new(100_f64, 50_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+
+    assert_eq!(
+        viewport.ndc_to_window(&Point3::new(-1_f64, 1_f64, -1_f64)),
+        Point3::new(100_f64, 50_f64, 0_f64)
+    );
+    assert_eq!(
+        viewport.ndc_to_window(&Point3::new(1_f64, 1_f64, -1_f64)),
+        Point3::new(900_f64, 50_f64, 0_f64)
+    );
+    assert_eq!(
+        viewport.ndc_to_window(&Point3::new(-1_f64, -1_f64, 1_f64)),
+        Point3::new(100_f64, 650_f64, 1_f64)
+    );
+    assert_eq!(
+        viewport.ndc_to_window(&Point3::new(1_f64, -1_f64, 1_f64)),
+        Point3::new(900_f64, 650_f64, 1_f64)
+    );
+}
+
+#[test]
+fn test_viewport_ndc_to_window_y_axis_down() {
+    let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, NdcConvention::VULKAN);
+
+    assert_eq!(
+        viewport.ndc_to_window(&Point3::new(-1_f64, -1_f64, 0_f64)),
+        Point3::new(0_f64, 0_f64, 0_f64)
+    );
+    assert_eq!(
+        viewport.ndc_to_window(&Point3::new(1_f64, 1_f64, 1_f64)),
+        Point3::new(800_f64, 600_f64, 1_f64)
+    );
+}
+
+#[test]
+fn test_viewport_depth_range() {
+    let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0.25_f64, 0.75_f64, NdcConvention::DIRECT3D);
+    let result = viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, 0.5_f64));
+    let expected = Point3::new(400_f64, 300_f64, 0.5_f64);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_viewport_window_to_ndc_inverse() {
+    let ndc_point = Point3::new(0.3_f64, -0.7_f64, 0.2_f64);
+    for ndc in NDC_CONVENTIONS {
+        let viewport = Viewport::new_with_ndc(17_f64, 33_f64, 1920_f64, 1080_f64, 0.1_f64, 0.9_f64, ndc);
+        let window_point = viewport.ndc_to_window(&ndc_point);
+        let result = viewport.window_to_ndc(&window_point);
+
+        assert_relative_eq!(result, ndc_point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_viewport_contains() {
+    let viewport = Viewport::new(100_f64, 50_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+
+    assert!(viewport.contains(&Point2::new(100_f64, 50_f64)));
+    assert!(viewport.contains(&Point2::new(899.5_f64, 649.5_f64)));
+    assert!(!viewport.contains(&Point2::new(900_f64, 300_f64)));
+    assert!(!viewport.contains(&Point2::new(500_f64, 650_f64)));
+    assert!(!viewport.contains(&Point2::new(500_f64, 49_f64)));
+}
+
+#[test]
+fn test_viewport_perspective_ray_passes_through_point() {
+    let eye = Point3::new(1_f64, 2_f64, 5_f64);
+    let target = Point3::new(0_f64, 0_f64, 0_f64);
+    let up = Vector3::unit_y();
+    let point = Point3::new(0.5_f64, -0.25_f64, -1_f64);
+    for ndc in NDC_CONVENTIONS {
+        let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, ndc);
+        let view = view_isometry(&ndc, &eye, &target, &up);
+        let standard = Perspective3::from_vfov_with_ndc(Degrees(70_f64), viewport.aspect_ratio(), 0.1_f64, 100_f64, ndc);
+        let reversed_z = Perspective3::from_vfov_reversed_z(Degrees(70_f64), viewport.aspect_ratio(), 0.1_f64, 100_f64, ndc);
+        let infinite = Perspective3::from_vfov_infinite(Degrees(70_f64), viewport.aspect_ratio(), 0.1_f64, ndc);
+        let asymmetric = Perspective3::new_with_ndc(0.1_f64, 0.05_f64, 0.02_f64, 0.08_f64, 0.1_f64, 100_f64, ndc);
+        for projection in [standard, reversed_z, infinite, asymmetric] {
+            let ndc_point = projection.project_point(&view.apply_point(&point));
+            let window_point = viewport.ndc_to_window(&ndc_point);
+            let ray = viewport
+                .perspective_ray(&projection, &view, &Point2::new(window_point.x, window_point.y))
+                .unwrap();
+            let expected = Unit::from_value(point - eye);
+
+            assert_relative_eq!(ray.origin(), &eye, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+            assert_relative_eq!(ray.direction(), &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
+
+#[test]
+fn test_viewport_perspective_ray_oblique_near_plane() {
+    let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    let target = Point3::new(0_f64, 0_f64, 0_f64);
+    let up = Vector3::unit_y();
+    let point = Point3::new(-1_f64, 0.5_f64, 0_f64);
+    for ndc in NDC_CONVENTIONS {
+        let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, ndc);
+        let view = view_isometry(&ndc, &eye, &target, &up);
+        let perspective = Perspective3::from_vfov_with_ndc(Degrees(70_f64), viewport.aspect_ratio(), 0.1_f64, 100_f64, ndc);
+        let gaze = perspective.view_unit_gaze().into_inner();
+        let plane = Vector4::new(0.3_f64, 0.1_f64, gaze.z, -2_f64);
+        let projection = perspective.with_oblique_near_plane(&plane).unwrap();
+        let ndc_point = projection.project_point(&view.apply_point(&point));
+        let window_point = viewport.ndc_to_window(&ndc_point);
+        let ray = viewport
+            .perspective_ray(&projection, &view, &Point2::new(window_point.x, window_point.y))
+            .unwrap();
+        let expected = Unit::from_value(point - eye);
+
+        assert_relative_eq!(ray.origin(), &eye, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(ray.direction(), &expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_viewport_orthographic_ray_passes_through_point() {
+    let eye = Point3::new(1_f64, 2_f64, 5_f64);
+    let target = Point3::new(0_f64, 0_f64, 0_f64);
+    let up = Vector3::unit_y();
+    let point = Point3::new(0.5_f64, -0.25_f64, -1_f64);
+    for ndc in NDC_CONVENTIONS {
+        let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, ndc);
+        let view = view_isometry(&ndc, &eye, &target, &up);
+        let projection = Orthographic3::new_with_ndc(4_f64, 3_f64, 2_f64, 2.5_f64, 0.5_f64, 100_f64, ndc);
+        let ndc_point = projection.project_point(&view.apply_point(&point));
+        let window_point = viewport.ndc_to_window(&ndc_point);
+        let ray = viewport
+            .orthographic_ray(&projection, &view, &Point2::new(window_point.x, window_point.y))
+            .unwrap();
+        let expected_direction = Unit::from_value(target - eye);
+        let distance = (point - ray.origin()).dot(ray.direction());
+        let result = ray.point_at(distance);
+
+        assert_relative_eq!(
+            ray.direction(),
+            &expected_direction,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+        assert_relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            view.apply_point(ray.origin()).z.abs(),
+            projection.near(),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON
+        );
+    }
+}
+
+#[test]
+fn test_viewport_rays_reject_mismatched_ndc_convention() {
+    let eye = Point3::new(1_f64, 2_f64, 5_f64);
+    let target = Point3::new(0_f64, 0_f64, 0_f64);
+    let up = Vector3::unit_y();
+    let window_point = Point2::new(250_f64, 400_f64);
+    for viewport_ndc in NDC_CONVENTIONS {
+        let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, viewport_ndc);
+        for ndc in NDC_CONVENTIONS {
+            let view = view_isometry(&ndc, &eye, &target, &up);
+            let perspective = Perspective3::from_vfov_with_ndc(Degrees(70_f64), viewport.aspect_ratio(), 0.1_f64, 100_f64, ndc);
+            let orthographic = Orthographic3::new_with_ndc(4_f64, 3_f64, 2_f64, 2.5_f64, 0.5_f64, 100_f64, ndc);
+
+            assert_eq!(
+                viewport.perspective_ray(&perspective, &view, &window_point).is_some(),
+                ndc == viewport_ndc
+            );
+            assert_eq!(
+                viewport.orthographic_ray(&orthographic, &view, &window_point).is_some(),
+                ndc == viewport_ndc
+            );
+        }
+    }
+}
+
+#[test]
+fn test_viewport_display() {
+    let viewport = Viewport::new_with_ndc(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64, NdcConvention::VULKAN);
+    let expected = format!(
+        "Viewport [x=0, y=0, width=800, height=600, min_depth=0, max_depth=1, ndc={}]",
+        NdcConvention::VULKAN
+    );
+
+    assert_eq!(format!("{}", viewport), expected);
+}
+
+#[test]
+fn test_ray_point_at() {
+    let origin = Point3::new(1_f64, -2_f64, 3_f64);
+    let direction = Unit::from_value(Vector3::new(2_f64, 0_f64, 0_f64));
+    let ray = Ray3::new(origin, direction);
+
+    assert_eq!(ray.point_at(0_f64), origin);
+    assert_eq!(ray.point_at(4_f64), Point3::new(5_f64, -2_f64, 3_f64));
+}